@@ -1,6 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use std::fs;
 use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
+use tf_demo_parser::demo::parser::player_summary_analyzer::PlayerSummaryAnalyzer;
+use tf_demo_parser::demo::parser::ParserConfig;
 use tf_demo_parser::{Demo, DemoParser, Stream};
 
 fn bench_file(input_file: &str, b: &mut Criterion) {
@@ -32,6 +34,31 @@ fn bench_gamestate(input_file: &str, b: &mut Criterion) {
     });
 }
 
+fn bench_lazy_props(input_file: &str, b: &mut Criterion) {
+    let file = fs::read(input_file).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let stream: Stream = demo.get_stream();
+
+    let mut group = b.benchmark_group(format!("bench lazy props {}", input_file));
+    for lazy_props in [false, true] {
+        let name = if lazy_props { "lazy" } else { "eager" };
+        let config = ParserConfig::new().lazy_props(lazy_props);
+        group.bench_function(format!("player summary {}", name), |b| {
+            b.iter(|| {
+                let (_, state) = DemoParser::with_config(
+                    stream.clone(),
+                    PlayerSummaryAnalyzer::new(),
+                    config.clone(),
+                )
+                .parse()
+                .unwrap();
+                black_box(state);
+            })
+        });
+    }
+    group.finish();
+}
+
 fn bench_gully(b: &mut Criterion) {
     bench_file("test_data/gully.dem", b);
 }
@@ -46,11 +73,16 @@ fn bench_gamestate_comp(b: &mut Criterion) {
     bench_gamestate("test_data/comp.dem", b);
 }
 
+fn bench_lazy_props_short(b: &mut Criterion) {
+    bench_lazy_props("test_data/short-2024.dem", b);
+}
+
 criterion_group!(
     benches,
     bench_comp,
     bench_gully,
     bench_gamestate_comp,
-    bench_gamestate_gully
+    bench_gamestate_gully,
+    bench_lazy_props_short
 );
 criterion_main!(benches);
@@ -23,6 +23,7 @@ impl MessageHandler for AllMessages {
 }
 
 const INPUT: &[u8] = include_bytes!("../test_data/gully.dem");
+const SHORT_INPUT: &[u8] = include_bytes!("../test_data/short-2024.dem");
 
 fn bench_all() {
     let demo = Demo::new(INPUT);
@@ -34,4 +35,14 @@ fn bench_all() {
     );
 }
 
-iai::main!(bench_all);
+fn bench_short() {
+    let demo = Demo::new(SHORT_INPUT);
+    let stream = demo.get_stream();
+    black_box(
+        DemoParser::new_with_analyser(stream.clone(), AllMessages)
+            .parse()
+            .unwrap(),
+    );
+}
+
+iai::main!(bench_all, bench_short);
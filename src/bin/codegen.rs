@@ -3,6 +3,7 @@ use prettyplease::unparse;
 use std::env;
 use std::fs;
 use syn::{parse2, File};
+use tf_demo_parser::codegen::entities::generate_entity_structs;
 use tf_demo_parser::codegen::gameevent::generate_game_events;
 use tf_demo_parser::codegen::propnames::generate_prop_names;
 use tf_demo_parser::Demo;
@@ -21,6 +22,7 @@ fn main() -> std::result::Result<(), MainError> {
     let tokens = match args.get(2).map(|s| s.as_str()) {
         None | Some("events") => generate_game_events(demo),
         Some("props") => generate_prop_names(demo),
+        Some("entities") => generate_entity_structs(demo),
         _ => panic!("unsupported"),
     };
     let file = parse2::<File>(tokens)?;
@@ -0,0 +1,37 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::BufWriter;
+
+use main_error::MainError;
+use tf_demo_parser::demo::cut::cut_demo;
+use tf_demo_parser::Demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 5 {
+        println!("4 arguments required: <input> <output> <start tick> <end tick>");
+        return Ok(());
+    }
+    let path = args[1].clone();
+    let out_path = args[2].clone();
+    let start: u32 = args[3].parse()?;
+    let end: u32 = args[4].parse()?;
+    let file = fs::read(path)?;
+    let demo = Demo::new(&file);
+
+    cut_demo(
+        &demo,
+        BufWriter::new(File::create(out_path)?),
+        start.into(),
+        end.into(),
+    )?;
+
+    Ok(())
+}
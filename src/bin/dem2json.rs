@@ -0,0 +1,30 @@
+use std::env;
+use std::fs;
+use std::fs::File;
+use std::io::BufWriter;
+
+use main_error::MainError;
+use tf_demo_parser::demo::json::demo_to_json;
+use tf_demo_parser::Demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 3 {
+        println!("2 arguments required: <input> <output>");
+        return Ok(());
+    }
+    let path = args[1].clone();
+    let out_path = args[2].clone();
+    let file = fs::read(path)?;
+    let demo = Demo::new(&file);
+
+    demo_to_json(&demo, BufWriter::new(File::create(out_path)?))?;
+
+    Ok(())
+}
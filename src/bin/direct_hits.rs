@@ -2,9 +2,6 @@ use std::env;
 use std::fs;
 
 use main_error::MainError;
-use serde::{Deserialize, Serialize};
-use tf_demo_parser::demo::header::Header;
-use tf_demo_parser::demo::parser::analyser::MatchState;
 use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
 pub use tf_demo_parser::{Demo, DemoParser, Parse};
 
@@ -12,14 +9,6 @@ pub use tf_demo_parser::{Demo, DemoParser, Parse};
 #[global_allocator]
 static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
-#[derive(Serialize, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct JsonDemo {
-    header: Header,
-    #[serde(flatten)]
-    state: MatchState,
-}
-
 fn main() -> Result<(), MainError> {
     #[cfg(feature = "better-panic")]
     better_panic::install();
@@ -56,8 +45,7 @@ fn main() -> Result<(), MainError> {
                 .find(|player| {
                     player
                         .weapons
-                        .iter()
-                        .any(|weapon| collision.projectile.launcher == *weapon)
+                        .contains(&collision.projectile.launcher)
                 })
                 .and_then(|player| player.info.as_ref());
 
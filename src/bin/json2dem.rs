@@ -0,0 +1,29 @@
+use std::env;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+
+use main_error::MainError;
+use tf_demo_parser::demo::json::json_to_demo;
+
+fn main() -> Result<(), MainError> {
+    #[cfg(feature = "better-panic")]
+    better_panic::install();
+
+    #[cfg(feature = "trace")]
+    tracing_subscriber::fmt::init();
+
+    let args: Vec<_> = env::args().collect();
+    if args.len() < 3 {
+        println!("2 arguments required: <input> <output>");
+        return Ok(());
+    }
+    let path = args[1].clone();
+    let out_path = args[2].clone();
+
+    json_to_demo(
+        BufReader::new(File::open(path)?),
+        BufWriter::new(File::create(out_path)?),
+    )?;
+
+    Ok(())
+}
@@ -1,17 +1,23 @@
-#![allow(unused_imports)]
-
 use std::env;
 use std::fs;
+use std::fs::File;
+use std::io::BufWriter;
 
-use bitbuffer::{BitError, BitRead, BitWrite, BitWriteStream, LittleEndian};
 use main_error::MainError;
-use tf_demo_parser::demo::data::DemoTick;
-use tf_demo_parser::demo::header::Header;
-use tf_demo_parser::demo::message::{setconvar::SetConVarMessage, Message, NetTickMessage};
-use tf_demo_parser::demo::packet::stop::StopPacket;
-use tf_demo_parser::demo::packet::{Packet, PacketType};
-use tf_demo_parser::demo::parser::{DemoHandler, Encode, RawPacketStream};
-use tf_demo_parser::{Demo, ParseError};
+use tf_demo_parser::demo::packet::PacketType;
+use tf_demo_parser::demo::transform::{
+    rewrite_demo, Anonymizer, DropPackets, PacketTransform, PovToStv, PropArraySizes, StripVoice,
+    TransformPipeline,
+};
+use tf_demo_parser::Demo;
+
+const STAGES: &str =
+    "stv, array-sizes, strip-console, anonymize, strip-voice, strip-voice=<client>,<client>...";
+
+enum Stage {
+    Transform(Box<dyn PacketTransform>),
+    StripVoice(StripVoice),
+}
 
 fn main() -> Result<(), MainError> {
     #[cfg(feature = "trace")]
@@ -22,121 +28,77 @@ fn main() -> Result<(), MainError> {
 
     let args: Vec<_> = env::args().collect();
     if args.len() < 3 {
-        println!("2 argument required");
+        println!("usage: {} <input> <output> [stage...]", args[0]);
+        println!("available stages: {STAGES}");
         return Ok(());
     }
     let path = args[1].clone();
     let out_path = args[2].clone();
-    let file = fs::read(path)?;
-    let strip_pov = true;
-
-    let mut out_buffer = Vec::with_capacity(file.len());
-    {
-        let mut out_stream = BitWriteStream::new(&mut out_buffer, LittleEndian);
-
-        let demo = Demo::new(&file);
-        let mut stream = demo.get_stream();
-        let mut header = Header::read(&mut stream)?;
-        let mut packets = RawPacketStream::new(stream.clone());
-
-        // demos that are closed unexpectedly have no length set
-        if header.ticks == 0 {
-            header_fixup(&mut header, packets.clone())?;
-        }
-        header.write(&mut out_stream)?;
 
-        let mut handler = DemoHandler::default();
-        let mut encode_handler = DemoHandler::default();
-
-        let mut has_stop = false;
-        let mut last_tick = DemoTick::default();
-
-        while let Some(packet) = packets.next(&handler.state_handler)? {
-            last_tick = packet.tick();
-            if packet.packet_type() == PacketType::Stop {
-                has_stop = true;
+    // without explicit stages, convert the demo to an stv demo that supports 100 players
+    let stages: Vec<&str> = match &args[3..] {
+        [] => vec!["stv", "array-sizes", "strip-console"],
+        stages => stages.iter().map(String::as_str).collect(),
+    };
+
+    let mut transforms = Vec::with_capacity(stages.len());
+    for stage in stages {
+        let transform = match stage {
+            "stv" => Stage::Transform(Box::new(PovToStv)),
+            "array-sizes" => Stage::Transform(Box::new(PropArraySizes::hundred_players())),
+            "strip-console" => {
+                Stage::Transform(Box::new(DropPackets::new([PacketType::ConsoleCmd])))
             }
-
-            let mut encode_packet = packet.clone();
-            match &mut encode_packet {
-                Packet::DataTables(tables_packet) => {
-                    for table in tables_packet.tables.iter_mut() {
-                        for prop in table.props.iter_mut() {
-                            match (table.name.as_str(), prop.name.as_str()) {
-                                ("DT_ObjectDispenser", "\"healing_array\"") => {
-                                    prop.element_count = Some(101);
-                                }
-                                ("DT_Team", "\"player_array\"") => {
-                                    prop.element_count = Some(101);
-                                }
-                                ("DT_TFTeam", "\"team_object_array\"") => {
-                                    prop.element_count = Some(606);
-                                }
-                                _ => {}
-                            }
-                        }
-                    }
-                }
-                Packet::Signon(message_packet) | Packet::Message(message_packet) if strip_pov => {
-                    message_packet.meta.view_angles = Default::default();
-                    message_packet.messages.iter_mut().for_each(|msg| {
-                        if let Message::ServerInfo(info) = msg {
-                            info.stv = true;
-                        }
-                    });
-                }
-                _ => {}
+            "anonymize" => Stage::Transform(Box::new(Anonymizer::new())),
+            "strip-voice" => Stage::StripVoice(StripVoice::new()),
+            _ if stage.starts_with("strip-voice=") => {
+                let clients = stage
+                    .trim_start_matches("strip-voice=")
+                    .split(',')
+                    .map(str::parse)
+                    .collect::<Result<Vec<u8>, _>>()?;
+                Stage::StripVoice(StripVoice::clients(clients))
             }
-
-            if encode_packet.packet_type() != PacketType::ConsoleCmd {
-                encode_packet
-                    .encode(&mut out_stream, &encode_handler.state_handler)
-                    .unwrap();
+            _ => {
+                println!("unknown stage {stage}, available stages: {STAGES}");
+                return Ok(());
             }
+        };
+        transforms.push(transform);
+    }
 
-            handler.handle_packet(packet).unwrap();
-            encode_handler.handle_packet(encode_packet).unwrap();
-        }
-
-        if packets.incomplete {
-            eprintln!("Warning: truncated demo");
-        }
-
-        if !has_stop {
-            Packet::Stop(StopPacket { tick: last_tick })
-                .encode(&mut out_stream, &encode_handler.state_handler)
-                .unwrap();
+    let file = fs::read(path)?;
+    let demo = Demo::new(&file);
+    // the stages are borrowed so the removed voice data can be reported afterwards
+    let mut pipeline = TransformPipeline::new();
+    for transform in transforms.iter_mut() {
+        match transform {
+            Stage::Transform(transform) => pipeline.push(transform),
+            Stage::StripVoice(strip_voice) => pipeline.push(strip_voice),
         }
     }
-
-    fs::write(out_path, out_buffer)?;
-
-    Ok(())
-}
-
-fn header_fixup(header: &mut Header, mut packets: RawPacketStream) -> Result<(), MainError> {
-    let mut ticks = 0;
-    let mut handler = DemoHandler::default();
-    let mut tickrate = 66;
-
-    while let Some(packet) = packets.next(&handler.state_handler)? {
-        ticks = packet.tick().into();
-
-        if let Packet::Signon(message_packet) = &packet {
-            for message in &message_packet.messages {
-                if let Message::SetConVar(SetConVarMessage { vars, .. }) = message {
-                    for cvar in vars {
-                        if cvar.key == "sv_minupdaterate" {
-                            tickrate = cvar.value.parse().expect("invalid sv_minupdaterate");
-                        }
-                    }
-                }
-            }
+    rewrite_demo(
+        &demo,
+        BufWriter::new(File::create(&out_path)?),
+        &mut pipeline,
+    )?;
+    drop(pipeline);
+
+    for transform in transforms.iter() {
+        if let Stage::StripVoice(strip_voice) = transform {
+            println!(
+                "removed {} voice messages, saving {} bytes",
+                strip_voice.removed_messages(),
+                strip_voice.removed_bytes()
+            );
         }
-        handler.handle_packet(packet).unwrap();
     }
+    // modified packets can change in size, compressed string tables are compressed again with their original method
+    println!(
+        "input: {} bytes, output: {} bytes",
+        file.len(),
+        fs::metadata(&out_path)?.len()
+    );
 
-    header.ticks = ticks;
-    header.duration = ticks as f32 / tickrate as f32;
     Ok(())
 }
@@ -0,0 +1,797 @@
+use crate::demo::packet::datatable::{ParseSendTable, PropLocation, ServerClass};
+use crate::demo::parser::MessageHandler;
+use crate::demo::sendprop::{SendPropIdentifier, SendPropParseDefinition};
+use crate::{Demo, DemoParser, MessageType, ParserState};
+use fnv::FnvHashMap;
+use inflector::Inflector;
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use std::collections::HashSet;
+
+/// Server classes to generate structs for
+const CLASSES: &[&str] = &[
+    "CTFPlayer",
+    "CTFTeam",
+    "CObjectSentrygun",
+    "CObjectDispenser",
+    "CObjectTeleporter",
+    "CWeaponMedigun",
+    "CTFProjectile_Rocket",
+    "CTFGrenadePipebombProjectile",
+];
+
+/// Hungarian notation prefixes that are stripped from the prop names
+const TYPE_PREFIXES: &[&str] = &[
+    "i", "fl", "b", "n", "h", "vec", "ang", "sz", "isz", "ch", "e", "un", "ui", "f", "clr", "ub",
+    "us",
+];
+
+enum FieldType {
+    Bool,
+    Handle,
+    Integer { signed: bool, bits: u8 },
+    Float,
+    Vector,
+    VectorXY,
+    String,
+    Array,
+}
+
+impl FieldType {
+    fn new(prop_name: &str, definition: &SendPropParseDefinition) -> Self {
+        match definition {
+            SendPropParseDefinition::NormalVarInt { .. }
+            | SendPropParseDefinition::UnsignedInt { .. }
+            | SendPropParseDefinition::Int { .. }
+                if is_handle(prop_name) =>
+            {
+                FieldType::Handle
+            }
+            SendPropParseDefinition::UnsignedInt { bit_count: 1, .. } => FieldType::Bool,
+            SendPropParseDefinition::NormalVarInt { unsigned, .. } => FieldType::Integer {
+                signed: !unsigned,
+                bits: 32,
+            },
+            SendPropParseDefinition::UnsignedInt { bit_count, .. } => FieldType::Integer {
+                signed: false,
+                bits: *bit_count,
+            },
+            SendPropParseDefinition::Int { bit_count, .. } => FieldType::Integer {
+                signed: true,
+                bits: *bit_count,
+            },
+            SendPropParseDefinition::Float { .. } => FieldType::Float,
+            SendPropParseDefinition::String { .. } => FieldType::String,
+            SendPropParseDefinition::Vector { .. } => FieldType::Vector,
+            SendPropParseDefinition::VectorXY { .. } => FieldType::VectorXY,
+            SendPropParseDefinition::Array { .. } => FieldType::Array,
+        }
+    }
+
+    fn rust_type(&self) -> TokenStream {
+        match self {
+            FieldType::Bool => quote!(bool),
+            FieldType::Handle => quote!(Handle),
+            FieldType::Integer { signed, bits } => {
+                let bits = match bits {
+                    0..=8 => 8,
+                    9..=16 => 16,
+                    17..=32 => 32,
+                    _ => 64,
+                };
+                let sign = if *signed { "i" } else { "u" };
+                let ty = Ident::new(&format!("{}{}", sign, bits), Span::call_site());
+                quote!(#ty)
+            }
+            FieldType::Float => quote!(f32),
+            FieldType::Vector => quote!(Vector),
+            FieldType::VectorXY => quote!(VectorXY),
+            FieldType::String => quote!(String),
+            FieldType::Array => quote!(Vec<SendPropValue>),
+        }
+    }
+
+    /// Expression converting `prop.value` into an `Option` of the field type
+    fn convert_value(&self) -> TokenStream {
+        match self {
+            FieldType::Bool => quote!(i64::try_from(&prop.value).ok().map(|value| value != 0)),
+            FieldType::Handle => quote!(i64::try_from(&prop.value).ok().map(Handle)),
+            FieldType::Integer { .. } => {
+                let ty = self.rust_type();
+                quote!(i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| #ty::try_from(value).ok()))
+            }
+            FieldType::Float | FieldType::Vector | FieldType::VectorXY => {
+                quote!((&prop.value).try_into().ok())
+            }
+            FieldType::String => {
+                quote!(<&str>::try_from(&prop.value).ok().map(String::from))
+            }
+            FieldType::Array => {
+                quote!(<&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec))
+            }
+        }
+    }
+}
+
+/// Entity handles are named like `m_hOwner`
+fn is_handle(prop_name: &str) -> bool {
+    let name = prop_name.rsplit('.').next().unwrap_or(prop_name);
+    name.strip_prefix("m_h")
+        .is_some_and(|rest| rest.starts_with(|c: char| c.is_ascii_uppercase()))
+}
+
+struct FieldInfo {
+    identifier: SendPropIdentifier,
+    table_name: String,
+    prop_name: String,
+    ty: FieldType,
+    /// The location path of the prop if the same prop is included multiple times, sub tables
+    /// like the elements of `m_AnimOverlay` have the same props for every time they're included
+    path: Option<u64>,
+    /// The name of the data table prop that tells the prop apart from other fields with the same
+    /// identifier, like `m_RoundScoreData` and `m_ScoreData`
+    parent: Option<String>,
+    /// The array element of every time the prop is included at the path
+    elements: Vec<Option<u16>>,
+    /// The identifier, prop name and index of every element if the field holds the props of an
+    /// array table, which are named by their index like `m_flPoseParameter.000`
+    indexed: Vec<(SendPropIdentifier, String, u16)>,
+}
+
+impl FieldInfo {
+    /// The length of the array if the prop is part of an array of sub tables or an array table
+    fn array_len(&self) -> Option<usize> {
+        if !self.indexed.is_empty() {
+            return self
+                .indexed
+                .iter()
+                .map(|(_, _, index)| usize::from(*index) + 1)
+                .max();
+        }
+        self.path?;
+        let elements: Option<Vec<u16>> = self.elements.iter().copied().collect();
+        elements?.into_iter().max().map(|max| usize::from(max) + 1)
+    }
+
+    /// The name of the vector if the field is the length of a vector of sub tables,
+    /// vectors like `m_AnimOverlay` store their length in `_LPT_m_AnimOverlay_15.lengthprop15`
+    fn length_of(&self) -> Option<&str> {
+        self.prop_name.strip_prefix("lengthprop")?;
+        let name = self.table_name.strip_prefix("_LPT_")?;
+        Some(
+            name.trim_end_matches(|c: char| c.is_ascii_digit())
+                .trim_end_matches('_'),
+        )
+    }
+}
+
+/// Collect the names of the data table props leading to each location path, leaving out the
+/// array elements
+fn collect_location_names<'a>(
+    table: &'a ParseSendTable,
+    tables: &'a [ParseSendTable],
+    location: PropLocation,
+    names: &mut Vec<&'a str>,
+    table_stack: &mut Vec<&'a str>,
+    paths: &mut FnvHashMap<u64, Vec<String>>,
+) {
+    table_stack.push(table.name.as_str());
+    for prop in table.props.iter() {
+        let Some(sub_table) = prop.get_data_table(tables) else {
+            continue;
+        };
+        if table_stack.contains(&sub_table.name.as_str()) {
+            continue;
+        }
+        let location = location.enter(prop.name.as_str());
+        let is_element = prop.name.as_str().parse::<u16>().is_ok();
+        if !is_element {
+            names.push(prop.name.as_str());
+        }
+        paths
+            .entry(location.path)
+            .or_insert_with(|| names.iter().map(|name| name.to_string()).collect());
+        collect_location_names(sub_table, tables, location, names, table_stack, paths);
+        if !is_element {
+            names.pop();
+        }
+    }
+    table_stack.pop();
+}
+
+struct ClassInfo {
+    name: String,
+    fields: Vec<FieldInfo>,
+}
+
+#[derive(Default)]
+struct EntityClassAnalyzer {
+    classes: Vec<ClassInfo>,
+}
+
+impl MessageHandler for EntityClassAnalyzer {
+    type Output = Vec<ClassInfo>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        parse_tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        _state: &ParserState,
+    ) {
+        let prop_names: FnvHashMap<SendPropIdentifier, (String, String)> = parse_tables
+            .iter()
+            .flat_map(|table| {
+                table.props.iter().map(|prop| {
+                    (
+                        prop.identifier,
+                        (table.name.to_string(), prop.name.to_string()),
+                    )
+                })
+            })
+            .collect();
+
+        for class in server_classes {
+            if !CLASSES.contains(&class.name.as_str()) {
+                continue;
+            }
+            let Some(table) = parse_tables
+                .iter()
+                .find(|table| table.name == class.data_table)
+            else {
+                continue;
+            };
+            let Ok(props) = table.flatten_props_with_locations(parse_tables) else {
+                continue;
+            };
+            let mut location_names = FnvHashMap::default();
+            collect_location_names(
+                table,
+                parse_tables,
+                PropLocation::default(),
+                &mut Vec::new(),
+                &mut Vec::new(),
+                &mut location_names,
+            );
+
+            let mut counts: FnvHashMap<SendPropIdentifier, usize> = FnvHashMap::default();
+            for (prop, _) in props.iter() {
+                *counts.entry(prop.identifier).or_default() += 1;
+            }
+
+            let mut fields: Vec<FieldInfo> = Vec::new();
+            for (prop, location) in props.iter() {
+                let path = counts
+                    .get(&prop.identifier)
+                    .is_some_and(|count| *count > 1)
+                    .then_some(location.path);
+                if let Some(field) = fields
+                    .iter_mut()
+                    .find(|field| field.identifier == prop.identifier && field.path == path)
+                {
+                    field.elements.push(location.element);
+                    continue;
+                }
+                let Some((table_name, prop_name)) = prop_names.get(&prop.identifier).cloned()
+                else {
+                    continue;
+                };
+                // the props of array tables are named by their index, the table is named after
+                // the array
+                let type_name = match prop_name.parse::<u16>() {
+                    Ok(_) => table_name.trim_start_matches("_ST_"),
+                    Err(_) => prop_name.as_str(),
+                };
+                fields.push(FieldInfo {
+                    identifier: prop.identifier,
+                    ty: FieldType::new(type_name, &prop.parse_definition),
+                    table_name,
+                    prop_name,
+                    path,
+                    parent: None,
+                    elements: vec![location.element],
+                    indexed: Vec::new(),
+                });
+            }
+            group_indexed_fields(&mut fields);
+            set_field_parents(&mut fields, &location_names);
+
+            self.classes.push(ClassInfo {
+                name: class.name.to_string(),
+                fields,
+            });
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.classes
+    }
+}
+
+/// Merge the props of array tables into a single field per table
+fn group_indexed_fields(fields: &mut Vec<FieldInfo>) {
+    let mut grouped: Vec<FieldInfo> = Vec::with_capacity(fields.len());
+    for field in fields.drain(..) {
+        let index = match (field.path, field.prop_name.parse::<u16>()) {
+            (None, Ok(index)) => index,
+            _ => {
+                grouped.push(field);
+                continue;
+            }
+        };
+        match grouped
+            .iter_mut()
+            .find(|array| !array.indexed.is_empty() && array.table_name == field.table_name)
+        {
+            Some(array) => array
+                .indexed
+                .push((field.identifier, field.prop_name, index)),
+            None => grouped.push(FieldInfo {
+                indexed: vec![(field.identifier, field.prop_name.clone(), index)],
+                ..field
+            }),
+        }
+    }
+    *fields = grouped;
+}
+
+/// Name the fields that have the same identifier after the first data table prop where their
+/// locations differ
+fn set_field_parents(fields: &mut [FieldInfo], location_names: &FnvHashMap<u64, Vec<String>>) {
+    let no_names = Vec::new();
+    let names = |field: &FieldInfo| {
+        field
+            .path
+            .and_then(|path| location_names.get(&path))
+            .unwrap_or(&no_names)
+    };
+
+    let parents: Vec<Option<String>> = fields
+        .iter()
+        .map(|field| {
+            let field_names = names(field);
+            let others: Vec<&Vec<String>> = fields
+                .iter()
+                .filter(|other| other.identifier == field.identifier && other.path != field.path)
+                .map(names)
+                .collect();
+            if others.is_empty() {
+                return None;
+            }
+            let common = others
+                .iter()
+                .map(|other| {
+                    field_names
+                        .iter()
+                        .zip(other.iter())
+                        .take_while(|(a, b)| a == b)
+                        .count()
+                })
+                .min()
+                .unwrap_or(0);
+            field_names.get(common).cloned()
+        })
+        .collect();
+
+    for (field, parent) in fields.iter_mut().zip(parents) {
+        field.parent = parent;
+    }
+}
+
+fn strip_prop_name(name: &str) -> &str {
+    let name = name.strip_prefix("m_").unwrap_or(name);
+    let prefix_len = name
+        .find(|c: char| !c.is_ascii_lowercase() && !c.is_ascii_digit())
+        .unwrap_or(0);
+    let (prefix, rest) = name.split_at(prefix_len);
+    if TYPE_PREFIXES.contains(&prefix) && rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+        rest
+    } else {
+        name
+    }
+}
+
+fn sanitize(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    name.to_snake_case()
+}
+
+fn is_keyword(name: &str) -> bool {
+    matches!(
+        name,
+        "as" | "break"
+            | "const"
+            | "continue"
+            | "crate"
+            | "else"
+            | "enum"
+            | "extern"
+            | "false"
+            | "fn"
+            | "for"
+            | "if"
+            | "impl"
+            | "in"
+            | "let"
+            | "loop"
+            | "match"
+            | "mod"
+            | "move"
+            | "mut"
+            | "pub"
+            | "ref"
+            | "return"
+            | "self"
+            | "static"
+            | "struct"
+            | "super"
+            | "trait"
+            | "true"
+            | "type"
+            | "unsafe"
+            | "use"
+            | "where"
+            | "while"
+            | "box"
+            | "dyn"
+            | "async"
+            | "await"
+            | "try"
+    )
+}
+
+/// Convert a prop name like `m_PlayerFog.m_hCtrl` into a field name like `player_fog_ctrl`
+fn get_prop_field_name(name: &str) -> String {
+    name.split('.')
+        .map(|part| sanitize(strip_prop_name(part)))
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+fn get_table_field_prefix(table_name: &str) -> String {
+    let name = table_name
+        .strip_prefix("DT_")
+        .or_else(|| table_name.strip_prefix("_ST_"))
+        .unwrap_or(table_name);
+    // array tables are suffixed with their size
+    let name = name.trim_end_matches(|c: char| c.is_ascii_digit());
+    get_prop_field_name(name.trim_end_matches('_'))
+}
+
+fn get_field_names(fields: &[FieldInfo]) -> Vec<String> {
+    let base_names: Vec<String> = fields
+        .iter()
+        .map(|field| {
+            let name = get_prop_field_name(&field.prop_name);
+            // elements of array tables are named by their index
+            let name = if let Some(vector) = field.length_of() {
+                format!("{}_len", get_prop_field_name(vector))
+            } else if !field.indexed.is_empty() {
+                get_table_field_prefix(&field.table_name)
+            } else if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+                format!("{}_{}", get_table_field_prefix(&field.table_name), name)
+            } else {
+                name
+            };
+            match &field.parent {
+                Some(parent) => format!("{}_{}", get_prop_field_name(parent), name),
+                None => name,
+            }
+        })
+        .collect();
+
+    let mut seen = HashSet::new();
+    let duplicates: HashSet<&String> = base_names
+        .iter()
+        .filter(|name| !seen.insert(*name))
+        .collect();
+
+    let mut used = HashSet::new();
+    fields
+        .iter()
+        .zip(base_names.iter())
+        .map(|(field, name)| {
+            let mut name = if duplicates.contains(name) {
+                format!("{}_{}", get_table_field_prefix(&field.table_name), name)
+            } else {
+                name.clone()
+            };
+            if is_keyword(&name) {
+                name.push('_');
+            }
+            while !used.insert(name.clone()) {
+                name.push('_');
+            }
+            name
+        })
+        .collect()
+}
+
+/// Name the constant for the identifier of a prop after the field it's stored in
+fn get_const_name(field_name: &str, used: &mut HashSet<String>) -> Ident {
+    let mut name = field_name.trim_end_matches('_').to_uppercase();
+    while !used.insert(name.clone()) {
+        name.push('_');
+    }
+    Ident::new(&name, Span::call_site())
+}
+
+/// Code that sets the field to the value of `prop`
+fn field_assignment(field: &FieldInfo, name: &Ident) -> TokenStream {
+    let value = field.ty.convert_value();
+    if field.array_len().is_none() {
+        let ty = field.ty.rust_type();
+        return quote!(
+            let value: Option<#ty> = #value;
+            if let Some(value) = value {
+                self.#name = value;
+            }
+        );
+    }
+    quote!(
+        let field = element.and_then(|element| self.#name.get_mut(usize::from(element)));
+        if let (Some(value), Some(field)) = (#value, field) {
+            *field = value;
+        }
+    )
+}
+
+/// Code that sets the element of an array table field to the value of `prop`
+fn element_assignment(field: &FieldInfo, name: &Ident, index: u16) -> TokenStream {
+    let value = field.ty.convert_value();
+    let ty = field.ty.rust_type();
+    let index = Literal::usize_unsuffixed(usize::from(index));
+    quote!(
+        let value: Option<#ty> = #value;
+        if let Some(value) = value {
+            self.#name[#index] = value;
+        }
+    )
+}
+
+fn get_struct_name(class_name: &str) -> String {
+    let name = class_name.strip_prefix('C').unwrap_or(class_name);
+    name.split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+pub fn generate_entity_structs(demo: Demo) -> TokenStream {
+    #[allow(clippy::unwrap_used)]
+    let (_, classes) =
+        DemoParser::new_with_analyser(demo.get_stream(), EntityClassAnalyzer::default())
+            .parse()
+            .unwrap();
+
+    let imports = quote!(
+        use crate::demo::data::game_state::Handle;
+        use crate::demo::message::packetentities::PacketEntity;
+        use crate::demo::packet::datatable::PropLocation;
+        use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+        use crate::demo::vector::{Vector, VectorXY};
+        use crate::{ParserState, Result};
+    );
+
+    let structs = classes.iter().map(|class| {
+        let class_name = &class.name;
+        let struct_name = Ident::new(&get_struct_name(class_name), Span::call_site());
+        let doc = format!("Typed props for the `{}` server class", class_name);
+        let field_names: Vec<Ident> = get_field_names(&class.fields)
+            .iter()
+            .map(|name| Ident::new(name, Span::call_site()))
+            .collect();
+
+        let fields = class
+            .fields
+            .iter()
+            .zip(field_names.iter())
+            .map(|(field, name)| {
+                let doc = match (&field.parent, field.length_of()) {
+                    (Some(parent), Some(vector)) => {
+                        format!("The number of elements in `{}` in `{}`", vector, parent)
+                    }
+                    (None, Some(vector)) => format!("The number of elements in `{}`", vector),
+                    _ if !field.indexed.is_empty() => {
+                        format!("The elements of the `{}` array table", field.table_name)
+                    }
+                    (Some(parent), None) => {
+                        format!("`{}.{}` in `{}`", field.table_name, field.prop_name, parent)
+                    }
+                    (None, None) => format!("`{}.{}`", field.table_name, field.prop_name),
+                };
+                let ty = field.ty.rust_type();
+                let ty = match field.array_len() {
+                    None => ty,
+                    Some(len) => {
+                        let len = Literal::usize_unsuffixed(len);
+                        quote!([#ty; #len])
+                    }
+                };
+                quote!(
+                    #[doc = #doc]
+                    pub #name: #ty
+                )
+            });
+
+        // the props are matched by named constants for their identifiers
+        let mut const_names = HashSet::new();
+        let mut consts = Vec::new();
+        let mut indexed_arms = Vec::new();
+        for (field, name) in class.fields.iter().zip(field_names.iter()) {
+            for (_, prop_name, index) in field.indexed.iter() {
+                let const_name = get_const_name(&format!("{}_{}", name, prop_name), &mut const_names);
+                let table_name = &field.table_name;
+                consts.push(quote!(
+                    const #const_name: SendPropIdentifier = SendPropIdentifier::new(#table_name, #prop_name);
+                ));
+                let body = element_assignment(field, name, *index);
+                indexed_arms.push(quote!(#const_name => { #body }));
+            }
+        }
+
+        let mut identifiers: Vec<(SendPropIdentifier, Ident)> = Vec::new();
+        for (field, name) in class.fields.iter().zip(field_names.iter()) {
+            if field.indexed.is_empty()
+                && !identifiers
+                    .iter()
+                    .any(|(identifier, _)| *identifier == field.identifier)
+            {
+                let const_name = get_const_name(&name.to_string(), &mut const_names);
+                let (table_name, prop_name) = (&field.table_name, &field.prop_name);
+                consts.push(quote!(
+                    const #const_name: SendPropIdentifier = SendPropIdentifier::new(#table_name, #prop_name);
+                ));
+                identifiers.push((field.identifier, const_name));
+            }
+        }
+        let arms = identifiers.iter().map(|(identifier, const_name)| {
+            let fields: Vec<(&FieldInfo, &Ident)> = class
+                .fields
+                .iter()
+                .zip(field_names.iter())
+                .filter(|(field, _)| field.identifier == *identifier)
+                .collect();
+            let identifier = const_name;
+            if let [(field, name)] = fields.as_slice() {
+                if field.path.is_none() {
+                    let body = field_assignment(field, name);
+                    return quote!(#identifier => { #body });
+                }
+            }
+            // props that are included multiple times are told apart by their location
+            let location_arms: Vec<(TokenStream, TokenStream)> = fields
+                .iter()
+                .map(|(field, name)| {
+                    let path = Literal::u64_unsuffixed(field.path.unwrap_or_default());
+                    let body = field_assignment(field, name);
+                    let element = match field.array_len() {
+                        Some(_) => quote!(element),
+                        None => quote!(..),
+                    };
+                    (quote!(Some(PropLocation { path: #path, #element })), body)
+                })
+                .collect();
+            if let [(pattern, body)] = location_arms.as_slice() {
+                return quote!(
+                    #identifier => {
+                        if let #pattern = location {
+                            #body
+                        }
+                    }
+                );
+            }
+            let location_arms = location_arms
+                .iter()
+                .map(|(pattern, body)| quote!(#pattern => { #body }));
+            quote!(
+                #identifier => match location {
+                    #(#location_arms)*
+                    _ => {}
+                }
+            )
+        });
+
+        // arrays longer than 32 elements don't implement `Default`, so it can't always be derived
+        let has_arrays = class.fields.iter().any(|field| field.array_len().is_some());
+        let (derive, default_impl) = if !has_arrays {
+            (
+                quote!(#[derive(Debug, Clone, Default, PartialEq)]),
+                quote!(),
+            )
+        } else {
+            let defaults = class
+                .fields
+                .iter()
+                .zip(field_names.iter())
+                .map(|(field, name)| match field.array_len() {
+                    None => quote!(#name: Default::default()),
+                    Some(_) => quote!(#name: std::array::from_fn(|_| Default::default())),
+                });
+            let default_impl = quote!(
+                impl Default for #struct_name {
+                    fn default() -> Self {
+                        #struct_name {
+                            #(#defaults,)*
+                        }
+                    }
+                }
+            );
+            (quote!(#[derive(Debug, Clone, PartialEq)]), default_impl)
+        };
+
+        // only props that are included multiple times need their location
+        let location = if class.fields.iter().any(|field| field.path.is_some()) {
+            quote!(location)
+        } else {
+            quote!(_location)
+        };
+
+        quote!(
+            #[doc = #doc]
+            #derive
+            pub struct #struct_name {
+                #(#fields,)*
+            }
+
+            #default_impl
+
+            impl #struct_name {
+                pub const CLASS_NAME: &'static str = #class_name;
+
+                /// Create the struct from an entity containing the full set of props
+                pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+                    let mut result = Self::default();
+                    result.apply(entity, state)?;
+                    Ok(result)
+                }
+
+                /// Update the fields with the props of the entity
+                ///
+                /// Fails if the lazily decoded props of the entity can't be decoded
+                pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+                    for prop in entity.try_props(state)? {
+                        let location = state.prop_location(entity.server_class, prop.index);
+                        self.apply_prop(&prop, location);
+                    }
+                    Ok(())
+                }
+
+                /// Update the field matching the prop, if any
+                ///
+                /// `location` is the location of the prop as returned by
+                /// [`ParserState::prop_location`]
+                pub fn apply_prop(&mut self, prop: &SendProp, #location: Option<PropLocation>) {
+                    #(#consts)*
+
+                    match prop.identifier {
+                        #(#arms)*
+                        #(#indexed_arms)*
+                        _ => {}
+                    }
+                }
+            }
+        )
+    });
+
+    quote!(
+        #imports
+
+        #(#structs)*
+    )
+}
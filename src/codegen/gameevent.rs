@@ -21,7 +21,7 @@ impl MessageHandler for GameEventAnalyser {
     }
 
     fn into_output(self, state: &ParserState) -> Self::Output {
-        state.event_definitions.clone()
+        state.event_definitions.to_vec()
     }
 }
 
@@ -0,0 +1,170 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::{
+    BaselineIndex, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::message::{Message, NetTickMessage};
+use crate::demo::packet::message::{MessagePacket, MessagePacketMeta, PacketPadding};
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use crate::demo::writer::DemoWriter;
+use crate::{Demo, ParserState, Result};
+use bitbuffer::BitRead;
+use std::io::{Seek, Write};
+
+/// Cut the ticks from `start` up to and including `end` out of a demo into a new standalone demo
+///
+/// The signon data, data tables and string tables of the demo are copied as is, followed by a
+/// synthesised message packet containing a full entity snapshot of the demo at the start tick and
+/// all string table and convar changes from the skipped part of the demo.
+/// After that the original packets up to the end tick are copied, with the ticks rebased to start at 0.
+///
+/// Entities entering after the start tick are written with their full props, since the instance
+/// baselines they are relative to in the original demo don't exist in the cut demo.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::cut::cut_demo;
+/// # use std::fs::File;
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// let file = std::fs::read("in.dem")?;
+/// let demo = Demo::new(&file);
+/// cut_demo(&demo, File::create("clip.dem")?, 1000.into(), 2000.into())?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn cut_demo<W: Write + Seek>(demo: &Demo, out: W, start: DemoTick, end: DemoTick) -> Result<W> {
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(
+        NullHandler,
+        ParserConfig::new().parse_all(true).track_entities(true),
+    );
+    let mut writer = DemoWriter::new(out, header)?.update_header(true);
+    let mut snapshot = Snapshot::default();
+    let mut started = false;
+
+    while let Some(packet) = packets.next(&handler.state_handler)? {
+        match packet.packet_type() {
+            // signon data is always needed to play back the demo
+            PacketType::Signon
+            | PacketType::DataTables
+            | PacketType::SyncTick
+            | PacketType::StringTables => {
+                writer.write_packet(packet.clone())?;
+            }
+            PacketType::Stop => break,
+            _ if packet.tick() < start => {
+                if let Packet::Message(message_packet) = &packet {
+                    snapshot.skip(message_packet);
+                }
+            }
+            _ if packet.tick() > end => break,
+            _ => {
+                if !started {
+                    writer.write_packet(snapshot.packet(&handler.state_handler))?;
+                    started = true;
+                }
+                let mut cut_packet = packet.clone();
+                cut_packet.set_tick(rebase(packet.tick(), start));
+                if let Packet::Message(message_packet) = &mut cut_packet {
+                    expand_entering(message_packet, &handler.state_handler)?;
+                }
+                writer.write_packet(cut_packet)?;
+            }
+        }
+
+        handler.handle_packet(packet)?;
+    }
+
+    // the range starts after the end of the demo, only the final state remains
+    if !started {
+        writer.write_packet(snapshot.packet(&handler.state_handler))?;
+    }
+
+    writer.finish()
+}
+
+fn rebase(tick: DemoTick, start: DemoTick) -> DemoTick {
+    u32::from(tick).saturating_sub(start.into()).into()
+}
+
+/// Replace the props of entering entities with the full props including their baseline
+fn expand_entering(packet: &mut MessagePacket, state: &ParserState) -> Result<()> {
+    for message in packet.messages.iter_mut() {
+        if let Message::PacketEntities(entities) = message {
+            for entity in entities.entities.iter_mut() {
+                if entity.update_type == UpdateType::Enter {
+                    let props = entity.try_props(state)?.collect();
+                    entity.props = props;
+                    entity.lazy_props = None;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The state from the skipped part of the demo that needs to be carried over into the cut demo
+#[derive(Default)]
+struct Snapshot<'a> {
+    net_tick: Option<NetTickMessage>,
+    messages: Vec<Message<'a>>,
+    meta: MessagePacketMeta,
+    max_entries: u16,
+}
+
+impl<'a> Snapshot<'a> {
+    fn skip(&mut self, packet: &MessagePacket<'a>) {
+        self.meta = packet.meta.clone();
+        for message in packet.messages.iter() {
+            match message {
+                Message::NetTick(net_tick) => self.net_tick = Some(net_tick.clone()),
+                Message::PacketEntities(entities) => self.max_entries = entities.max_entries,
+                Message::CreateStringTable(_)
+                | Message::UpdateStringTable(_)
+                | Message::SetConVar(_)
+                | Message::SetView(_) => self.messages.push(message.clone()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Create a packet with the carried over messages and a full update of all entities in the pvs
+    fn packet(&mut self, state: &ParserState) -> Packet<'a> {
+        let mut entities: Vec<PacketEntity> = state
+            .entities
+            .iter()
+            .filter(|entity| entity.in_pvs)
+            .map(|entity| PacketEntity {
+                update_type: UpdateType::Enter,
+                delta: None,
+                baseline_index: BaselineIndex::First,
+                lazy_props: None,
+                ..entity.clone()
+            })
+            .collect();
+        entities.sort_by_key(|entity| entity.entity_index);
+
+        let mut messages = Vec::with_capacity(self.messages.len() + 2);
+        messages.extend(self.net_tick.take().map(Message::NetTick));
+        messages.append(&mut self.messages);
+        messages.push(Message::PacketEntities(PacketEntitiesMessage {
+            max_entries: self.max_entries.max(entities.len() as u16),
+            entities,
+            removed_entities: Vec::new(),
+            delta: None,
+            base_line: BaselineIndex::First,
+            updated_base_line: false,
+        }));
+
+        Packet::Message(MessagePacket {
+            tick: DemoTick::default(),
+            messages,
+            meta: std::mem::take(&mut self.meta),
+            padding: PacketPadding::default(),
+            data_remaining: 0,
+        })
+    }
+}
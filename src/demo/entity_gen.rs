@@ -0,0 +1,19010 @@
+use crate::demo::data::game_state::Handle;
+use crate::demo::message::packetentities::PacketEntity;
+use crate::demo::packet::datatable::PropLocation;
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::demo::vector::{Vector, VectorXY};
+use crate::{ParserState, Result};
+///Typed props for the `CObjectDispenser` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectDispenser {
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub anim_time: u8,
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub server_animation_data_cycle: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_BaseEntity.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///The number of elements in `m_AnimOverlay`
+    pub anim_overlay_len: u8,
+    ///`DT_Animationlayer.m_nSequence`
+    pub animationlayer_sequence: [u16; 15],
+    ///`DT_Animationlayer.m_flCycle`
+    pub animationlayer_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub prev_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flWeight`
+    pub weight: [f32; 15],
+    ///`DT_Animationlayer.m_nOrder`
+    pub order: [u8; 15],
+    ///The elements of the `m_flexWeight` array table
+    pub flex_weight: [f32; 96],
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub next_attack: f32,
+    ///The elements of the `m_hMyWeapons` array table
+    pub my_weapons: [Handle; 48],
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub base_animating_sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub blinktoggle: bool,
+    ///`DT_BaseFlex.m_viewtarget`
+    pub viewtarget: Vector,
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub glow_enabled: bool,
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub active_weapon: Handle,
+    ///`DT_BaseObject.m_iHealth`
+    pub health: i32,
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub max_health: i32,
+    ///`DT_BaseObject.m_bHasSapper`
+    pub has_sapper: bool,
+    ///`DT_BaseObject.m_iObjectType`
+    pub object_type: u8,
+    ///`DT_BaseObject.m_bBuilding`
+    pub building: bool,
+    ///`DT_BaseObject.m_bPlacing`
+    pub placing: bool,
+    ///`DT_BaseObject.m_bCarried`
+    pub carried: bool,
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub carry_deploy: bool,
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub mini_building: bool,
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub percentage_constructed: f32,
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub object_flags: u8,
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub built_on_entity: Handle,
+    ///`DT_BaseObject.m_bDisabled`
+    pub disabled: bool,
+    ///`DT_BaseObject.m_hBuilder`
+    pub builder: Handle,
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub build_maxs: Vector,
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub build_mins: Vector,
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub desired_build_rotations: u8,
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub server_override_placement: bool,
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub upgrade_level: i8,
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub upgrade_metal: i16,
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub upgrade_metal_required: i16,
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub highest_upgrade_level: i8,
+    ///`DT_BaseObject.m_iObjectMode`
+    pub object_mode: u8,
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub disposable_building: bool,
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub was_map_placed: bool,
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub plasma_disable: bool,
+    ///`DT_ObjectDispenser.m_iState`
+    pub state: i8,
+    ///`DT_ObjectDispenser.m_iAmmoMetal`
+    pub ammo_metal: i32,
+    ///`DT_ObjectDispenser.m_iMiniBombCounter`
+    pub mini_bomb_counter: i32,
+    ///`DT_ObjectDispenser."healing_array"`
+    pub healing_array: Vec<SendPropValue>,
+}
+impl Default for ObjectDispenser {
+    fn default() -> Self {
+        ObjectDispenser {
+            anim_time: Default::default(),
+            server_animation_data_cycle: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            solid_type: Default::default(),
+            solid_flags: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            anim_overlay_len: Default::default(),
+            animationlayer_sequence: std::array::from_fn(|_| Default::default()),
+            animationlayer_cycle: std::array::from_fn(|_| Default::default()),
+            prev_cycle: std::array::from_fn(|_| Default::default()),
+            weight: std::array::from_fn(|_| Default::default()),
+            order: std::array::from_fn(|_| Default::default()),
+            flex_weight: std::array::from_fn(|_| Default::default()),
+            next_attack: Default::default(),
+            my_weapons: std::array::from_fn(|_| Default::default()),
+            maxs_pre_scaled: Default::default(),
+            mins: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            maxs: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            base_animating_sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            blinktoggle: Default::default(),
+            viewtarget: Default::default(),
+            glow_enabled: Default::default(),
+            active_weapon: Default::default(),
+            health: Default::default(),
+            max_health: Default::default(),
+            has_sapper: Default::default(),
+            object_type: Default::default(),
+            building: Default::default(),
+            placing: Default::default(),
+            carried: Default::default(),
+            carry_deploy: Default::default(),
+            mini_building: Default::default(),
+            percentage_constructed: Default::default(),
+            object_flags: Default::default(),
+            built_on_entity: Default::default(),
+            disabled: Default::default(),
+            builder: Default::default(),
+            build_maxs: Default::default(),
+            build_mins: Default::default(),
+            desired_build_rotations: Default::default(),
+            server_override_placement: Default::default(),
+            upgrade_level: Default::default(),
+            upgrade_metal: Default::default(),
+            upgrade_metal_required: Default::default(),
+            highest_upgrade_level: Default::default(),
+            object_mode: Default::default(),
+            disposable_building: Default::default(),
+            was_map_placed: Default::default(),
+            plasma_disable: Default::default(),
+            state: Default::default(),
+            ammo_metal: Default::default(),
+            mini_bomb_counter: Default::default(),
+            healing_array: Default::default(),
+        }
+    }
+}
+impl ObjectDispenser {
+    pub const CLASS_NAME: &'static str = "CObjectDispenser";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const FLEX_WEIGHT_000: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        const FLEX_WEIGHT_001: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        const FLEX_WEIGHT_002: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        const FLEX_WEIGHT_003: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        const FLEX_WEIGHT_004: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        const FLEX_WEIGHT_005: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        const FLEX_WEIGHT_006: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        const FLEX_WEIGHT_007: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        const FLEX_WEIGHT_008: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        const FLEX_WEIGHT_009: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        const FLEX_WEIGHT_010: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        const FLEX_WEIGHT_011: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        const FLEX_WEIGHT_012: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        const FLEX_WEIGHT_013: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        const FLEX_WEIGHT_014: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        const FLEX_WEIGHT_015: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        const FLEX_WEIGHT_016: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        const FLEX_WEIGHT_017: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        const FLEX_WEIGHT_018: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        const FLEX_WEIGHT_019: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        const FLEX_WEIGHT_020: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        const FLEX_WEIGHT_021: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        const FLEX_WEIGHT_022: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        const FLEX_WEIGHT_023: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        const FLEX_WEIGHT_024: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        const FLEX_WEIGHT_025: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        const FLEX_WEIGHT_026: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        const FLEX_WEIGHT_027: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        const FLEX_WEIGHT_028: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        const FLEX_WEIGHT_029: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        const FLEX_WEIGHT_030: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        const FLEX_WEIGHT_031: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        const FLEX_WEIGHT_032: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        const FLEX_WEIGHT_033: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        const FLEX_WEIGHT_034: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        const FLEX_WEIGHT_035: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        const FLEX_WEIGHT_036: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        const FLEX_WEIGHT_037: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        const FLEX_WEIGHT_038: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        const FLEX_WEIGHT_039: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        const FLEX_WEIGHT_040: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        const FLEX_WEIGHT_041: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        const FLEX_WEIGHT_042: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        const FLEX_WEIGHT_043: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        const FLEX_WEIGHT_044: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        const FLEX_WEIGHT_045: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        const FLEX_WEIGHT_046: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        const FLEX_WEIGHT_047: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        const FLEX_WEIGHT_048: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        const FLEX_WEIGHT_049: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        const FLEX_WEIGHT_050: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        const FLEX_WEIGHT_051: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        const FLEX_WEIGHT_052: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        const FLEX_WEIGHT_053: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        const FLEX_WEIGHT_054: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        const FLEX_WEIGHT_055: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        const FLEX_WEIGHT_056: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        const FLEX_WEIGHT_057: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        const FLEX_WEIGHT_058: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        const FLEX_WEIGHT_059: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        const FLEX_WEIGHT_060: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        const FLEX_WEIGHT_061: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        const FLEX_WEIGHT_062: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        const FLEX_WEIGHT_063: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        const FLEX_WEIGHT_064: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        const FLEX_WEIGHT_065: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        const FLEX_WEIGHT_066: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        const FLEX_WEIGHT_067: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        const FLEX_WEIGHT_068: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        const FLEX_WEIGHT_069: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        const FLEX_WEIGHT_070: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        const FLEX_WEIGHT_071: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        const FLEX_WEIGHT_072: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        const FLEX_WEIGHT_073: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        const FLEX_WEIGHT_074: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        const FLEX_WEIGHT_075: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        const FLEX_WEIGHT_076: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        const FLEX_WEIGHT_077: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        const FLEX_WEIGHT_078: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        const FLEX_WEIGHT_079: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        const FLEX_WEIGHT_080: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        const FLEX_WEIGHT_081: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        const FLEX_WEIGHT_082: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        const FLEX_WEIGHT_083: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        const FLEX_WEIGHT_084: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        const FLEX_WEIGHT_085: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        const FLEX_WEIGHT_086: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        const FLEX_WEIGHT_087: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        const FLEX_WEIGHT_088: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        const FLEX_WEIGHT_089: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        const FLEX_WEIGHT_090: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        const FLEX_WEIGHT_091: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        const FLEX_WEIGHT_092: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        const FLEX_WEIGHT_093: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        const FLEX_WEIGHT_094: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        const FLEX_WEIGHT_095: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        const MY_WEAPONS_000: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        const MY_WEAPONS_001: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        const MY_WEAPONS_002: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        const MY_WEAPONS_003: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        const MY_WEAPONS_004: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        const MY_WEAPONS_005: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        const MY_WEAPONS_006: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        const MY_WEAPONS_007: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        const MY_WEAPONS_008: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        const MY_WEAPONS_009: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        const MY_WEAPONS_010: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        const MY_WEAPONS_011: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        const MY_WEAPONS_012: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        const MY_WEAPONS_013: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        const MY_WEAPONS_014: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        const MY_WEAPONS_015: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        const MY_WEAPONS_016: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        const MY_WEAPONS_017: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        const MY_WEAPONS_018: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        const MY_WEAPONS_019: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        const MY_WEAPONS_020: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        const MY_WEAPONS_021: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        const MY_WEAPONS_022: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        const MY_WEAPONS_023: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        const MY_WEAPONS_024: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        const MY_WEAPONS_025: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        const MY_WEAPONS_026: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        const MY_WEAPONS_027: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        const MY_WEAPONS_028: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        const MY_WEAPONS_029: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        const MY_WEAPONS_030: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        const MY_WEAPONS_031: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        const MY_WEAPONS_032: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        const MY_WEAPONS_033: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        const MY_WEAPONS_034: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        const MY_WEAPONS_035: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        const MY_WEAPONS_036: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        const MY_WEAPONS_037: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        const MY_WEAPONS_038: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        const MY_WEAPONS_039: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        const MY_WEAPONS_040: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        const MY_WEAPONS_041: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        const MY_WEAPONS_042: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        const MY_WEAPONS_043: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        const MY_WEAPONS_044: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        const MY_WEAPONS_045: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        const MY_WEAPONS_046: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        const MY_WEAPONS_047: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        const ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        const SERVER_ANIMATION_DATA_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const ANIM_OVERLAY_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        const ANIMATIONLAYER_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        const ANIMATIONLAYER_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        const PREV_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        const WEIGHT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        const ORDER: SendPropIdentifier = SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        const NEXT_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const BASE_ANIMATING_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const BLINKTOGGLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        const VIEWTARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        const GLOW_ENABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        const ACTIVE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        const MAX_HEALTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        const HAS_SAPPER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        const OBJECT_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        const BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        const PLACING: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        const CARRIED: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        const CARRY_DEPLOY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        const MINI_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        const PERCENTAGE_CONSTRUCTED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        const OBJECT_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        const BUILT_ON_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        const DISABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        const BUILDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        const BUILD_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        const BUILD_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        const DESIRED_BUILD_ROTATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        const SERVER_OVERRIDE_PLACEMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        const UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        const UPGRADE_METAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        const UPGRADE_METAL_REQUIRED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        const HIGHEST_UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        const OBJECT_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        const DISPOSABLE_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        const WAS_MAP_PLACED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        const PLASMA_DISABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        const STATE: SendPropIdentifier = SendPropIdentifier::new("DT_ObjectDispenser", "m_iState");
+        const AMMO_METAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iAmmoMetal");
+        const MINI_BOMB_COUNTER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "m_iMiniBombCounter");
+        const HEALING_ARRAY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectDispenser", "\"healing_array\"");
+        match prop.identifier {
+            ANIM_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_time = value;
+                }
+            }
+            SERVER_ANIMATION_DATA_CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.server_animation_data_cycle = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            ANIM_OVERLAY_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_overlay_len = value;
+                }
+            }
+            ANIMATIONLAYER_SEQUENCE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_sequence.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            ANIMATIONLAYER_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_cycle.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            PREV_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.prev_cycle.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            WEIGHT => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.weight.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            ORDER => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.order.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u8::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            NEXT_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_attack = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            BASE_ANIMATING_SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.base_animating_sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            BLINKTOGGLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.blinktoggle = value;
+                }
+            }
+            VIEWTARGET => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.viewtarget = value;
+                }
+            }
+            GLOW_ENABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.glow_enabled = value;
+                }
+            }
+            ACTIVE_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.active_weapon = value;
+                }
+            }
+            HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.health = value;
+                }
+            }
+            MAX_HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.max_health = value;
+                }
+            }
+            HAS_SAPPER => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.has_sapper = value;
+                }
+            }
+            OBJECT_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_type = value;
+                }
+            }
+            BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.building = value;
+                }
+            }
+            PLACING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.placing = value;
+                }
+            }
+            CARRIED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carried = value;
+                }
+            }
+            CARRY_DEPLOY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carry_deploy = value;
+                }
+            }
+            MINI_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.mini_building = value;
+                }
+            }
+            PERCENTAGE_CONSTRUCTED => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.percentage_constructed = value;
+                }
+            }
+            OBJECT_FLAGS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_flags = value;
+                }
+            }
+            BUILT_ON_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.built_on_entity = value;
+                }
+            }
+            DISABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disabled = value;
+                }
+            }
+            BUILDER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.builder = value;
+                }
+            }
+            BUILD_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_maxs = value;
+                }
+            }
+            BUILD_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_mins = value;
+                }
+            }
+            DESIRED_BUILD_ROTATIONS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_build_rotations = value;
+                }
+            }
+            SERVER_OVERRIDE_PLACEMENT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.server_override_placement = value;
+                }
+            }
+            UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_level = value;
+                }
+            }
+            UPGRADE_METAL => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal = value;
+                }
+            }
+            UPGRADE_METAL_REQUIRED => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal_required = value;
+                }
+            }
+            HIGHEST_UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.highest_upgrade_level = value;
+                }
+            }
+            OBJECT_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_mode = value;
+                }
+            }
+            DISPOSABLE_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disposable_building = value;
+                }
+            }
+            WAS_MAP_PLACED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.was_map_placed = value;
+                }
+            }
+            PLASMA_DISABLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.plasma_disable = value;
+                }
+            }
+            STATE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.state = value;
+                }
+            }
+            AMMO_METAL => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo_metal = value;
+                }
+            }
+            MINI_BOMB_COUNTER => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.mini_bomb_counter = value;
+                }
+            }
+            HEALING_ARRAY => {
+                let value: Option<Vec<SendPropValue>> = <&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec);
+                if let Some(value) = value {
+                    self.healing_array = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            FLEX_WEIGHT_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[0] = value;
+                }
+            }
+            FLEX_WEIGHT_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[1] = value;
+                }
+            }
+            FLEX_WEIGHT_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[2] = value;
+                }
+            }
+            FLEX_WEIGHT_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[3] = value;
+                }
+            }
+            FLEX_WEIGHT_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[4] = value;
+                }
+            }
+            FLEX_WEIGHT_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[5] = value;
+                }
+            }
+            FLEX_WEIGHT_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[6] = value;
+                }
+            }
+            FLEX_WEIGHT_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[7] = value;
+                }
+            }
+            FLEX_WEIGHT_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[8] = value;
+                }
+            }
+            FLEX_WEIGHT_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[9] = value;
+                }
+            }
+            FLEX_WEIGHT_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[10] = value;
+                }
+            }
+            FLEX_WEIGHT_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[11] = value;
+                }
+            }
+            FLEX_WEIGHT_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[12] = value;
+                }
+            }
+            FLEX_WEIGHT_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[13] = value;
+                }
+            }
+            FLEX_WEIGHT_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[14] = value;
+                }
+            }
+            FLEX_WEIGHT_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[15] = value;
+                }
+            }
+            FLEX_WEIGHT_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[16] = value;
+                }
+            }
+            FLEX_WEIGHT_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[17] = value;
+                }
+            }
+            FLEX_WEIGHT_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[18] = value;
+                }
+            }
+            FLEX_WEIGHT_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[19] = value;
+                }
+            }
+            FLEX_WEIGHT_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[20] = value;
+                }
+            }
+            FLEX_WEIGHT_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[21] = value;
+                }
+            }
+            FLEX_WEIGHT_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[22] = value;
+                }
+            }
+            FLEX_WEIGHT_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[23] = value;
+                }
+            }
+            FLEX_WEIGHT_024 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[24] = value;
+                }
+            }
+            FLEX_WEIGHT_025 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[25] = value;
+                }
+            }
+            FLEX_WEIGHT_026 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[26] = value;
+                }
+            }
+            FLEX_WEIGHT_027 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[27] = value;
+                }
+            }
+            FLEX_WEIGHT_028 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[28] = value;
+                }
+            }
+            FLEX_WEIGHT_029 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[29] = value;
+                }
+            }
+            FLEX_WEIGHT_030 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[30] = value;
+                }
+            }
+            FLEX_WEIGHT_031 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[31] = value;
+                }
+            }
+            FLEX_WEIGHT_032 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[32] = value;
+                }
+            }
+            FLEX_WEIGHT_033 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[33] = value;
+                }
+            }
+            FLEX_WEIGHT_034 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[34] = value;
+                }
+            }
+            FLEX_WEIGHT_035 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[35] = value;
+                }
+            }
+            FLEX_WEIGHT_036 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[36] = value;
+                }
+            }
+            FLEX_WEIGHT_037 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[37] = value;
+                }
+            }
+            FLEX_WEIGHT_038 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[38] = value;
+                }
+            }
+            FLEX_WEIGHT_039 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[39] = value;
+                }
+            }
+            FLEX_WEIGHT_040 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[40] = value;
+                }
+            }
+            FLEX_WEIGHT_041 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[41] = value;
+                }
+            }
+            FLEX_WEIGHT_042 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[42] = value;
+                }
+            }
+            FLEX_WEIGHT_043 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[43] = value;
+                }
+            }
+            FLEX_WEIGHT_044 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[44] = value;
+                }
+            }
+            FLEX_WEIGHT_045 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[45] = value;
+                }
+            }
+            FLEX_WEIGHT_046 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[46] = value;
+                }
+            }
+            FLEX_WEIGHT_047 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[47] = value;
+                }
+            }
+            FLEX_WEIGHT_048 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[48] = value;
+                }
+            }
+            FLEX_WEIGHT_049 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[49] = value;
+                }
+            }
+            FLEX_WEIGHT_050 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[50] = value;
+                }
+            }
+            FLEX_WEIGHT_051 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[51] = value;
+                }
+            }
+            FLEX_WEIGHT_052 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[52] = value;
+                }
+            }
+            FLEX_WEIGHT_053 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[53] = value;
+                }
+            }
+            FLEX_WEIGHT_054 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[54] = value;
+                }
+            }
+            FLEX_WEIGHT_055 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[55] = value;
+                }
+            }
+            FLEX_WEIGHT_056 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[56] = value;
+                }
+            }
+            FLEX_WEIGHT_057 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[57] = value;
+                }
+            }
+            FLEX_WEIGHT_058 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[58] = value;
+                }
+            }
+            FLEX_WEIGHT_059 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[59] = value;
+                }
+            }
+            FLEX_WEIGHT_060 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[60] = value;
+                }
+            }
+            FLEX_WEIGHT_061 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[61] = value;
+                }
+            }
+            FLEX_WEIGHT_062 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[62] = value;
+                }
+            }
+            FLEX_WEIGHT_063 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[63] = value;
+                }
+            }
+            FLEX_WEIGHT_064 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[64] = value;
+                }
+            }
+            FLEX_WEIGHT_065 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[65] = value;
+                }
+            }
+            FLEX_WEIGHT_066 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[66] = value;
+                }
+            }
+            FLEX_WEIGHT_067 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[67] = value;
+                }
+            }
+            FLEX_WEIGHT_068 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[68] = value;
+                }
+            }
+            FLEX_WEIGHT_069 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[69] = value;
+                }
+            }
+            FLEX_WEIGHT_070 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[70] = value;
+                }
+            }
+            FLEX_WEIGHT_071 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[71] = value;
+                }
+            }
+            FLEX_WEIGHT_072 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[72] = value;
+                }
+            }
+            FLEX_WEIGHT_073 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[73] = value;
+                }
+            }
+            FLEX_WEIGHT_074 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[74] = value;
+                }
+            }
+            FLEX_WEIGHT_075 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[75] = value;
+                }
+            }
+            FLEX_WEIGHT_076 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[76] = value;
+                }
+            }
+            FLEX_WEIGHT_077 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[77] = value;
+                }
+            }
+            FLEX_WEIGHT_078 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[78] = value;
+                }
+            }
+            FLEX_WEIGHT_079 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[79] = value;
+                }
+            }
+            FLEX_WEIGHT_080 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[80] = value;
+                }
+            }
+            FLEX_WEIGHT_081 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[81] = value;
+                }
+            }
+            FLEX_WEIGHT_082 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[82] = value;
+                }
+            }
+            FLEX_WEIGHT_083 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[83] = value;
+                }
+            }
+            FLEX_WEIGHT_084 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[84] = value;
+                }
+            }
+            FLEX_WEIGHT_085 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[85] = value;
+                }
+            }
+            FLEX_WEIGHT_086 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[86] = value;
+                }
+            }
+            FLEX_WEIGHT_087 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[87] = value;
+                }
+            }
+            FLEX_WEIGHT_088 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[88] = value;
+                }
+            }
+            FLEX_WEIGHT_089 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[89] = value;
+                }
+            }
+            FLEX_WEIGHT_090 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[90] = value;
+                }
+            }
+            FLEX_WEIGHT_091 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[91] = value;
+                }
+            }
+            FLEX_WEIGHT_092 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[92] = value;
+                }
+            }
+            FLEX_WEIGHT_093 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[93] = value;
+                }
+            }
+            FLEX_WEIGHT_094 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[94] = value;
+                }
+            }
+            FLEX_WEIGHT_095 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[95] = value;
+                }
+            }
+            MY_WEAPONS_000 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[0] = value;
+                }
+            }
+            MY_WEAPONS_001 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[1] = value;
+                }
+            }
+            MY_WEAPONS_002 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[2] = value;
+                }
+            }
+            MY_WEAPONS_003 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[3] = value;
+                }
+            }
+            MY_WEAPONS_004 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[4] = value;
+                }
+            }
+            MY_WEAPONS_005 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[5] = value;
+                }
+            }
+            MY_WEAPONS_006 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[6] = value;
+                }
+            }
+            MY_WEAPONS_007 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[7] = value;
+                }
+            }
+            MY_WEAPONS_008 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[8] = value;
+                }
+            }
+            MY_WEAPONS_009 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[9] = value;
+                }
+            }
+            MY_WEAPONS_010 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[10] = value;
+                }
+            }
+            MY_WEAPONS_011 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[11] = value;
+                }
+            }
+            MY_WEAPONS_012 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[12] = value;
+                }
+            }
+            MY_WEAPONS_013 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[13] = value;
+                }
+            }
+            MY_WEAPONS_014 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[14] = value;
+                }
+            }
+            MY_WEAPONS_015 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[15] = value;
+                }
+            }
+            MY_WEAPONS_016 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[16] = value;
+                }
+            }
+            MY_WEAPONS_017 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[17] = value;
+                }
+            }
+            MY_WEAPONS_018 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[18] = value;
+                }
+            }
+            MY_WEAPONS_019 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[19] = value;
+                }
+            }
+            MY_WEAPONS_020 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[20] = value;
+                }
+            }
+            MY_WEAPONS_021 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[21] = value;
+                }
+            }
+            MY_WEAPONS_022 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[22] = value;
+                }
+            }
+            MY_WEAPONS_023 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[23] = value;
+                }
+            }
+            MY_WEAPONS_024 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[24] = value;
+                }
+            }
+            MY_WEAPONS_025 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[25] = value;
+                }
+            }
+            MY_WEAPONS_026 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[26] = value;
+                }
+            }
+            MY_WEAPONS_027 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[27] = value;
+                }
+            }
+            MY_WEAPONS_028 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[28] = value;
+                }
+            }
+            MY_WEAPONS_029 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[29] = value;
+                }
+            }
+            MY_WEAPONS_030 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[30] = value;
+                }
+            }
+            MY_WEAPONS_031 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[31] = value;
+                }
+            }
+            MY_WEAPONS_032 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[32] = value;
+                }
+            }
+            MY_WEAPONS_033 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[33] = value;
+                }
+            }
+            MY_WEAPONS_034 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[34] = value;
+                }
+            }
+            MY_WEAPONS_035 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[35] = value;
+                }
+            }
+            MY_WEAPONS_036 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[36] = value;
+                }
+            }
+            MY_WEAPONS_037 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[37] = value;
+                }
+            }
+            MY_WEAPONS_038 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[38] = value;
+                }
+            }
+            MY_WEAPONS_039 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[39] = value;
+                }
+            }
+            MY_WEAPONS_040 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[40] = value;
+                }
+            }
+            MY_WEAPONS_041 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[41] = value;
+                }
+            }
+            MY_WEAPONS_042 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[42] = value;
+                }
+            }
+            MY_WEAPONS_043 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[43] = value;
+                }
+            }
+            MY_WEAPONS_044 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[44] = value;
+                }
+            }
+            MY_WEAPONS_045 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[45] = value;
+                }
+            }
+            MY_WEAPONS_046 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[46] = value;
+                }
+            }
+            MY_WEAPONS_047 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[47] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CObjectSentrygun` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectSentrygun {
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub anim_time: u8,
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub server_animation_data_cycle: f32,
+    ///`DT_SentrygunLocalData.m_iKills`
+    pub kills: i32,
+    ///`DT_SentrygunLocalData.m_iAssists`
+    pub assists: i32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_BaseEntity.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_ObjectSentrygun.m_iAmmoShells`
+    pub ammo_shells: i32,
+    ///`DT_ObjectSentrygun.m_iAmmoRockets`
+    pub ammo_rockets: i32,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///The number of elements in `m_AnimOverlay`
+    pub anim_overlay_len: u8,
+    ///`DT_Animationlayer.m_nSequence`
+    pub animationlayer_sequence: [u16; 15],
+    ///`DT_Animationlayer.m_flCycle`
+    pub animationlayer_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub prev_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flWeight`
+    pub weight: [f32; 15],
+    ///`DT_Animationlayer.m_nOrder`
+    pub order: [u8; 15],
+    ///The elements of the `m_flexWeight` array table
+    pub flex_weight: [f32; 96],
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub next_attack: f32,
+    ///The elements of the `m_hMyWeapons` array table
+    pub my_weapons: [Handle; 48],
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub base_animating_sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub blinktoggle: bool,
+    ///`DT_BaseFlex.m_viewtarget`
+    pub viewtarget: Vector,
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub glow_enabled: bool,
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub active_weapon: Handle,
+    ///`DT_BaseObject.m_iHealth`
+    pub health: i32,
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub max_health: i32,
+    ///`DT_BaseObject.m_bHasSapper`
+    pub has_sapper: bool,
+    ///`DT_BaseObject.m_iObjectType`
+    pub object_type: u8,
+    ///`DT_BaseObject.m_bBuilding`
+    pub building: bool,
+    ///`DT_BaseObject.m_bPlacing`
+    pub placing: bool,
+    ///`DT_BaseObject.m_bCarried`
+    pub carried: bool,
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub carry_deploy: bool,
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub mini_building: bool,
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub percentage_constructed: f32,
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub object_flags: u8,
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub built_on_entity: Handle,
+    ///`DT_BaseObject.m_bDisabled`
+    pub disabled: bool,
+    ///`DT_BaseObject.m_hBuilder`
+    pub builder: Handle,
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub build_maxs: Vector,
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub build_mins: Vector,
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub desired_build_rotations: u8,
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub server_override_placement: bool,
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub upgrade_level: i8,
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub upgrade_metal: i16,
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub upgrade_metal_required: i16,
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub highest_upgrade_level: i8,
+    ///`DT_BaseObject.m_iObjectMode`
+    pub object_mode: u8,
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub disposable_building: bool,
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub was_map_placed: bool,
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub plasma_disable: bool,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_ObjectSentrygun.m_iState`
+    pub state: u8,
+    ///`DT_ObjectSentrygun.m_bPlayerControlled`
+    pub player_controlled: bool,
+    ///`DT_ObjectSentrygun.m_nShieldLevel`
+    pub shield_level: u8,
+    ///`DT_ObjectSentrygun.m_hEnemy`
+    pub enemy: Handle,
+    ///`DT_ObjectSentrygun.m_hAutoAimTarget`
+    pub auto_aim_target: Handle,
+}
+impl Default for ObjectSentrygun {
+    fn default() -> Self {
+        ObjectSentrygun {
+            anim_time: Default::default(),
+            server_animation_data_cycle: Default::default(),
+            kills: Default::default(),
+            assists: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            ammo_shells: Default::default(),
+            ammo_rockets: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            anim_overlay_len: Default::default(),
+            animationlayer_sequence: std::array::from_fn(|_| Default::default()),
+            animationlayer_cycle: std::array::from_fn(|_| Default::default()),
+            prev_cycle: std::array::from_fn(|_| Default::default()),
+            weight: std::array::from_fn(|_| Default::default()),
+            order: std::array::from_fn(|_| Default::default()),
+            flex_weight: std::array::from_fn(|_| Default::default()),
+            next_attack: Default::default(),
+            my_weapons: std::array::from_fn(|_| Default::default()),
+            maxs_pre_scaled: Default::default(),
+            mins: Default::default(),
+            maxs: Default::default(),
+            solid_type: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            solid_flags: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            base_animating_sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            blinktoggle: Default::default(),
+            viewtarget: Default::default(),
+            glow_enabled: Default::default(),
+            active_weapon: Default::default(),
+            health: Default::default(),
+            max_health: Default::default(),
+            has_sapper: Default::default(),
+            object_type: Default::default(),
+            building: Default::default(),
+            placing: Default::default(),
+            carried: Default::default(),
+            carry_deploy: Default::default(),
+            mini_building: Default::default(),
+            percentage_constructed: Default::default(),
+            object_flags: Default::default(),
+            built_on_entity: Default::default(),
+            disabled: Default::default(),
+            builder: Default::default(),
+            build_maxs: Default::default(),
+            build_mins: Default::default(),
+            desired_build_rotations: Default::default(),
+            server_override_placement: Default::default(),
+            upgrade_level: Default::default(),
+            upgrade_metal: Default::default(),
+            upgrade_metal_required: Default::default(),
+            highest_upgrade_level: Default::default(),
+            object_mode: Default::default(),
+            disposable_building: Default::default(),
+            was_map_placed: Default::default(),
+            plasma_disable: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            state: Default::default(),
+            player_controlled: Default::default(),
+            shield_level: Default::default(),
+            enemy: Default::default(),
+            auto_aim_target: Default::default(),
+        }
+    }
+}
+impl ObjectSentrygun {
+    pub const CLASS_NAME: &'static str = "CObjectSentrygun";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const FLEX_WEIGHT_000: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        const FLEX_WEIGHT_001: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        const FLEX_WEIGHT_002: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        const FLEX_WEIGHT_003: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        const FLEX_WEIGHT_004: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        const FLEX_WEIGHT_005: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        const FLEX_WEIGHT_006: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        const FLEX_WEIGHT_007: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        const FLEX_WEIGHT_008: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        const FLEX_WEIGHT_009: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        const FLEX_WEIGHT_010: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        const FLEX_WEIGHT_011: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        const FLEX_WEIGHT_012: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        const FLEX_WEIGHT_013: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        const FLEX_WEIGHT_014: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        const FLEX_WEIGHT_015: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        const FLEX_WEIGHT_016: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        const FLEX_WEIGHT_017: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        const FLEX_WEIGHT_018: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        const FLEX_WEIGHT_019: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        const FLEX_WEIGHT_020: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        const FLEX_WEIGHT_021: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        const FLEX_WEIGHT_022: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        const FLEX_WEIGHT_023: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        const FLEX_WEIGHT_024: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        const FLEX_WEIGHT_025: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        const FLEX_WEIGHT_026: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        const FLEX_WEIGHT_027: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        const FLEX_WEIGHT_028: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        const FLEX_WEIGHT_029: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        const FLEX_WEIGHT_030: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        const FLEX_WEIGHT_031: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        const FLEX_WEIGHT_032: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        const FLEX_WEIGHT_033: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        const FLEX_WEIGHT_034: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        const FLEX_WEIGHT_035: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        const FLEX_WEIGHT_036: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        const FLEX_WEIGHT_037: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        const FLEX_WEIGHT_038: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        const FLEX_WEIGHT_039: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        const FLEX_WEIGHT_040: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        const FLEX_WEIGHT_041: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        const FLEX_WEIGHT_042: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        const FLEX_WEIGHT_043: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        const FLEX_WEIGHT_044: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        const FLEX_WEIGHT_045: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        const FLEX_WEIGHT_046: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        const FLEX_WEIGHT_047: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        const FLEX_WEIGHT_048: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        const FLEX_WEIGHT_049: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        const FLEX_WEIGHT_050: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        const FLEX_WEIGHT_051: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        const FLEX_WEIGHT_052: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        const FLEX_WEIGHT_053: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        const FLEX_WEIGHT_054: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        const FLEX_WEIGHT_055: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        const FLEX_WEIGHT_056: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        const FLEX_WEIGHT_057: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        const FLEX_WEIGHT_058: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        const FLEX_WEIGHT_059: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        const FLEX_WEIGHT_060: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        const FLEX_WEIGHT_061: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        const FLEX_WEIGHT_062: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        const FLEX_WEIGHT_063: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        const FLEX_WEIGHT_064: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        const FLEX_WEIGHT_065: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        const FLEX_WEIGHT_066: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        const FLEX_WEIGHT_067: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        const FLEX_WEIGHT_068: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        const FLEX_WEIGHT_069: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        const FLEX_WEIGHT_070: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        const FLEX_WEIGHT_071: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        const FLEX_WEIGHT_072: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        const FLEX_WEIGHT_073: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        const FLEX_WEIGHT_074: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        const FLEX_WEIGHT_075: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        const FLEX_WEIGHT_076: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        const FLEX_WEIGHT_077: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        const FLEX_WEIGHT_078: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        const FLEX_WEIGHT_079: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        const FLEX_WEIGHT_080: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        const FLEX_WEIGHT_081: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        const FLEX_WEIGHT_082: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        const FLEX_WEIGHT_083: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        const FLEX_WEIGHT_084: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        const FLEX_WEIGHT_085: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        const FLEX_WEIGHT_086: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        const FLEX_WEIGHT_087: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        const FLEX_WEIGHT_088: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        const FLEX_WEIGHT_089: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        const FLEX_WEIGHT_090: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        const FLEX_WEIGHT_091: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        const FLEX_WEIGHT_092: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        const FLEX_WEIGHT_093: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        const FLEX_WEIGHT_094: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        const FLEX_WEIGHT_095: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        const MY_WEAPONS_000: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        const MY_WEAPONS_001: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        const MY_WEAPONS_002: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        const MY_WEAPONS_003: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        const MY_WEAPONS_004: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        const MY_WEAPONS_005: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        const MY_WEAPONS_006: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        const MY_WEAPONS_007: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        const MY_WEAPONS_008: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        const MY_WEAPONS_009: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        const MY_WEAPONS_010: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        const MY_WEAPONS_011: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        const MY_WEAPONS_012: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        const MY_WEAPONS_013: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        const MY_WEAPONS_014: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        const MY_WEAPONS_015: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        const MY_WEAPONS_016: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        const MY_WEAPONS_017: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        const MY_WEAPONS_018: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        const MY_WEAPONS_019: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        const MY_WEAPONS_020: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        const MY_WEAPONS_021: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        const MY_WEAPONS_022: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        const MY_WEAPONS_023: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        const MY_WEAPONS_024: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        const MY_WEAPONS_025: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        const MY_WEAPONS_026: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        const MY_WEAPONS_027: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        const MY_WEAPONS_028: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        const MY_WEAPONS_029: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        const MY_WEAPONS_030: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        const MY_WEAPONS_031: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        const MY_WEAPONS_032: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        const MY_WEAPONS_033: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        const MY_WEAPONS_034: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        const MY_WEAPONS_035: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        const MY_WEAPONS_036: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        const MY_WEAPONS_037: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        const MY_WEAPONS_038: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        const MY_WEAPONS_039: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        const MY_WEAPONS_040: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        const MY_WEAPONS_041: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        const MY_WEAPONS_042: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        const MY_WEAPONS_043: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        const MY_WEAPONS_044: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        const MY_WEAPONS_045: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        const MY_WEAPONS_046: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        const MY_WEAPONS_047: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        const ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        const SERVER_ANIMATION_DATA_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const KILLS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iKills");
+        const ASSISTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_SentrygunLocalData", "m_iAssists");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        const AMMO_SHELLS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoShells");
+        const AMMO_ROCKETS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_iAmmoRockets");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const ANIM_OVERLAY_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        const ANIMATIONLAYER_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        const ANIMATIONLAYER_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        const PREV_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        const WEIGHT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        const ORDER: SendPropIdentifier = SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        const NEXT_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const BASE_ANIMATING_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const BLINKTOGGLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        const VIEWTARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        const GLOW_ENABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        const ACTIVE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        const MAX_HEALTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        const HAS_SAPPER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        const OBJECT_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        const BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        const PLACING: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        const CARRIED: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        const CARRY_DEPLOY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        const MINI_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        const PERCENTAGE_CONSTRUCTED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        const OBJECT_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        const BUILT_ON_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        const DISABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        const BUILDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        const BUILD_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        const BUILD_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        const DESIRED_BUILD_ROTATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        const SERVER_OVERRIDE_PLACEMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        const UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        const UPGRADE_METAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        const UPGRADE_METAL_REQUIRED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        const HIGHEST_UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        const OBJECT_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        const DISPOSABLE_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        const WAS_MAP_PLACED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        const PLASMA_DISABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const STATE: SendPropIdentifier = SendPropIdentifier::new("DT_ObjectSentrygun", "m_iState");
+        const PLAYER_CONTROLLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_bPlayerControlled");
+        const SHIELD_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_nShieldLevel");
+        const ENEMY: SendPropIdentifier = SendPropIdentifier::new("DT_ObjectSentrygun", "m_hEnemy");
+        const AUTO_AIM_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectSentrygun", "m_hAutoAimTarget");
+        match prop.identifier {
+            ANIM_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_time = value;
+                }
+            }
+            SERVER_ANIMATION_DATA_CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.server_animation_data_cycle = value;
+                }
+            }
+            KILLS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kills = value;
+                }
+            }
+            ASSISTS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.assists = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            AMMO_SHELLS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo_shells = value;
+                }
+            }
+            AMMO_ROCKETS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo_rockets = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            ANIM_OVERLAY_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_overlay_len = value;
+                }
+            }
+            ANIMATIONLAYER_SEQUENCE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_sequence.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            ANIMATIONLAYER_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_cycle.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            PREV_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.prev_cycle.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            WEIGHT => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.weight.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            ORDER => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.order.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u8::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            NEXT_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_attack = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            BASE_ANIMATING_SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.base_animating_sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            BLINKTOGGLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.blinktoggle = value;
+                }
+            }
+            VIEWTARGET => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.viewtarget = value;
+                }
+            }
+            GLOW_ENABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.glow_enabled = value;
+                }
+            }
+            ACTIVE_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.active_weapon = value;
+                }
+            }
+            HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.health = value;
+                }
+            }
+            MAX_HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.max_health = value;
+                }
+            }
+            HAS_SAPPER => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.has_sapper = value;
+                }
+            }
+            OBJECT_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_type = value;
+                }
+            }
+            BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.building = value;
+                }
+            }
+            PLACING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.placing = value;
+                }
+            }
+            CARRIED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carried = value;
+                }
+            }
+            CARRY_DEPLOY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carry_deploy = value;
+                }
+            }
+            MINI_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.mini_building = value;
+                }
+            }
+            PERCENTAGE_CONSTRUCTED => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.percentage_constructed = value;
+                }
+            }
+            OBJECT_FLAGS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_flags = value;
+                }
+            }
+            BUILT_ON_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.built_on_entity = value;
+                }
+            }
+            DISABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disabled = value;
+                }
+            }
+            BUILDER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.builder = value;
+                }
+            }
+            BUILD_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_maxs = value;
+                }
+            }
+            BUILD_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_mins = value;
+                }
+            }
+            DESIRED_BUILD_ROTATIONS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_build_rotations = value;
+                }
+            }
+            SERVER_OVERRIDE_PLACEMENT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.server_override_placement = value;
+                }
+            }
+            UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_level = value;
+                }
+            }
+            UPGRADE_METAL => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal = value;
+                }
+            }
+            UPGRADE_METAL_REQUIRED => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal_required = value;
+                }
+            }
+            HIGHEST_UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.highest_upgrade_level = value;
+                }
+            }
+            OBJECT_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_mode = value;
+                }
+            }
+            DISPOSABLE_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disposable_building = value;
+                }
+            }
+            WAS_MAP_PLACED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.was_map_placed = value;
+                }
+            }
+            PLASMA_DISABLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.plasma_disable = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            STATE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.state = value;
+                }
+            }
+            PLAYER_CONTROLLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_controlled = value;
+                }
+            }
+            SHIELD_LEVEL => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.shield_level = value;
+                }
+            }
+            ENEMY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.enemy = value;
+                }
+            }
+            AUTO_AIM_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.auto_aim_target = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            FLEX_WEIGHT_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[0] = value;
+                }
+            }
+            FLEX_WEIGHT_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[1] = value;
+                }
+            }
+            FLEX_WEIGHT_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[2] = value;
+                }
+            }
+            FLEX_WEIGHT_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[3] = value;
+                }
+            }
+            FLEX_WEIGHT_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[4] = value;
+                }
+            }
+            FLEX_WEIGHT_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[5] = value;
+                }
+            }
+            FLEX_WEIGHT_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[6] = value;
+                }
+            }
+            FLEX_WEIGHT_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[7] = value;
+                }
+            }
+            FLEX_WEIGHT_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[8] = value;
+                }
+            }
+            FLEX_WEIGHT_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[9] = value;
+                }
+            }
+            FLEX_WEIGHT_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[10] = value;
+                }
+            }
+            FLEX_WEIGHT_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[11] = value;
+                }
+            }
+            FLEX_WEIGHT_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[12] = value;
+                }
+            }
+            FLEX_WEIGHT_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[13] = value;
+                }
+            }
+            FLEX_WEIGHT_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[14] = value;
+                }
+            }
+            FLEX_WEIGHT_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[15] = value;
+                }
+            }
+            FLEX_WEIGHT_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[16] = value;
+                }
+            }
+            FLEX_WEIGHT_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[17] = value;
+                }
+            }
+            FLEX_WEIGHT_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[18] = value;
+                }
+            }
+            FLEX_WEIGHT_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[19] = value;
+                }
+            }
+            FLEX_WEIGHT_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[20] = value;
+                }
+            }
+            FLEX_WEIGHT_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[21] = value;
+                }
+            }
+            FLEX_WEIGHT_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[22] = value;
+                }
+            }
+            FLEX_WEIGHT_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[23] = value;
+                }
+            }
+            FLEX_WEIGHT_024 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[24] = value;
+                }
+            }
+            FLEX_WEIGHT_025 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[25] = value;
+                }
+            }
+            FLEX_WEIGHT_026 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[26] = value;
+                }
+            }
+            FLEX_WEIGHT_027 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[27] = value;
+                }
+            }
+            FLEX_WEIGHT_028 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[28] = value;
+                }
+            }
+            FLEX_WEIGHT_029 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[29] = value;
+                }
+            }
+            FLEX_WEIGHT_030 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[30] = value;
+                }
+            }
+            FLEX_WEIGHT_031 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[31] = value;
+                }
+            }
+            FLEX_WEIGHT_032 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[32] = value;
+                }
+            }
+            FLEX_WEIGHT_033 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[33] = value;
+                }
+            }
+            FLEX_WEIGHT_034 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[34] = value;
+                }
+            }
+            FLEX_WEIGHT_035 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[35] = value;
+                }
+            }
+            FLEX_WEIGHT_036 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[36] = value;
+                }
+            }
+            FLEX_WEIGHT_037 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[37] = value;
+                }
+            }
+            FLEX_WEIGHT_038 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[38] = value;
+                }
+            }
+            FLEX_WEIGHT_039 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[39] = value;
+                }
+            }
+            FLEX_WEIGHT_040 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[40] = value;
+                }
+            }
+            FLEX_WEIGHT_041 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[41] = value;
+                }
+            }
+            FLEX_WEIGHT_042 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[42] = value;
+                }
+            }
+            FLEX_WEIGHT_043 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[43] = value;
+                }
+            }
+            FLEX_WEIGHT_044 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[44] = value;
+                }
+            }
+            FLEX_WEIGHT_045 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[45] = value;
+                }
+            }
+            FLEX_WEIGHT_046 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[46] = value;
+                }
+            }
+            FLEX_WEIGHT_047 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[47] = value;
+                }
+            }
+            FLEX_WEIGHT_048 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[48] = value;
+                }
+            }
+            FLEX_WEIGHT_049 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[49] = value;
+                }
+            }
+            FLEX_WEIGHT_050 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[50] = value;
+                }
+            }
+            FLEX_WEIGHT_051 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[51] = value;
+                }
+            }
+            FLEX_WEIGHT_052 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[52] = value;
+                }
+            }
+            FLEX_WEIGHT_053 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[53] = value;
+                }
+            }
+            FLEX_WEIGHT_054 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[54] = value;
+                }
+            }
+            FLEX_WEIGHT_055 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[55] = value;
+                }
+            }
+            FLEX_WEIGHT_056 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[56] = value;
+                }
+            }
+            FLEX_WEIGHT_057 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[57] = value;
+                }
+            }
+            FLEX_WEIGHT_058 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[58] = value;
+                }
+            }
+            FLEX_WEIGHT_059 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[59] = value;
+                }
+            }
+            FLEX_WEIGHT_060 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[60] = value;
+                }
+            }
+            FLEX_WEIGHT_061 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[61] = value;
+                }
+            }
+            FLEX_WEIGHT_062 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[62] = value;
+                }
+            }
+            FLEX_WEIGHT_063 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[63] = value;
+                }
+            }
+            FLEX_WEIGHT_064 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[64] = value;
+                }
+            }
+            FLEX_WEIGHT_065 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[65] = value;
+                }
+            }
+            FLEX_WEIGHT_066 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[66] = value;
+                }
+            }
+            FLEX_WEIGHT_067 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[67] = value;
+                }
+            }
+            FLEX_WEIGHT_068 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[68] = value;
+                }
+            }
+            FLEX_WEIGHT_069 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[69] = value;
+                }
+            }
+            FLEX_WEIGHT_070 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[70] = value;
+                }
+            }
+            FLEX_WEIGHT_071 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[71] = value;
+                }
+            }
+            FLEX_WEIGHT_072 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[72] = value;
+                }
+            }
+            FLEX_WEIGHT_073 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[73] = value;
+                }
+            }
+            FLEX_WEIGHT_074 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[74] = value;
+                }
+            }
+            FLEX_WEIGHT_075 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[75] = value;
+                }
+            }
+            FLEX_WEIGHT_076 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[76] = value;
+                }
+            }
+            FLEX_WEIGHT_077 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[77] = value;
+                }
+            }
+            FLEX_WEIGHT_078 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[78] = value;
+                }
+            }
+            FLEX_WEIGHT_079 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[79] = value;
+                }
+            }
+            FLEX_WEIGHT_080 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[80] = value;
+                }
+            }
+            FLEX_WEIGHT_081 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[81] = value;
+                }
+            }
+            FLEX_WEIGHT_082 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[82] = value;
+                }
+            }
+            FLEX_WEIGHT_083 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[83] = value;
+                }
+            }
+            FLEX_WEIGHT_084 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[84] = value;
+                }
+            }
+            FLEX_WEIGHT_085 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[85] = value;
+                }
+            }
+            FLEX_WEIGHT_086 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[86] = value;
+                }
+            }
+            FLEX_WEIGHT_087 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[87] = value;
+                }
+            }
+            FLEX_WEIGHT_088 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[88] = value;
+                }
+            }
+            FLEX_WEIGHT_089 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[89] = value;
+                }
+            }
+            FLEX_WEIGHT_090 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[90] = value;
+                }
+            }
+            FLEX_WEIGHT_091 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[91] = value;
+                }
+            }
+            FLEX_WEIGHT_092 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[92] = value;
+                }
+            }
+            FLEX_WEIGHT_093 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[93] = value;
+                }
+            }
+            FLEX_WEIGHT_094 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[94] = value;
+                }
+            }
+            FLEX_WEIGHT_095 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[95] = value;
+                }
+            }
+            MY_WEAPONS_000 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[0] = value;
+                }
+            }
+            MY_WEAPONS_001 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[1] = value;
+                }
+            }
+            MY_WEAPONS_002 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[2] = value;
+                }
+            }
+            MY_WEAPONS_003 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[3] = value;
+                }
+            }
+            MY_WEAPONS_004 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[4] = value;
+                }
+            }
+            MY_WEAPONS_005 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[5] = value;
+                }
+            }
+            MY_WEAPONS_006 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[6] = value;
+                }
+            }
+            MY_WEAPONS_007 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[7] = value;
+                }
+            }
+            MY_WEAPONS_008 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[8] = value;
+                }
+            }
+            MY_WEAPONS_009 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[9] = value;
+                }
+            }
+            MY_WEAPONS_010 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[10] = value;
+                }
+            }
+            MY_WEAPONS_011 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[11] = value;
+                }
+            }
+            MY_WEAPONS_012 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[12] = value;
+                }
+            }
+            MY_WEAPONS_013 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[13] = value;
+                }
+            }
+            MY_WEAPONS_014 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[14] = value;
+                }
+            }
+            MY_WEAPONS_015 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[15] = value;
+                }
+            }
+            MY_WEAPONS_016 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[16] = value;
+                }
+            }
+            MY_WEAPONS_017 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[17] = value;
+                }
+            }
+            MY_WEAPONS_018 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[18] = value;
+                }
+            }
+            MY_WEAPONS_019 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[19] = value;
+                }
+            }
+            MY_WEAPONS_020 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[20] = value;
+                }
+            }
+            MY_WEAPONS_021 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[21] = value;
+                }
+            }
+            MY_WEAPONS_022 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[22] = value;
+                }
+            }
+            MY_WEAPONS_023 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[23] = value;
+                }
+            }
+            MY_WEAPONS_024 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[24] = value;
+                }
+            }
+            MY_WEAPONS_025 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[25] = value;
+                }
+            }
+            MY_WEAPONS_026 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[26] = value;
+                }
+            }
+            MY_WEAPONS_027 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[27] = value;
+                }
+            }
+            MY_WEAPONS_028 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[28] = value;
+                }
+            }
+            MY_WEAPONS_029 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[29] = value;
+                }
+            }
+            MY_WEAPONS_030 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[30] = value;
+                }
+            }
+            MY_WEAPONS_031 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[31] = value;
+                }
+            }
+            MY_WEAPONS_032 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[32] = value;
+                }
+            }
+            MY_WEAPONS_033 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[33] = value;
+                }
+            }
+            MY_WEAPONS_034 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[34] = value;
+                }
+            }
+            MY_WEAPONS_035 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[35] = value;
+                }
+            }
+            MY_WEAPONS_036 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[36] = value;
+                }
+            }
+            MY_WEAPONS_037 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[37] = value;
+                }
+            }
+            MY_WEAPONS_038 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[38] = value;
+                }
+            }
+            MY_WEAPONS_039 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[39] = value;
+                }
+            }
+            MY_WEAPONS_040 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[40] = value;
+                }
+            }
+            MY_WEAPONS_041 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[41] = value;
+                }
+            }
+            MY_WEAPONS_042 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[42] = value;
+                }
+            }
+            MY_WEAPONS_043 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[43] = value;
+                }
+            }
+            MY_WEAPONS_044 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[44] = value;
+                }
+            }
+            MY_WEAPONS_045 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[45] = value;
+                }
+            }
+            MY_WEAPONS_046 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[46] = value;
+                }
+            }
+            MY_WEAPONS_047 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[47] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CObjectTeleporter` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectTeleporter {
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub anim_time: u8,
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub server_animation_data_cycle: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_BaseEntity.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///The number of elements in `m_AnimOverlay`
+    pub anim_overlay_len: u8,
+    ///`DT_Animationlayer.m_nSequence`
+    pub animationlayer_sequence: [u16; 15],
+    ///`DT_Animationlayer.m_flCycle`
+    pub animationlayer_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flPrevCycle`
+    pub prev_cycle: [f32; 15],
+    ///`DT_Animationlayer.m_flWeight`
+    pub weight: [f32; 15],
+    ///`DT_Animationlayer.m_nOrder`
+    pub order: [u8; 15],
+    ///The elements of the `m_flexWeight` array table
+    pub flex_weight: [f32; 96],
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub next_attack: f32,
+    ///The elements of the `m_hMyWeapons` array table
+    pub my_weapons: [Handle; 48],
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub base_animating_sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseFlex.m_blinktoggle`
+    pub blinktoggle: bool,
+    ///`DT_BaseFlex.m_viewtarget`
+    pub viewtarget: Vector,
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub glow_enabled: bool,
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub active_weapon: Handle,
+    ///`DT_BaseObject.m_iHealth`
+    pub health: i32,
+    ///`DT_BaseObject.m_iMaxHealth`
+    pub max_health: i32,
+    ///`DT_BaseObject.m_bHasSapper`
+    pub has_sapper: bool,
+    ///`DT_BaseObject.m_iObjectType`
+    pub object_type: u8,
+    ///`DT_BaseObject.m_bBuilding`
+    pub building: bool,
+    ///`DT_BaseObject.m_bPlacing`
+    pub placing: bool,
+    ///`DT_BaseObject.m_bCarried`
+    pub carried: bool,
+    ///`DT_BaseObject.m_bCarryDeploy`
+    pub carry_deploy: bool,
+    ///`DT_BaseObject.m_bMiniBuilding`
+    pub mini_building: bool,
+    ///`DT_BaseObject.m_flPercentageConstructed`
+    pub percentage_constructed: f32,
+    ///`DT_BaseObject.m_fObjectFlags`
+    pub object_flags: u8,
+    ///`DT_BaseObject.m_hBuiltOnEntity`
+    pub built_on_entity: Handle,
+    ///`DT_BaseObject.m_bDisabled`
+    pub disabled: bool,
+    ///`DT_BaseObject.m_hBuilder`
+    pub builder: Handle,
+    ///`DT_BaseObject.m_vecBuildMaxs`
+    pub build_maxs: Vector,
+    ///`DT_BaseObject.m_vecBuildMins`
+    pub build_mins: Vector,
+    ///`DT_BaseObject.m_iDesiredBuildRotations`
+    pub desired_build_rotations: u8,
+    ///`DT_BaseObject.m_bServerOverridePlacement`
+    pub server_override_placement: bool,
+    ///`DT_BaseObject.m_iUpgradeLevel`
+    pub upgrade_level: i8,
+    ///`DT_BaseObject.m_iUpgradeMetal`
+    pub upgrade_metal: i16,
+    ///`DT_BaseObject.m_iUpgradeMetalRequired`
+    pub upgrade_metal_required: i16,
+    ///`DT_BaseObject.m_iHighestUpgradeLevel`
+    pub highest_upgrade_level: i8,
+    ///`DT_BaseObject.m_iObjectMode`
+    pub object_mode: u8,
+    ///`DT_BaseObject.m_bDisposableBuilding`
+    pub disposable_building: bool,
+    ///`DT_BaseObject.m_bWasMapPlaced`
+    pub was_map_placed: bool,
+    ///`DT_BaseObject.m_bPlasmaDisable`
+    pub plasma_disable: bool,
+    ///`DT_ObjectTeleporter.m_iState`
+    pub state: i8,
+    ///`DT_ObjectTeleporter.m_flRechargeTime`
+    pub recharge_time: f32,
+    ///`DT_ObjectTeleporter.m_flCurrentRechargeDuration`
+    pub current_recharge_duration: f32,
+    ///`DT_ObjectTeleporter.m_iTimesUsed`
+    pub times_used: u16,
+    ///`DT_ObjectTeleporter.m_flYawToExit`
+    pub yaw_to_exit: f32,
+    ///`DT_ObjectTeleporter.m_bMatchBuilding`
+    pub match_building: bool,
+}
+impl Default for ObjectTeleporter {
+    fn default() -> Self {
+        ObjectTeleporter {
+            anim_time: Default::default(),
+            server_animation_data_cycle: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            solid_type: Default::default(),
+            solid_flags: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            anim_overlay_len: Default::default(),
+            animationlayer_sequence: std::array::from_fn(|_| Default::default()),
+            animationlayer_cycle: std::array::from_fn(|_| Default::default()),
+            prev_cycle: std::array::from_fn(|_| Default::default()),
+            weight: std::array::from_fn(|_| Default::default()),
+            order: std::array::from_fn(|_| Default::default()),
+            flex_weight: std::array::from_fn(|_| Default::default()),
+            next_attack: Default::default(),
+            my_weapons: std::array::from_fn(|_| Default::default()),
+            maxs_pre_scaled: Default::default(),
+            mins: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            maxs: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            base_animating_sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            blinktoggle: Default::default(),
+            viewtarget: Default::default(),
+            glow_enabled: Default::default(),
+            active_weapon: Default::default(),
+            health: Default::default(),
+            max_health: Default::default(),
+            has_sapper: Default::default(),
+            object_type: Default::default(),
+            building: Default::default(),
+            placing: Default::default(),
+            carried: Default::default(),
+            carry_deploy: Default::default(),
+            mini_building: Default::default(),
+            percentage_constructed: Default::default(),
+            object_flags: Default::default(),
+            built_on_entity: Default::default(),
+            disabled: Default::default(),
+            builder: Default::default(),
+            build_maxs: Default::default(),
+            build_mins: Default::default(),
+            desired_build_rotations: Default::default(),
+            server_override_placement: Default::default(),
+            upgrade_level: Default::default(),
+            upgrade_metal: Default::default(),
+            upgrade_metal_required: Default::default(),
+            highest_upgrade_level: Default::default(),
+            object_mode: Default::default(),
+            disposable_building: Default::default(),
+            was_map_placed: Default::default(),
+            plasma_disable: Default::default(),
+            state: Default::default(),
+            recharge_time: Default::default(),
+            current_recharge_duration: Default::default(),
+            times_used: Default::default(),
+            yaw_to_exit: Default::default(),
+            match_building: Default::default(),
+        }
+    }
+}
+impl ObjectTeleporter {
+    pub const CLASS_NAME: &'static str = "CObjectTeleporter";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const FLEX_WEIGHT_000: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "000");
+        const FLEX_WEIGHT_001: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "001");
+        const FLEX_WEIGHT_002: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "002");
+        const FLEX_WEIGHT_003: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "003");
+        const FLEX_WEIGHT_004: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "004");
+        const FLEX_WEIGHT_005: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "005");
+        const FLEX_WEIGHT_006: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "006");
+        const FLEX_WEIGHT_007: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "007");
+        const FLEX_WEIGHT_008: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "008");
+        const FLEX_WEIGHT_009: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "009");
+        const FLEX_WEIGHT_010: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "010");
+        const FLEX_WEIGHT_011: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "011");
+        const FLEX_WEIGHT_012: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "012");
+        const FLEX_WEIGHT_013: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "013");
+        const FLEX_WEIGHT_014: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "014");
+        const FLEX_WEIGHT_015: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "015");
+        const FLEX_WEIGHT_016: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "016");
+        const FLEX_WEIGHT_017: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "017");
+        const FLEX_WEIGHT_018: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "018");
+        const FLEX_WEIGHT_019: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "019");
+        const FLEX_WEIGHT_020: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "020");
+        const FLEX_WEIGHT_021: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "021");
+        const FLEX_WEIGHT_022: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "022");
+        const FLEX_WEIGHT_023: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "023");
+        const FLEX_WEIGHT_024: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "024");
+        const FLEX_WEIGHT_025: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "025");
+        const FLEX_WEIGHT_026: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "026");
+        const FLEX_WEIGHT_027: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "027");
+        const FLEX_WEIGHT_028: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "028");
+        const FLEX_WEIGHT_029: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "029");
+        const FLEX_WEIGHT_030: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "030");
+        const FLEX_WEIGHT_031: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "031");
+        const FLEX_WEIGHT_032: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "032");
+        const FLEX_WEIGHT_033: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "033");
+        const FLEX_WEIGHT_034: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "034");
+        const FLEX_WEIGHT_035: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "035");
+        const FLEX_WEIGHT_036: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "036");
+        const FLEX_WEIGHT_037: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "037");
+        const FLEX_WEIGHT_038: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "038");
+        const FLEX_WEIGHT_039: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "039");
+        const FLEX_WEIGHT_040: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "040");
+        const FLEX_WEIGHT_041: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "041");
+        const FLEX_WEIGHT_042: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "042");
+        const FLEX_WEIGHT_043: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "043");
+        const FLEX_WEIGHT_044: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "044");
+        const FLEX_WEIGHT_045: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "045");
+        const FLEX_WEIGHT_046: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "046");
+        const FLEX_WEIGHT_047: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "047");
+        const FLEX_WEIGHT_048: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "048");
+        const FLEX_WEIGHT_049: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "049");
+        const FLEX_WEIGHT_050: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "050");
+        const FLEX_WEIGHT_051: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "051");
+        const FLEX_WEIGHT_052: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "052");
+        const FLEX_WEIGHT_053: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "053");
+        const FLEX_WEIGHT_054: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "054");
+        const FLEX_WEIGHT_055: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "055");
+        const FLEX_WEIGHT_056: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "056");
+        const FLEX_WEIGHT_057: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "057");
+        const FLEX_WEIGHT_058: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "058");
+        const FLEX_WEIGHT_059: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "059");
+        const FLEX_WEIGHT_060: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "060");
+        const FLEX_WEIGHT_061: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "061");
+        const FLEX_WEIGHT_062: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "062");
+        const FLEX_WEIGHT_063: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "063");
+        const FLEX_WEIGHT_064: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "064");
+        const FLEX_WEIGHT_065: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "065");
+        const FLEX_WEIGHT_066: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "066");
+        const FLEX_WEIGHT_067: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "067");
+        const FLEX_WEIGHT_068: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "068");
+        const FLEX_WEIGHT_069: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "069");
+        const FLEX_WEIGHT_070: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "070");
+        const FLEX_WEIGHT_071: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "071");
+        const FLEX_WEIGHT_072: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "072");
+        const FLEX_WEIGHT_073: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "073");
+        const FLEX_WEIGHT_074: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "074");
+        const FLEX_WEIGHT_075: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "075");
+        const FLEX_WEIGHT_076: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "076");
+        const FLEX_WEIGHT_077: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "077");
+        const FLEX_WEIGHT_078: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "078");
+        const FLEX_WEIGHT_079: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "079");
+        const FLEX_WEIGHT_080: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "080");
+        const FLEX_WEIGHT_081: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "081");
+        const FLEX_WEIGHT_082: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "082");
+        const FLEX_WEIGHT_083: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "083");
+        const FLEX_WEIGHT_084: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "084");
+        const FLEX_WEIGHT_085: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "085");
+        const FLEX_WEIGHT_086: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "086");
+        const FLEX_WEIGHT_087: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "087");
+        const FLEX_WEIGHT_088: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "088");
+        const FLEX_WEIGHT_089: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "089");
+        const FLEX_WEIGHT_090: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "090");
+        const FLEX_WEIGHT_091: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "091");
+        const FLEX_WEIGHT_092: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "092");
+        const FLEX_WEIGHT_093: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "093");
+        const FLEX_WEIGHT_094: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "094");
+        const FLEX_WEIGHT_095: SendPropIdentifier = SendPropIdentifier::new("m_flexWeight", "095");
+        const MY_WEAPONS_000: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        const MY_WEAPONS_001: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        const MY_WEAPONS_002: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        const MY_WEAPONS_003: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        const MY_WEAPONS_004: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        const MY_WEAPONS_005: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        const MY_WEAPONS_006: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        const MY_WEAPONS_007: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        const MY_WEAPONS_008: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        const MY_WEAPONS_009: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        const MY_WEAPONS_010: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        const MY_WEAPONS_011: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        const MY_WEAPONS_012: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        const MY_WEAPONS_013: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        const MY_WEAPONS_014: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        const MY_WEAPONS_015: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        const MY_WEAPONS_016: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        const MY_WEAPONS_017: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        const MY_WEAPONS_018: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        const MY_WEAPONS_019: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        const MY_WEAPONS_020: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        const MY_WEAPONS_021: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        const MY_WEAPONS_022: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        const MY_WEAPONS_023: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        const MY_WEAPONS_024: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        const MY_WEAPONS_025: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        const MY_WEAPONS_026: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        const MY_WEAPONS_027: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        const MY_WEAPONS_028: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        const MY_WEAPONS_029: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        const MY_WEAPONS_030: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        const MY_WEAPONS_031: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        const MY_WEAPONS_032: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        const MY_WEAPONS_033: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        const MY_WEAPONS_034: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        const MY_WEAPONS_035: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        const MY_WEAPONS_036: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        const MY_WEAPONS_037: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        const MY_WEAPONS_038: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        const MY_WEAPONS_039: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        const MY_WEAPONS_040: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        const MY_WEAPONS_041: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        const MY_WEAPONS_042: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        const MY_WEAPONS_043: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        const MY_WEAPONS_044: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        const MY_WEAPONS_045: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        const MY_WEAPONS_046: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        const MY_WEAPONS_047: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        const ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        const SERVER_ANIMATION_DATA_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const ANIM_OVERLAY_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_AnimOverlay_15", "lengthprop15");
+        const ANIMATIONLAYER_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_nSequence");
+        const ANIMATIONLAYER_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flCycle");
+        const PREV_CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flPrevCycle");
+        const WEIGHT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Animationlayer", "m_flWeight");
+        const ORDER: SendPropIdentifier = SendPropIdentifier::new("DT_Animationlayer", "m_nOrder");
+        const NEXT_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const BASE_ANIMATING_SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const BLINKTOGGLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_blinktoggle");
+        const VIEWTARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseFlex", "m_viewtarget");
+        const GLOW_ENABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        const ACTIVE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_iHealth");
+        const MAX_HEALTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iMaxHealth");
+        const HAS_SAPPER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bHasSapper");
+        const OBJECT_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectType");
+        const BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bBuilding");
+        const PLACING: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bPlacing");
+        const CARRIED: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_bCarried");
+        const CARRY_DEPLOY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bCarryDeploy");
+        const MINI_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bMiniBuilding");
+        const PERCENTAGE_CONSTRUCTED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_flPercentageConstructed");
+        const OBJECT_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_fObjectFlags");
+        const BUILT_ON_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_hBuiltOnEntity");
+        const DISABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisabled");
+        const BUILDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseObject", "m_hBuilder");
+        const BUILD_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMaxs");
+        const BUILD_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_vecBuildMins");
+        const DESIRED_BUILD_ROTATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iDesiredBuildRotations");
+        const SERVER_OVERRIDE_PLACEMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bServerOverridePlacement");
+        const UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeLevel");
+        const UPGRADE_METAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetal");
+        const UPGRADE_METAL_REQUIRED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iUpgradeMetalRequired");
+        const HIGHEST_UPGRADE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iHighestUpgradeLevel");
+        const OBJECT_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_iObjectMode");
+        const DISPOSABLE_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bDisposableBuilding");
+        const WAS_MAP_PLACED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bWasMapPlaced");
+        const PLASMA_DISABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseObject", "m_bPlasmaDisable");
+        const STATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iState");
+        const RECHARGE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flRechargeTime");
+        const CURRENT_RECHARGE_DURATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flCurrentRechargeDuration");
+        const TIMES_USED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_iTimesUsed");
+        const YAW_TO_EXIT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_flYawToExit");
+        const MATCH_BUILDING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ObjectTeleporter", "m_bMatchBuilding");
+        match prop.identifier {
+            ANIM_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_time = value;
+                }
+            }
+            SERVER_ANIMATION_DATA_CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.server_animation_data_cycle = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            ANIM_OVERLAY_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_overlay_len = value;
+                }
+            }
+            ANIMATIONLAYER_SEQUENCE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_sequence.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            ANIMATIONLAYER_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.animationlayer_cycle.get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            PREV_CYCLE => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.prev_cycle.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            WEIGHT => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.weight.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = ((&prop.value).try_into().ok(), field) {
+                        *field = value;
+                    }
+                }
+            }
+            ORDER => {
+                if let Some(PropLocation {
+                    path: 5454506302675102535,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.order.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u8::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            NEXT_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_attack = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            BASE_ANIMATING_SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.base_animating_sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            BLINKTOGGLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.blinktoggle = value;
+                }
+            }
+            VIEWTARGET => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.viewtarget = value;
+                }
+            }
+            GLOW_ENABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.glow_enabled = value;
+                }
+            }
+            ACTIVE_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.active_weapon = value;
+                }
+            }
+            HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.health = value;
+                }
+            }
+            MAX_HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.max_health = value;
+                }
+            }
+            HAS_SAPPER => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.has_sapper = value;
+                }
+            }
+            OBJECT_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_type = value;
+                }
+            }
+            BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.building = value;
+                }
+            }
+            PLACING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.placing = value;
+                }
+            }
+            CARRIED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carried = value;
+                }
+            }
+            CARRY_DEPLOY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carry_deploy = value;
+                }
+            }
+            MINI_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.mini_building = value;
+                }
+            }
+            PERCENTAGE_CONSTRUCTED => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.percentage_constructed = value;
+                }
+            }
+            OBJECT_FLAGS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_flags = value;
+                }
+            }
+            BUILT_ON_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.built_on_entity = value;
+                }
+            }
+            DISABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disabled = value;
+                }
+            }
+            BUILDER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.builder = value;
+                }
+            }
+            BUILD_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_maxs = value;
+                }
+            }
+            BUILD_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.build_mins = value;
+                }
+            }
+            DESIRED_BUILD_ROTATIONS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_build_rotations = value;
+                }
+            }
+            SERVER_OVERRIDE_PLACEMENT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.server_override_placement = value;
+                }
+            }
+            UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_level = value;
+                }
+            }
+            UPGRADE_METAL => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal = value;
+                }
+            }
+            UPGRADE_METAL_REQUIRED => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.upgrade_metal_required = value;
+                }
+            }
+            HIGHEST_UPGRADE_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.highest_upgrade_level = value;
+                }
+            }
+            OBJECT_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.object_mode = value;
+                }
+            }
+            DISPOSABLE_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disposable_building = value;
+                }
+            }
+            WAS_MAP_PLACED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.was_map_placed = value;
+                }
+            }
+            PLASMA_DISABLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.plasma_disable = value;
+                }
+            }
+            STATE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.state = value;
+                }
+            }
+            RECHARGE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.recharge_time = value;
+                }
+            }
+            CURRENT_RECHARGE_DURATION => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.current_recharge_duration = value;
+                }
+            }
+            TIMES_USED => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.times_used = value;
+                }
+            }
+            YAW_TO_EXIT => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.yaw_to_exit = value;
+                }
+            }
+            MATCH_BUILDING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.match_building = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            FLEX_WEIGHT_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[0] = value;
+                }
+            }
+            FLEX_WEIGHT_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[1] = value;
+                }
+            }
+            FLEX_WEIGHT_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[2] = value;
+                }
+            }
+            FLEX_WEIGHT_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[3] = value;
+                }
+            }
+            FLEX_WEIGHT_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[4] = value;
+                }
+            }
+            FLEX_WEIGHT_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[5] = value;
+                }
+            }
+            FLEX_WEIGHT_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[6] = value;
+                }
+            }
+            FLEX_WEIGHT_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[7] = value;
+                }
+            }
+            FLEX_WEIGHT_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[8] = value;
+                }
+            }
+            FLEX_WEIGHT_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[9] = value;
+                }
+            }
+            FLEX_WEIGHT_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[10] = value;
+                }
+            }
+            FLEX_WEIGHT_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[11] = value;
+                }
+            }
+            FLEX_WEIGHT_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[12] = value;
+                }
+            }
+            FLEX_WEIGHT_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[13] = value;
+                }
+            }
+            FLEX_WEIGHT_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[14] = value;
+                }
+            }
+            FLEX_WEIGHT_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[15] = value;
+                }
+            }
+            FLEX_WEIGHT_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[16] = value;
+                }
+            }
+            FLEX_WEIGHT_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[17] = value;
+                }
+            }
+            FLEX_WEIGHT_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[18] = value;
+                }
+            }
+            FLEX_WEIGHT_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[19] = value;
+                }
+            }
+            FLEX_WEIGHT_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[20] = value;
+                }
+            }
+            FLEX_WEIGHT_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[21] = value;
+                }
+            }
+            FLEX_WEIGHT_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[22] = value;
+                }
+            }
+            FLEX_WEIGHT_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[23] = value;
+                }
+            }
+            FLEX_WEIGHT_024 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[24] = value;
+                }
+            }
+            FLEX_WEIGHT_025 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[25] = value;
+                }
+            }
+            FLEX_WEIGHT_026 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[26] = value;
+                }
+            }
+            FLEX_WEIGHT_027 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[27] = value;
+                }
+            }
+            FLEX_WEIGHT_028 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[28] = value;
+                }
+            }
+            FLEX_WEIGHT_029 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[29] = value;
+                }
+            }
+            FLEX_WEIGHT_030 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[30] = value;
+                }
+            }
+            FLEX_WEIGHT_031 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[31] = value;
+                }
+            }
+            FLEX_WEIGHT_032 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[32] = value;
+                }
+            }
+            FLEX_WEIGHT_033 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[33] = value;
+                }
+            }
+            FLEX_WEIGHT_034 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[34] = value;
+                }
+            }
+            FLEX_WEIGHT_035 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[35] = value;
+                }
+            }
+            FLEX_WEIGHT_036 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[36] = value;
+                }
+            }
+            FLEX_WEIGHT_037 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[37] = value;
+                }
+            }
+            FLEX_WEIGHT_038 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[38] = value;
+                }
+            }
+            FLEX_WEIGHT_039 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[39] = value;
+                }
+            }
+            FLEX_WEIGHT_040 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[40] = value;
+                }
+            }
+            FLEX_WEIGHT_041 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[41] = value;
+                }
+            }
+            FLEX_WEIGHT_042 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[42] = value;
+                }
+            }
+            FLEX_WEIGHT_043 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[43] = value;
+                }
+            }
+            FLEX_WEIGHT_044 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[44] = value;
+                }
+            }
+            FLEX_WEIGHT_045 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[45] = value;
+                }
+            }
+            FLEX_WEIGHT_046 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[46] = value;
+                }
+            }
+            FLEX_WEIGHT_047 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[47] = value;
+                }
+            }
+            FLEX_WEIGHT_048 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[48] = value;
+                }
+            }
+            FLEX_WEIGHT_049 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[49] = value;
+                }
+            }
+            FLEX_WEIGHT_050 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[50] = value;
+                }
+            }
+            FLEX_WEIGHT_051 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[51] = value;
+                }
+            }
+            FLEX_WEIGHT_052 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[52] = value;
+                }
+            }
+            FLEX_WEIGHT_053 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[53] = value;
+                }
+            }
+            FLEX_WEIGHT_054 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[54] = value;
+                }
+            }
+            FLEX_WEIGHT_055 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[55] = value;
+                }
+            }
+            FLEX_WEIGHT_056 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[56] = value;
+                }
+            }
+            FLEX_WEIGHT_057 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[57] = value;
+                }
+            }
+            FLEX_WEIGHT_058 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[58] = value;
+                }
+            }
+            FLEX_WEIGHT_059 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[59] = value;
+                }
+            }
+            FLEX_WEIGHT_060 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[60] = value;
+                }
+            }
+            FLEX_WEIGHT_061 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[61] = value;
+                }
+            }
+            FLEX_WEIGHT_062 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[62] = value;
+                }
+            }
+            FLEX_WEIGHT_063 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[63] = value;
+                }
+            }
+            FLEX_WEIGHT_064 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[64] = value;
+                }
+            }
+            FLEX_WEIGHT_065 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[65] = value;
+                }
+            }
+            FLEX_WEIGHT_066 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[66] = value;
+                }
+            }
+            FLEX_WEIGHT_067 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[67] = value;
+                }
+            }
+            FLEX_WEIGHT_068 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[68] = value;
+                }
+            }
+            FLEX_WEIGHT_069 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[69] = value;
+                }
+            }
+            FLEX_WEIGHT_070 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[70] = value;
+                }
+            }
+            FLEX_WEIGHT_071 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[71] = value;
+                }
+            }
+            FLEX_WEIGHT_072 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[72] = value;
+                }
+            }
+            FLEX_WEIGHT_073 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[73] = value;
+                }
+            }
+            FLEX_WEIGHT_074 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[74] = value;
+                }
+            }
+            FLEX_WEIGHT_075 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[75] = value;
+                }
+            }
+            FLEX_WEIGHT_076 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[76] = value;
+                }
+            }
+            FLEX_WEIGHT_077 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[77] = value;
+                }
+            }
+            FLEX_WEIGHT_078 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[78] = value;
+                }
+            }
+            FLEX_WEIGHT_079 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[79] = value;
+                }
+            }
+            FLEX_WEIGHT_080 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[80] = value;
+                }
+            }
+            FLEX_WEIGHT_081 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[81] = value;
+                }
+            }
+            FLEX_WEIGHT_082 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[82] = value;
+                }
+            }
+            FLEX_WEIGHT_083 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[83] = value;
+                }
+            }
+            FLEX_WEIGHT_084 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[84] = value;
+                }
+            }
+            FLEX_WEIGHT_085 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[85] = value;
+                }
+            }
+            FLEX_WEIGHT_086 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[86] = value;
+                }
+            }
+            FLEX_WEIGHT_087 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[87] = value;
+                }
+            }
+            FLEX_WEIGHT_088 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[88] = value;
+                }
+            }
+            FLEX_WEIGHT_089 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[89] = value;
+                }
+            }
+            FLEX_WEIGHT_090 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[90] = value;
+                }
+            }
+            FLEX_WEIGHT_091 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[91] = value;
+                }
+            }
+            FLEX_WEIGHT_092 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[92] = value;
+                }
+            }
+            FLEX_WEIGHT_093 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[93] = value;
+                }
+            }
+            FLEX_WEIGHT_094 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[94] = value;
+                }
+            }
+            FLEX_WEIGHT_095 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.flex_weight[95] = value;
+                }
+            }
+            MY_WEAPONS_000 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[0] = value;
+                }
+            }
+            MY_WEAPONS_001 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[1] = value;
+                }
+            }
+            MY_WEAPONS_002 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[2] = value;
+                }
+            }
+            MY_WEAPONS_003 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[3] = value;
+                }
+            }
+            MY_WEAPONS_004 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[4] = value;
+                }
+            }
+            MY_WEAPONS_005 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[5] = value;
+                }
+            }
+            MY_WEAPONS_006 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[6] = value;
+                }
+            }
+            MY_WEAPONS_007 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[7] = value;
+                }
+            }
+            MY_WEAPONS_008 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[8] = value;
+                }
+            }
+            MY_WEAPONS_009 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[9] = value;
+                }
+            }
+            MY_WEAPONS_010 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[10] = value;
+                }
+            }
+            MY_WEAPONS_011 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[11] = value;
+                }
+            }
+            MY_WEAPONS_012 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[12] = value;
+                }
+            }
+            MY_WEAPONS_013 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[13] = value;
+                }
+            }
+            MY_WEAPONS_014 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[14] = value;
+                }
+            }
+            MY_WEAPONS_015 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[15] = value;
+                }
+            }
+            MY_WEAPONS_016 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[16] = value;
+                }
+            }
+            MY_WEAPONS_017 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[17] = value;
+                }
+            }
+            MY_WEAPONS_018 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[18] = value;
+                }
+            }
+            MY_WEAPONS_019 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[19] = value;
+                }
+            }
+            MY_WEAPONS_020 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[20] = value;
+                }
+            }
+            MY_WEAPONS_021 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[21] = value;
+                }
+            }
+            MY_WEAPONS_022 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[22] = value;
+                }
+            }
+            MY_WEAPONS_023 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[23] = value;
+                }
+            }
+            MY_WEAPONS_024 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[24] = value;
+                }
+            }
+            MY_WEAPONS_025 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[25] = value;
+                }
+            }
+            MY_WEAPONS_026 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[26] = value;
+                }
+            }
+            MY_WEAPONS_027 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[27] = value;
+                }
+            }
+            MY_WEAPONS_028 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[28] = value;
+                }
+            }
+            MY_WEAPONS_029 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[29] = value;
+                }
+            }
+            MY_WEAPONS_030 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[30] = value;
+                }
+            }
+            MY_WEAPONS_031 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[31] = value;
+                }
+            }
+            MY_WEAPONS_032 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[32] = value;
+                }
+            }
+            MY_WEAPONS_033 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[33] = value;
+                }
+            }
+            MY_WEAPONS_034 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[34] = value;
+                }
+            }
+            MY_WEAPONS_035 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[35] = value;
+                }
+            }
+            MY_WEAPONS_036 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[36] = value;
+                }
+            }
+            MY_WEAPONS_037 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[37] = value;
+                }
+            }
+            MY_WEAPONS_038 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[38] = value;
+                }
+            }
+            MY_WEAPONS_039 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[39] = value;
+                }
+            }
+            MY_WEAPONS_040 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[40] = value;
+                }
+            }
+            MY_WEAPONS_041 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[41] = value;
+                }
+            }
+            MY_WEAPONS_042 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[42] = value;
+                }
+            }
+            MY_WEAPONS_043 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[43] = value;
+                }
+            }
+            MY_WEAPONS_044 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[44] = value;
+                }
+            }
+            MY_WEAPONS_045 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[45] = value;
+                }
+            }
+            MY_WEAPONS_046 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[46] = value;
+                }
+            }
+            MY_WEAPONS_047 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[47] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CTFGrenadePipebombProjectile` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct TFGrenadePipebombProjectile {
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub anim_time: u8,
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub cycle: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_TFWeaponBaseGrenadeProj.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_TFWeaponBaseGrenadeProj.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseProjectile.m_hOriginalLauncher`
+    pub original_launcher: Handle,
+    ///`DT_BaseGrenade.m_flDamage`
+    pub damage: f32,
+    ///`DT_BaseGrenade.m_DmgRadius`
+    pub dmg_radius: f32,
+    ///`DT_BaseGrenade.m_bIsLive`
+    pub is_live: bool,
+    ///`DT_BaseGrenade.m_hThrower`
+    pub thrower: Handle,
+    ///`DT_BaseGrenade.m_vecVelocity`
+    pub velocity: Vector,
+    ///`DT_BaseGrenade.m_fFlags`
+    pub flags: u16,
+    ///`DT_TFWeaponBaseGrenadeProj.m_vInitialVelocity`
+    pub v_initial_velocity: Vector,
+    ///`DT_TFWeaponBaseGrenadeProj.m_bCritical`
+    pub critical: bool,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_TFWeaponBaseGrenadeProj.m_iDeflected`
+    pub deflected: u8,
+    ///`DT_TFWeaponBaseGrenadeProj.m_hDeflectOwner`
+    pub deflect_owner: Handle,
+    ///`DT_TFProjectile_Pipebomb.m_bTouched`
+    pub touched: bool,
+    ///`DT_TFProjectile_Pipebomb.m_iType`
+    pub type_: i8,
+    ///`DT_TFProjectile_Pipebomb.m_hLauncher`
+    pub launcher: Handle,
+    ///`DT_TFProjectile_Pipebomb.m_bDefensiveBomb`
+    pub defensive_bomb: bool,
+}
+impl Default for TFGrenadePipebombProjectile {
+    fn default() -> Self {
+        TFGrenadePipebombProjectile {
+            anim_time: Default::default(),
+            cycle: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            solid_type: Default::default(),
+            solid_flags: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            maxs_pre_scaled: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            original_launcher: Default::default(),
+            damage: Default::default(),
+            dmg_radius: Default::default(),
+            is_live: Default::default(),
+            thrower: Default::default(),
+            velocity: Default::default(),
+            flags: Default::default(),
+            v_initial_velocity: Default::default(),
+            critical: Default::default(),
+            mins: Default::default(),
+            maxs: Default::default(),
+            deflected: Default::default(),
+            deflect_owner: Default::default(),
+            touched: Default::default(),
+            type_: Default::default(),
+            launcher: Default::default(),
+            defensive_bomb: Default::default(),
+        }
+    }
+}
+impl TFGrenadePipebombProjectile {
+    pub const CLASS_NAME: &'static str = "CTFGrenadePipebombProjectile";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, _location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        const CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_angRotation");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const ORIGINAL_LAUNCHER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseProjectile", "m_hOriginalLauncher");
+        const DAMAGE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseGrenade", "m_flDamage");
+        const DMG_RADIUS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseGrenade", "m_DmgRadius");
+        const IS_LIVE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseGrenade", "m_bIsLive");
+        const THROWER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseGrenade", "m_hThrower");
+        const VELOCITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseGrenade", "m_vecVelocity");
+        const FLAGS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseGrenade", "m_fFlags");
+        const V_INITIAL_VELOCITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_vInitialVelocity");
+        const CRITICAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_bCritical");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const DEFLECTED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_iDeflected");
+        const DEFLECT_OWNER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBaseGrenadeProj", "m_hDeflectOwner");
+        const TOUCHED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_bTouched");
+        const TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_iType");
+        const LAUNCHER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_hLauncher");
+        const DEFENSIVE_BOMB: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFProjectile_Pipebomb", "m_bDefensiveBomb");
+        match prop.identifier {
+            ANIM_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_time = value;
+                }
+            }
+            CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.cycle = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            ORIGINAL_LAUNCHER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.original_launcher = value;
+                }
+            }
+            DAMAGE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.damage = value;
+                }
+            }
+            DMG_RADIUS => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.dmg_radius = value;
+                }
+            }
+            IS_LIVE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_live = value;
+                }
+            }
+            THROWER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.thrower = value;
+                }
+            }
+            VELOCITY => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.velocity = value;
+                }
+            }
+            FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.flags = value;
+                }
+            }
+            V_INITIAL_VELOCITY => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.v_initial_velocity = value;
+                }
+            }
+            CRITICAL => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.critical = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            DEFLECTED => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.deflected = value;
+                }
+            }
+            DEFLECT_OWNER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.deflect_owner = value;
+                }
+            }
+            TOUCHED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.touched = value;
+                }
+            }
+            TYPE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.type_ = value;
+                }
+            }
+            LAUNCHER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.launcher = value;
+                }
+            }
+            DEFENSIVE_BOMB => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.defensive_bomb = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CTFPlayer` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct TFPlayer {
+    ///`DT_Local.m_flDucktime`
+    pub ducktime: f32,
+    ///`DT_Local.m_flFallVelocity`
+    pub fall_velocity: f32,
+    ///`DT_Local.m_vecPunchAngle`
+    pub punch_angle: Vector,
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[2]`
+    pub view_offset_2: f32,
+    ///`DT_LocalPlayerExclusive.m_nTickBase`
+    pub tick_base: i32,
+    ///`DT_LocalPlayerExclusive.m_hGroundEntity`
+    pub ground_entity: Handle,
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[0]`
+    pub velocity_0: f32,
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[1]`
+    pub velocity_1: f32,
+    ///`DT_LocalPlayerExclusive.m_vecVelocity[2]`
+    pub velocity_2: f32,
+    ///`DT_TFLocalPlayerExclusive.m_vecOrigin`
+    pub tf_local_player_exclusive_origin: VectorXY,
+    ///`DT_TFLocalPlayerExclusive.m_vecOrigin[2]`
+    pub tf_local_player_exclusive_origin_2: f32,
+    ///`DT_TFLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub tf_local_player_exclusive_eye_angles_0: f32,
+    ///`DT_TFLocalPlayerExclusive.m_angEyeAngles[1]`
+    pub tf_local_player_exclusive_eye_angles_1: f32,
+    ///`DT_TFNonLocalPlayerExclusive.m_vecOrigin`
+    pub tf_non_local_player_exclusive_origin: VectorXY,
+    ///`DT_TFNonLocalPlayerExclusive.m_vecOrigin[2]`
+    pub tf_non_local_player_exclusive_origin_2: f32,
+    ///`DT_TFNonLocalPlayerExclusive.m_angEyeAngles[0]`
+    pub tf_non_local_player_exclusive_eye_angles_0: f32,
+    ///`DT_TFNonLocalPlayerExclusive.m_angEyeAngles[1]`
+    pub tf_non_local_player_exclusive_eye_angles_1: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_BasePlayer.m_iHealth`
+    pub health: i32,
+    ///`DT_BasePlayer.m_fFlags`
+    pub flags: u16,
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_BCCLocalPlayerExclusive.m_flNextAttack`
+    pub next_attack: f32,
+    ///The elements of the `m_hMyWeapons` array table
+    pub my_weapons: [Handle; 48],
+    ///The number of elements in `m_Attributes`
+    pub attributes_len: u8,
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex`
+    pub attribute_definition_index: [u16; 20],
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32`
+    pub raw_value_32: [u32; 20],
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency`
+    pub refundable_currency: [u32; 20],
+    ///`DT_PlayerState.deadflag`
+    pub deadflag: bool,
+    ///The number of elements in `m_hMyWearables`
+    pub my_wearables_len: u8,
+    ///The elements of the `_ST_m_hMyWearables_8` array table
+    pub my_wearables: [Handle; 8],
+    ///The elements of the `m_chAreaBits` array table
+    pub area_bits: [u8; 32],
+    ///The elements of the `m_chAreaPortalBits` array table
+    pub area_portal_bits: [u8; 24],
+    ///`DT_Local.m_iHideHUD`
+    pub hide_hud: u32,
+    ///`DT_Local.m_flFOVRate`
+    pub fov_rate: f32,
+    ///`DT_Local.m_bDucked`
+    pub ducked: bool,
+    ///`DT_Local.m_bDucking`
+    pub ducking: bool,
+    ///`DT_Local.m_bInDuckJump`
+    pub in_duck_jump: bool,
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///`DT_Local.m_flDuckJumpTime`
+    pub duck_jump_time: f32,
+    ///`DT_Local.m_flJumpTime`
+    pub jump_time: f32,
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_Local.m_vecPunchAngleVel`
+    pub punch_angle_vel: Vector,
+    ///`DT_Local.m_bDrawViewmodel`
+    pub draw_viewmodel: bool,
+    ///`DT_Local.m_bWearingSuit`
+    pub wearing_suit: bool,
+    ///`DT_Local.m_bPoisoned`
+    pub poisoned: bool,
+    ///`DT_Local.m_bForceLocalPlayerDraw`
+    pub force_local_player_draw: bool,
+    ///`DT_Local.m_flStepSize`
+    pub step_size: f32,
+    ///`DT_Local.m_bAllowAutoMovement`
+    pub allow_auto_movement: bool,
+    ///`DT_Local.m_skybox3d.scale`
+    pub skybox_3d_scale: i16,
+    ///`DT_Local.m_skybox3d.origin`
+    pub skybox_3d_origin: Vector,
+    ///`DT_Local.m_skybox3d.area`
+    pub skybox_3d_area: u8,
+    ///`DT_Local.m_skybox3d.fog.enable`
+    pub skybox_3d_fog_enable: bool,
+    ///`DT_Local.m_skybox3d.fog.blend`
+    pub skybox_3d_fog_blend: bool,
+    ///`DT_Local.m_skybox3d.fog.dirPrimary`
+    pub skybox_3d_fog_dir_primary: Vector,
+    ///`DT_Local.m_skybox3d.fog.colorPrimary`
+    pub skybox_3d_fog_color_primary: u32,
+    ///`DT_Local.m_skybox3d.fog.colorSecondary`
+    pub skybox_3d_fog_color_secondary: u32,
+    ///`DT_Local.m_skybox3d.fog.start`
+    pub skybox_3d_fog_start: f32,
+    ///`DT_Local.m_skybox3d.fog.end`
+    pub skybox_3d_fog_end: f32,
+    ///`DT_Local.m_skybox3d.fog.maxdensity`
+    pub skybox_3d_fog_maxdensity: f32,
+    ///`DT_Local.m_PlayerFog.m_hCtrl`
+    pub player_fog_ctrl: Handle,
+    ///`DT_Local.m_audio.localSound[0]`
+    pub audio_local_sound_0: Vector,
+    ///`DT_Local.m_audio.localSound[1]`
+    pub audio_local_sound_1: Vector,
+    ///`DT_Local.m_audio.localSound[2]`
+    pub audio_local_sound_2: Vector,
+    ///`DT_Local.m_audio.localSound[3]`
+    pub audio_local_sound_3: Vector,
+    ///`DT_Local.m_audio.localSound[4]`
+    pub audio_local_sound_4: Vector,
+    ///`DT_Local.m_audio.localSound[5]`
+    pub audio_local_sound_5: Vector,
+    ///`DT_Local.m_audio.localSound[6]`
+    pub audio_local_sound_6: Vector,
+    ///`DT_Local.m_audio.localSound[7]`
+    pub audio_local_sound_7: Vector,
+    ///`DT_Local.m_audio.soundscapeIndex`
+    pub audio_soundscape_index: i32,
+    ///`DT_Local.m_audio.localBits`
+    pub audio_local_bits: u8,
+    ///`DT_Local.m_audio.entIndex`
+    pub audio_ent_index: i32,
+    ///`DT_Local.m_szScriptOverlayMaterial`
+    pub script_overlay_material: String,
+    ///The elements of the `m_iAmmo` array table
+    pub ammo: [u32; 32],
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[0]`
+    pub view_offset_0: f32,
+    ///`DT_LocalPlayerExclusive.m_vecViewOffset[1]`
+    pub view_offset_1: f32,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_LocalPlayerExclusive.m_flFriction`
+    pub friction: f32,
+    ///`DT_LocalPlayerExclusive.m_fOnTarget`
+    pub on_target: u8,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_LocalPlayerExclusive.m_nNextThinkTick`
+    pub next_think_tick: i32,
+    ///`DT_LocalPlayerExclusive.m_hLastWeapon`
+    pub last_weapon: Handle,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_LocalPlayerExclusive.m_vecBaseVelocity`
+    pub base_velocity: Vector,
+    ///`DT_LocalPlayerExclusive.m_hConstraintEntity`
+    pub constraint_entity: Handle,
+    ///`DT_LocalPlayerExclusive.m_vecConstraintCenter`
+    pub constraint_center: Vector,
+    ///`DT_LocalPlayerExclusive.m_flConstraintRadius`
+    pub constraint_radius: f32,
+    ///`DT_LocalPlayerExclusive.m_flConstraintWidth`
+    pub constraint_width: f32,
+    ///`DT_LocalPlayerExclusive.m_flConstraintSpeedFactor`
+    pub constraint_speed_factor: f32,
+    ///`DT_LocalPlayerExclusive.m_flDeathTime`
+    pub death_time: f32,
+    ///`DT_LocalPlayerExclusive.m_nWaterLevel`
+    pub local_player_exclusive_water_level: u8,
+    ///`DT_LocalPlayerExclusive.m_flLaggedMovementValue`
+    pub lagged_movement_value: f32,
+    ///`DT_TFPlayerClassShared.m_iClass`
+    pub class: u8,
+    ///`DT_TFPlayerClassShared.m_iszClassIcon`
+    pub class_icon: String,
+    ///`DT_TFPlayerClassShared.m_iszCustomModel`
+    pub custom_model: String,
+    ///`DT_TFPlayerClassShared.m_vecCustomModelOffset`
+    pub custom_model_offset: Vector,
+    ///`DT_TFPlayerClassShared.m_angCustomModelRotation`
+    pub custom_model_rotation: Vector,
+    ///`DT_TFPlayerClassShared.m_bCustomModelRotates`
+    pub custom_model_rotates: bool,
+    ///`DT_TFPlayerClassShared.m_bCustomModelRotationSet`
+    pub custom_model_rotation_set: bool,
+    ///`DT_TFPlayerClassShared.m_bCustomModelVisibleToSelf`
+    pub custom_model_visible_to_self: bool,
+    ///`DT_TFPlayerClassShared.m_bUseClassAnimations`
+    pub use_class_animations: bool,
+    ///`DT_TFPlayerClassShared.m_iClassModelParity`
+    pub class_model_parity: u8,
+    ///The elements of the `m_flItemChargeMeter` array table
+    pub item_charge_meter: [f32; 11],
+    ///The elements of the `m_bPlayerDominated` array table
+    pub player_dominated: [bool; 102],
+    ///The elements of the `m_bPlayerDominatingMe` array table
+    pub player_dominating_me: [bool; 102],
+    ///`DT_TFPlayerScoringDataExclusive.m_iCaptures` in `m_ScoreData`
+    pub score_data_captures: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDefenses` in `m_ScoreData`
+    pub score_data_defenses: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iKills` in `m_ScoreData`
+    pub score_data_kills: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDeaths` in `m_ScoreData`
+    pub score_data_deaths: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iSuicides` in `m_ScoreData`
+    pub score_data_suicides: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDominations` in `m_ScoreData`
+    pub score_data_dominations: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iRevenge` in `m_ScoreData`
+    pub score_data_revenge: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsBuilt` in `m_ScoreData`
+    pub score_data_buildings_built: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsDestroyed` in `m_ScoreData`
+    pub score_data_buildings_destroyed: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iHeadshots` in `m_ScoreData`
+    pub score_data_headshots: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBackstabs` in `m_ScoreData`
+    pub score_data_backstabs: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iHealPoints` in `m_ScoreData`
+    pub score_data_heal_points: u32,
+    ///`DT_TFPlayerScoringDataExclusive.m_iInvulns` in `m_ScoreData`
+    pub score_data_invulns: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iTeleports` in `m_ScoreData`
+    pub score_data_teleports: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDamageDone` in `m_ScoreData`
+    pub score_data_damage_done: u32,
+    ///`DT_TFPlayerScoringDataExclusive.m_iCrits` in `m_ScoreData`
+    pub score_data_crits: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iResupplyPoints` in `m_ScoreData`
+    pub score_data_resupply_points: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iKillAssists` in `m_ScoreData`
+    pub score_data_kill_assists: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBonusPoints` in `m_ScoreData`
+    pub score_data_bonus_points: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iPoints` in `m_ScoreData`
+    pub score_data_points: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iCaptures` in `m_RoundScoreData`
+    pub round_score_data_captures: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDefenses` in `m_RoundScoreData`
+    pub round_score_data_defenses: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iKills` in `m_RoundScoreData`
+    pub round_score_data_kills: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDeaths` in `m_RoundScoreData`
+    pub round_score_data_deaths: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iSuicides` in `m_RoundScoreData`
+    pub round_score_data_suicides: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDominations` in `m_RoundScoreData`
+    pub round_score_data_dominations: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iRevenge` in `m_RoundScoreData`
+    pub round_score_data_revenge: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsBuilt` in `m_RoundScoreData`
+    pub round_score_data_buildings_built: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBuildingsDestroyed` in `m_RoundScoreData`
+    pub round_score_data_buildings_destroyed: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iHeadshots` in `m_RoundScoreData`
+    pub round_score_data_headshots: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBackstabs` in `m_RoundScoreData`
+    pub round_score_data_backstabs: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iHealPoints` in `m_RoundScoreData`
+    pub round_score_data_heal_points: u32,
+    ///`DT_TFPlayerScoringDataExclusive.m_iInvulns` in `m_RoundScoreData`
+    pub round_score_data_invulns: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iTeleports` in `m_RoundScoreData`
+    pub round_score_data_teleports: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iDamageDone` in `m_RoundScoreData`
+    pub round_score_data_damage_done: u32,
+    ///`DT_TFPlayerScoringDataExclusive.m_iCrits` in `m_RoundScoreData`
+    pub round_score_data_crits: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iResupplyPoints` in `m_RoundScoreData`
+    pub round_score_data_resupply_points: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iKillAssists` in `m_RoundScoreData`
+    pub round_score_data_kill_assists: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iBonusPoints` in `m_RoundScoreData`
+    pub round_score_data_bonus_points: u16,
+    ///`DT_TFPlayerScoringDataExclusive.m_iPoints` in `m_RoundScoreData`
+    pub round_score_data_points: u16,
+    ///`DT_TFPlayerSharedLocal.m_nDesiredDisguiseTeam`
+    pub desired_disguise_team: u8,
+    ///`DT_TFPlayerSharedLocal.m_nDesiredDisguiseClass`
+    pub desired_disguise_class: u8,
+    ///`DT_TFPlayerSharedLocal.m_bLastDisguisedAsOwnTeam`
+    pub last_disguised_as_own_team: bool,
+    ///`DT_TFPlayerSharedLocal.m_flStealthNoAttackExpire`
+    pub stealth_no_attack_expire: f32,
+    ///`DT_TFPlayerSharedLocal.m_flStealthNextChangeTime`
+    pub stealth_next_change_time: f32,
+    ///`DT_TFPlayerSharedLocal.m_flRageMeter`
+    pub rage_meter: f32,
+    ///`DT_TFPlayerSharedLocal.m_bRageDraining`
+    pub rage_draining: bool,
+    ///`DT_TFPlayerSharedLocal.m_flNextRageEarnTime`
+    pub next_rage_earn_time: f32,
+    ///`DT_TFPlayerSharedLocal.m_bInUpgradeZone`
+    pub in_upgrade_zone: bool,
+    ///`DT_TFPlayerConditionListExclusive._condition_bits`
+    pub condition_bits: u32,
+    ///The elements of the `m_nStreaks` array table
+    pub streaks: [i32; 4],
+    ///The number of elements in `m_ConditionData`
+    pub condition_data_len: u8,
+    ///`DT_TFPlayerConditionSource.m_pProvider`
+    pub p_provider: [u32; 131],
+    ///`DT_TFPlayerShared.m_nPlayerCond`
+    pub player_cond: u32,
+    ///`DT_TFPlayerShared.m_bJumping`
+    pub jumping: bool,
+    ///`DT_TFPlayerShared.m_nNumHealers`
+    pub num_healers: u8,
+    ///`DT_TFPlayerShared.m_iCritMult`
+    pub crit_mult: u8,
+    ///`DT_TFPlayerShared.m_iAirDash`
+    pub air_dash: u32,
+    ///`DT_TFPlayerShared.m_nAirDucked`
+    pub air_ducked: u8,
+    ///`DT_TFPlayerShared.m_flDuckTimer`
+    pub duck_timer: f32,
+    ///`DT_TFPlayerShared.m_nPlayerState`
+    pub player_state: u8,
+    ///`DT_TFPlayerShared.m_iDesiredPlayerClass`
+    pub desired_player_class: u8,
+    ///`DT_TFPlayerShared.m_flMovementStunTime`
+    pub movement_stun_time: f32,
+    ///`DT_TFPlayerShared.m_iMovementStunAmount`
+    pub movement_stun_amount: u8,
+    ///`DT_TFPlayerShared.m_iMovementStunParity`
+    pub movement_stun_parity: u8,
+    ///`DT_TFPlayerShared.m_hStunner`
+    pub stunner: Handle,
+    ///`DT_TFPlayerShared.m_iStunFlags`
+    pub stun_flags: u16,
+    ///`DT_TFPlayerShared.m_nArenaNumChanges`
+    pub arena_num_changes: u8,
+    ///`DT_TFPlayerShared.m_bArenaFirstBloodBoost`
+    pub arena_first_blood_boost: bool,
+    ///`DT_TFPlayerShared.m_iWeaponKnockbackID`
+    pub weapon_knockback_id: i32,
+    ///`DT_TFPlayerShared.m_bLoadoutUnavailable`
+    pub loadout_unavailable: bool,
+    ///`DT_TFPlayerShared.m_iItemFindBonus`
+    pub item_find_bonus: i32,
+    ///`DT_TFPlayerShared.m_bShieldEquipped`
+    pub shield_equipped: bool,
+    ///`DT_TFPlayerShared.m_bParachuteEquipped`
+    pub parachute_equipped: bool,
+    ///`DT_TFPlayerShared.m_iNextMeleeCrit`
+    pub next_melee_crit: i32,
+    ///`DT_TFPlayerShared.m_iDecapitations`
+    pub decapitations: u8,
+    ///`DT_TFPlayerShared.m_iRevengeCrits`
+    pub revenge_crits: u8,
+    ///`DT_TFPlayerShared.m_iDisguiseBody`
+    pub disguise_body: i32,
+    ///`DT_TFPlayerShared.m_hCarriedObject`
+    pub carried_object: Handle,
+    ///`DT_TFPlayerShared.m_bCarryingObject`
+    pub carrying_object: bool,
+    ///`DT_TFPlayerShared.m_flNextNoiseMakerTime`
+    pub next_noise_maker_time: f32,
+    ///`DT_TFPlayerShared.m_iSpawnRoomTouchCount`
+    pub spawn_room_touch_count: i32,
+    ///`DT_TFPlayerShared.m_iKillCountSinceLastDeploy`
+    pub kill_count_since_last_deploy: i32,
+    ///`DT_TFPlayerShared.m_flFirstPrimaryAttack`
+    pub first_primary_attack: f32,
+    ///`DT_TFPlayerShared.m_flEnergyDrinkMeter`
+    pub energy_drink_meter: f32,
+    ///`DT_TFPlayerShared.m_flHypeMeter`
+    pub hype_meter: f32,
+    ///`DT_TFPlayerShared.m_flChargeMeter`
+    pub charge_meter: f32,
+    ///`DT_TFPlayerShared.m_flInvisChangeCompleteTime`
+    pub invis_change_complete_time: f32,
+    ///`DT_TFPlayerShared.m_nDisguiseTeam`
+    pub disguise_team: u8,
+    ///`DT_TFPlayerShared.m_nDisguiseClass`
+    pub disguise_class: u8,
+    ///`DT_TFPlayerShared.m_nDisguiseSkinOverride`
+    pub disguise_skin_override: bool,
+    ///`DT_TFPlayerShared.m_nMaskClass`
+    pub mask_class: u8,
+    ///`DT_TFPlayerShared.m_hDisguiseTarget`
+    pub disguise_target: Handle,
+    ///`DT_TFPlayerShared.m_iDisguiseHealth`
+    pub disguise_health: i32,
+    ///`DT_TFPlayerShared.m_bFeignDeathReady`
+    pub feign_death_ready: bool,
+    ///`DT_TFPlayerShared.m_hDisguiseWeapon`
+    pub disguise_weapon: Handle,
+    ///`DT_TFPlayerShared.m_nTeamTeleporterUsed`
+    pub team_teleporter_used: u8,
+    ///`DT_TFPlayerShared.m_flCloakMeter`
+    pub cloak_meter: f32,
+    ///`DT_TFPlayerShared.m_flSpyTranqBuffDuration`
+    pub spy_tranq_buff_duration: f32,
+    ///`DT_TFPlayerShared.m_iTauntIndex`
+    pub taunt_index: u8,
+    ///`DT_TFPlayerShared.m_iTauntConcept`
+    pub taunt_concept: u8,
+    ///`DT_TFPlayerShared.m_nPlayerCondEx`
+    pub player_cond_ex: u32,
+    ///`DT_TFPlayerShared.m_iStunIndex`
+    pub stun_index: i8,
+    ///`DT_TFPlayerShared.m_nHalloweenBombHeadStage`
+    pub halloween_bomb_head_stage: u8,
+    ///`DT_TFPlayerShared.m_nPlayerCondEx2`
+    pub player_cond_ex_2: u32,
+    ///`DT_TFPlayerShared.m_nPlayerCondEx3`
+    pub player_cond_ex_3: u32,
+    ///`DT_TFPlayerShared.m_unTauntSourceItemID_Low`
+    pub taunt_source_item_id_low: u32,
+    ///`DT_TFPlayerShared.m_unTauntSourceItemID_High`
+    pub taunt_source_item_id_high: u32,
+    ///`DT_TFPlayerShared.m_flRuneCharge`
+    pub rune_charge: f32,
+    ///`DT_TFPlayerShared.m_bHasPasstimeBall`
+    pub has_passtime_ball: bool,
+    ///`DT_TFPlayerShared.m_bIsTargetedForPasstimePass`
+    pub is_targeted_for_passtime_pass: bool,
+    ///`DT_TFPlayerShared.m_hPasstimePassTarget`
+    pub passtime_pass_target: Handle,
+    ///`DT_TFPlayerShared.m_askForBallTime`
+    pub ask_for_ball_time: f32,
+    ///`DT_TFPlayerShared.m_bKingRuneBuffActive`
+    pub king_rune_buff_active: bool,
+    ///`DT_TFPlayerShared.m_nPlayerCondEx4`
+    pub player_cond_ex_4: u32,
+    ///`DT_TFPlayerShared.m_flHolsterAnimTime`
+    pub holster_anim_time: f32,
+    ///`DT_TFPlayerShared.m_hSwitchTo`
+    pub switch_to: Handle,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_TFLocalPlayerExclusive."player_object_array"`
+    pub player_object_array: Vec<SendPropValue>,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_TFLocalPlayerExclusive.m_bIsCoaching`
+    pub is_coaching: bool,
+    ///`DT_TFLocalPlayerExclusive.m_hCoach`
+    pub coach: Handle,
+    ///`DT_TFLocalPlayerExclusive.m_hStudent`
+    pub student: Handle,
+    ///`DT_TFLocalPlayerExclusive.m_nCurrency`
+    pub currency: i32,
+    ///`DT_TFLocalPlayerExclusive.m_nExperienceLevel`
+    pub experience_level: u8,
+    ///`DT_TFLocalPlayerExclusive.m_nExperienceLevelProgress`
+    pub experience_level_progress: u8,
+    ///`DT_TFLocalPlayerExclusive.m_bMatchSafeToLeave`
+    pub match_safe_to_leave: bool,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///`DT_AttributeManager.m_hOuter`
+    pub outer: Handle,
+    ///`DT_AttributeManager.m_ProviderType`
+    pub provider_type: u8,
+    ///`DT_AttributeManager.m_iReapplyProvisionParity`
+    pub reapply_provision_parity: u8,
+    ///`DT_TFSendHealersDataTable.m_nActiveWpnClip`
+    pub active_wpn_clip: u32,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseCombatCharacter.m_bGlowEnabled`
+    pub glow_enabled: bool,
+    ///`DT_BaseCombatCharacter.m_hActiveWeapon`
+    pub active_weapon: Handle,
+    ///`DT_BasePlayer.m_hVehicle`
+    pub vehicle: Handle,
+    ///`DT_BasePlayer.m_hUseEntity`
+    pub use_entity: Handle,
+    ///`DT_BasePlayer.m_lifeState`
+    pub life_state: u8,
+    ///`DT_BasePlayer.m_iBonusProgress`
+    pub bonus_progress: i16,
+    ///`DT_BasePlayer.m_iBonusChallenge`
+    pub bonus_challenge: i8,
+    ///`DT_BasePlayer.m_flMaxspeed`
+    pub maxspeed: f32,
+    ///`DT_BasePlayer.m_iObserverMode`
+    pub observer_mode: u8,
+    ///`DT_BasePlayer.m_hObserverTarget`
+    pub observer_target: Handle,
+    ///`DT_BasePlayer.m_iFOV`
+    pub fov: u8,
+    ///`DT_BasePlayer.m_iFOVStart`
+    pub fov_start: u8,
+    ///`DT_BasePlayer.m_flFOVTime`
+    pub fov_time: f32,
+    ///`DT_BasePlayer.m_iDefaultFOV`
+    pub default_fov: u8,
+    ///`DT_BasePlayer.m_hZoomOwner`
+    pub zoom_owner: Handle,
+    ///`DT_BasePlayer.m_hViewModel`
+    pub view_model: Vec<SendPropValue>,
+    ///`DT_BasePlayer.m_szLastPlaceName`
+    pub last_place_name: String,
+    ///`DT_TFPlayer.m_bSaveMeParity`
+    pub save_me_parity: bool,
+    ///`DT_TFPlayer.m_bIsMiniBoss`
+    pub is_mini_boss: bool,
+    ///`DT_TFPlayer.m_bIsABot`
+    pub is_a_bot: bool,
+    ///`DT_TFPlayer.m_nBotSkill`
+    pub bot_skill: u8,
+    ///`DT_TFPlayer.m_nWaterLevel`
+    pub tf_player_water_level: u8,
+    ///`DT_TFPlayer.m_hRagdoll`
+    pub ragdoll: Handle,
+    ///`DT_TFPlayer.m_hItem`
+    pub item: Handle,
+    ///`DT_TFPlayer.m_bAllowMoveDuringTaunt`
+    pub allow_move_during_taunt: bool,
+    ///`DT_TFPlayer.m_bIsReadyToHighFive`
+    pub is_ready_to_high_five: bool,
+    ///`DT_TFPlayer.m_hHighFivePartner`
+    pub high_five_partner: Handle,
+    ///`DT_TFPlayer.m_nForceTauntCam`
+    pub force_taunt_cam: u8,
+    ///`DT_TFPlayer.m_flTauntYaw`
+    pub taunt_yaw: f32,
+    ///`DT_TFPlayer.m_nActiveTauntSlot`
+    pub active_taunt_slot: i32,
+    ///`DT_TFPlayer.m_iTauntItemDefIndex`
+    pub taunt_item_def_index: i16,
+    ///`DT_TFPlayer.m_flCurrentTauntMoveSpeed`
+    pub current_taunt_move_speed: f32,
+    ///`DT_TFPlayer.m_flVehicleReverseTime`
+    pub vehicle_reverse_time: f32,
+    ///`DT_TFPlayer.m_flMvMLastDamageTime`
+    pub mv_m_last_damage_time: f32,
+    ///`DT_TFPlayer.m_bInPowerPlay`
+    pub in_power_play: bool,
+    ///`DT_TFPlayer.m_iSpawnCounter`
+    pub spawn_counter: i8,
+    ///`DT_TFPlayer.m_bArenaSpectator`
+    pub arena_spectator: bool,
+    ///`DT_TFPlayer.m_flHeadScale`
+    pub head_scale: f32,
+    ///`DT_TFPlayer.m_flTorsoScale`
+    pub torso_scale: f32,
+    ///`DT_TFPlayer.m_flHandScale`
+    pub hand_scale: f32,
+    ///`DT_TFPlayer.m_bUseBossHealthBar`
+    pub use_boss_health_bar: bool,
+    ///`DT_TFPlayer.m_bUsingVRHeadset`
+    pub using_vr_headset: bool,
+    ///`DT_TFPlayer.m_bForcedSkin`
+    pub tf_player_forced_skin: bool,
+    ///`DT_TFPlayer.m_nForcedSkin`
+    pub tf_player_forced_skin_: i16,
+    ///`DT_TFPlayer.m_flKartNextAvailableBoost`
+    pub kart_next_available_boost: f32,
+    ///`DT_TFPlayer.m_iKartHealth`
+    pub kart_health: i32,
+    ///`DT_TFPlayer.m_iKartState`
+    pub kart_state: i32,
+    ///`DT_TFPlayer.m_hGrapplingHookTarget`
+    pub grappling_hook_target: Handle,
+    ///`DT_TFPlayer.m_hSecondaryLastWeapon`
+    pub secondary_last_weapon: Handle,
+    ///`DT_TFPlayer.m_bUsingActionSlot`
+    pub using_action_slot: bool,
+    ///`DT_TFPlayer.m_flInspectTime`
+    pub inspect_time: f32,
+    ///`DT_TFPlayer.m_flHelpmeButtonPressTime`
+    pub helpme_button_press_time: f32,
+    ///`DT_TFPlayer.m_iCampaignMedals`
+    pub campaign_medals: i32,
+    ///`DT_TFPlayer.m_iPlayerSkinOverride`
+    pub player_skin_override: i32,
+    ///`DT_TFPlayer.m_bViewingCYOAPDA`
+    pub viewing_cyoapda: bool,
+    ///`DT_TFPlayer.m_bRegenerating`
+    pub regenerating: bool,
+}
+impl Default for TFPlayer {
+    fn default() -> Self {
+        TFPlayer {
+            ducktime: Default::default(),
+            fall_velocity: Default::default(),
+            punch_angle: Default::default(),
+            view_offset_2: Default::default(),
+            tick_base: Default::default(),
+            ground_entity: Default::default(),
+            velocity_0: Default::default(),
+            velocity_1: Default::default(),
+            velocity_2: Default::default(),
+            tf_local_player_exclusive_origin: Default::default(),
+            tf_local_player_exclusive_origin_2: Default::default(),
+            tf_local_player_exclusive_eye_angles_0: Default::default(),
+            tf_local_player_exclusive_eye_angles_1: Default::default(),
+            tf_non_local_player_exclusive_origin: Default::default(),
+            tf_non_local_player_exclusive_origin_2: Default::default(),
+            tf_non_local_player_exclusive_eye_angles_0: Default::default(),
+            tf_non_local_player_exclusive_eye_angles_1: Default::default(),
+            simulation_time: Default::default(),
+            health: Default::default(),
+            flags: Default::default(),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            next_attack: Default::default(),
+            my_weapons: std::array::from_fn(|_| Default::default()),
+            attributes_len: Default::default(),
+            attribute_definition_index: std::array::from_fn(|_| Default::default()),
+            raw_value_32: std::array::from_fn(|_| Default::default()),
+            refundable_currency: std::array::from_fn(|_| Default::default()),
+            deadflag: Default::default(),
+            my_wearables_len: Default::default(),
+            my_wearables: std::array::from_fn(|_| Default::default()),
+            area_bits: std::array::from_fn(|_| Default::default()),
+            area_portal_bits: std::array::from_fn(|_| Default::default()),
+            hide_hud: Default::default(),
+            fov_rate: Default::default(),
+            ducked: Default::default(),
+            ducking: Default::default(),
+            in_duck_jump: Default::default(),
+            mins_pre_scaled: Default::default(),
+            duck_jump_time: Default::default(),
+            jump_time: Default::default(),
+            maxs_pre_scaled: Default::default(),
+            mins: Default::default(),
+            punch_angle_vel: Default::default(),
+            draw_viewmodel: Default::default(),
+            wearing_suit: Default::default(),
+            poisoned: Default::default(),
+            force_local_player_draw: Default::default(),
+            step_size: Default::default(),
+            allow_auto_movement: Default::default(),
+            skybox_3d_scale: Default::default(),
+            skybox_3d_origin: Default::default(),
+            skybox_3d_area: Default::default(),
+            skybox_3d_fog_enable: Default::default(),
+            skybox_3d_fog_blend: Default::default(),
+            skybox_3d_fog_dir_primary: Default::default(),
+            skybox_3d_fog_color_primary: Default::default(),
+            skybox_3d_fog_color_secondary: Default::default(),
+            skybox_3d_fog_start: Default::default(),
+            skybox_3d_fog_end: Default::default(),
+            skybox_3d_fog_maxdensity: Default::default(),
+            player_fog_ctrl: Default::default(),
+            audio_local_sound_0: Default::default(),
+            audio_local_sound_1: Default::default(),
+            audio_local_sound_2: Default::default(),
+            audio_local_sound_3: Default::default(),
+            audio_local_sound_4: Default::default(),
+            audio_local_sound_5: Default::default(),
+            audio_local_sound_6: Default::default(),
+            audio_local_sound_7: Default::default(),
+            audio_soundscape_index: Default::default(),
+            audio_local_bits: Default::default(),
+            audio_ent_index: Default::default(),
+            script_overlay_material: Default::default(),
+            ammo: std::array::from_fn(|_| Default::default()),
+            view_offset_0: Default::default(),
+            view_offset_1: Default::default(),
+            maxs: Default::default(),
+            friction: Default::default(),
+            on_target: Default::default(),
+            solid_type: Default::default(),
+            next_think_tick: Default::default(),
+            last_weapon: Default::default(),
+            solid_flags: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            base_velocity: Default::default(),
+            constraint_entity: Default::default(),
+            constraint_center: Default::default(),
+            constraint_radius: Default::default(),
+            constraint_width: Default::default(),
+            constraint_speed_factor: Default::default(),
+            death_time: Default::default(),
+            local_player_exclusive_water_level: Default::default(),
+            lagged_movement_value: Default::default(),
+            class: Default::default(),
+            class_icon: Default::default(),
+            custom_model: Default::default(),
+            custom_model_offset: Default::default(),
+            custom_model_rotation: Default::default(),
+            custom_model_rotates: Default::default(),
+            custom_model_rotation_set: Default::default(),
+            custom_model_visible_to_self: Default::default(),
+            use_class_animations: Default::default(),
+            class_model_parity: Default::default(),
+            item_charge_meter: std::array::from_fn(|_| Default::default()),
+            player_dominated: std::array::from_fn(|_| Default::default()),
+            player_dominating_me: std::array::from_fn(|_| Default::default()),
+            score_data_captures: Default::default(),
+            score_data_defenses: Default::default(),
+            score_data_kills: Default::default(),
+            score_data_deaths: Default::default(),
+            score_data_suicides: Default::default(),
+            score_data_dominations: Default::default(),
+            score_data_revenge: Default::default(),
+            score_data_buildings_built: Default::default(),
+            score_data_buildings_destroyed: Default::default(),
+            score_data_headshots: Default::default(),
+            score_data_backstabs: Default::default(),
+            score_data_heal_points: Default::default(),
+            score_data_invulns: Default::default(),
+            score_data_teleports: Default::default(),
+            score_data_damage_done: Default::default(),
+            score_data_crits: Default::default(),
+            score_data_resupply_points: Default::default(),
+            score_data_kill_assists: Default::default(),
+            score_data_bonus_points: Default::default(),
+            score_data_points: Default::default(),
+            round_score_data_captures: Default::default(),
+            round_score_data_defenses: Default::default(),
+            round_score_data_kills: Default::default(),
+            round_score_data_deaths: Default::default(),
+            round_score_data_suicides: Default::default(),
+            round_score_data_dominations: Default::default(),
+            round_score_data_revenge: Default::default(),
+            round_score_data_buildings_built: Default::default(),
+            round_score_data_buildings_destroyed: Default::default(),
+            round_score_data_headshots: Default::default(),
+            round_score_data_backstabs: Default::default(),
+            round_score_data_heal_points: Default::default(),
+            round_score_data_invulns: Default::default(),
+            round_score_data_teleports: Default::default(),
+            round_score_data_damage_done: Default::default(),
+            round_score_data_crits: Default::default(),
+            round_score_data_resupply_points: Default::default(),
+            round_score_data_kill_assists: Default::default(),
+            round_score_data_bonus_points: Default::default(),
+            round_score_data_points: Default::default(),
+            desired_disguise_team: Default::default(),
+            desired_disguise_class: Default::default(),
+            last_disguised_as_own_team: Default::default(),
+            stealth_no_attack_expire: Default::default(),
+            stealth_next_change_time: Default::default(),
+            rage_meter: Default::default(),
+            rage_draining: Default::default(),
+            next_rage_earn_time: Default::default(),
+            in_upgrade_zone: Default::default(),
+            condition_bits: Default::default(),
+            streaks: std::array::from_fn(|_| Default::default()),
+            condition_data_len: Default::default(),
+            p_provider: std::array::from_fn(|_| Default::default()),
+            player_cond: Default::default(),
+            jumping: Default::default(),
+            num_healers: Default::default(),
+            crit_mult: Default::default(),
+            air_dash: Default::default(),
+            air_ducked: Default::default(),
+            duck_timer: Default::default(),
+            player_state: Default::default(),
+            desired_player_class: Default::default(),
+            movement_stun_time: Default::default(),
+            movement_stun_amount: Default::default(),
+            movement_stun_parity: Default::default(),
+            stunner: Default::default(),
+            stun_flags: Default::default(),
+            arena_num_changes: Default::default(),
+            arena_first_blood_boost: Default::default(),
+            weapon_knockback_id: Default::default(),
+            loadout_unavailable: Default::default(),
+            item_find_bonus: Default::default(),
+            shield_equipped: Default::default(),
+            parachute_equipped: Default::default(),
+            next_melee_crit: Default::default(),
+            decapitations: Default::default(),
+            revenge_crits: Default::default(),
+            disguise_body: Default::default(),
+            carried_object: Default::default(),
+            carrying_object: Default::default(),
+            next_noise_maker_time: Default::default(),
+            spawn_room_touch_count: Default::default(),
+            kill_count_since_last_deploy: Default::default(),
+            first_primary_attack: Default::default(),
+            energy_drink_meter: Default::default(),
+            hype_meter: Default::default(),
+            charge_meter: Default::default(),
+            invis_change_complete_time: Default::default(),
+            disguise_team: Default::default(),
+            disguise_class: Default::default(),
+            disguise_skin_override: Default::default(),
+            mask_class: Default::default(),
+            disguise_target: Default::default(),
+            disguise_health: Default::default(),
+            feign_death_ready: Default::default(),
+            disguise_weapon: Default::default(),
+            team_teleporter_used: Default::default(),
+            cloak_meter: Default::default(),
+            spy_tranq_buff_duration: Default::default(),
+            taunt_index: Default::default(),
+            taunt_concept: Default::default(),
+            player_cond_ex: Default::default(),
+            stun_index: Default::default(),
+            halloween_bomb_head_stage: Default::default(),
+            player_cond_ex_2: Default::default(),
+            player_cond_ex_3: Default::default(),
+            taunt_source_item_id_low: Default::default(),
+            taunt_source_item_id_high: Default::default(),
+            rune_charge: Default::default(),
+            has_passtime_ball: Default::default(),
+            is_targeted_for_passtime_pass: Default::default(),
+            passtime_pass_target: Default::default(),
+            ask_for_ball_time: Default::default(),
+            king_rune_buff_active: Default::default(),
+            player_cond_ex_4: Default::default(),
+            holster_anim_time: Default::default(),
+            switch_to: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            player_object_array: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            is_coaching: Default::default(),
+            coach: Default::default(),
+            student: Default::default(),
+            currency: Default::default(),
+            experience_level: Default::default(),
+            experience_level_progress: Default::default(),
+            match_safe_to_leave: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            outer: Default::default(),
+            provider_type: Default::default(),
+            reapply_provision_parity: Default::default(),
+            active_wpn_clip: Default::default(),
+            interpolation_frame: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            glow_enabled: Default::default(),
+            active_weapon: Default::default(),
+            vehicle: Default::default(),
+            use_entity: Default::default(),
+            life_state: Default::default(),
+            bonus_progress: Default::default(),
+            bonus_challenge: Default::default(),
+            maxspeed: Default::default(),
+            observer_mode: Default::default(),
+            observer_target: Default::default(),
+            fov: Default::default(),
+            fov_start: Default::default(),
+            fov_time: Default::default(),
+            default_fov: Default::default(),
+            zoom_owner: Default::default(),
+            view_model: Default::default(),
+            last_place_name: Default::default(),
+            save_me_parity: Default::default(),
+            is_mini_boss: Default::default(),
+            is_a_bot: Default::default(),
+            bot_skill: Default::default(),
+            tf_player_water_level: Default::default(),
+            ragdoll: Default::default(),
+            item: Default::default(),
+            allow_move_during_taunt: Default::default(),
+            is_ready_to_high_five: Default::default(),
+            high_five_partner: Default::default(),
+            force_taunt_cam: Default::default(),
+            taunt_yaw: Default::default(),
+            active_taunt_slot: Default::default(),
+            taunt_item_def_index: Default::default(),
+            current_taunt_move_speed: Default::default(),
+            vehicle_reverse_time: Default::default(),
+            mv_m_last_damage_time: Default::default(),
+            in_power_play: Default::default(),
+            spawn_counter: Default::default(),
+            arena_spectator: Default::default(),
+            head_scale: Default::default(),
+            torso_scale: Default::default(),
+            hand_scale: Default::default(),
+            use_boss_health_bar: Default::default(),
+            using_vr_headset: Default::default(),
+            tf_player_forced_skin: Default::default(),
+            tf_player_forced_skin_: Default::default(),
+            kart_next_available_boost: Default::default(),
+            kart_health: Default::default(),
+            kart_state: Default::default(),
+            grappling_hook_target: Default::default(),
+            secondary_last_weapon: Default::default(),
+            using_action_slot: Default::default(),
+            inspect_time: Default::default(),
+            helpme_button_press_time: Default::default(),
+            campaign_medals: Default::default(),
+            player_skin_override: Default::default(),
+            viewing_cyoapda: Default::default(),
+            regenerating: Default::default(),
+        }
+    }
+}
+impl TFPlayer {
+    pub const CLASS_NAME: &'static str = "CTFPlayer";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, location: Option<PropLocation>) {
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const MY_WEAPONS_000: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "000");
+        const MY_WEAPONS_001: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "001");
+        const MY_WEAPONS_002: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "002");
+        const MY_WEAPONS_003: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "003");
+        const MY_WEAPONS_004: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "004");
+        const MY_WEAPONS_005: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "005");
+        const MY_WEAPONS_006: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "006");
+        const MY_WEAPONS_007: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "007");
+        const MY_WEAPONS_008: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "008");
+        const MY_WEAPONS_009: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "009");
+        const MY_WEAPONS_010: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "010");
+        const MY_WEAPONS_011: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "011");
+        const MY_WEAPONS_012: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "012");
+        const MY_WEAPONS_013: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "013");
+        const MY_WEAPONS_014: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "014");
+        const MY_WEAPONS_015: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "015");
+        const MY_WEAPONS_016: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "016");
+        const MY_WEAPONS_017: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "017");
+        const MY_WEAPONS_018: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "018");
+        const MY_WEAPONS_019: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "019");
+        const MY_WEAPONS_020: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "020");
+        const MY_WEAPONS_021: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "021");
+        const MY_WEAPONS_022: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "022");
+        const MY_WEAPONS_023: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "023");
+        const MY_WEAPONS_024: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "024");
+        const MY_WEAPONS_025: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "025");
+        const MY_WEAPONS_026: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "026");
+        const MY_WEAPONS_027: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "027");
+        const MY_WEAPONS_028: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "028");
+        const MY_WEAPONS_029: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "029");
+        const MY_WEAPONS_030: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "030");
+        const MY_WEAPONS_031: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "031");
+        const MY_WEAPONS_032: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "032");
+        const MY_WEAPONS_033: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "033");
+        const MY_WEAPONS_034: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "034");
+        const MY_WEAPONS_035: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "035");
+        const MY_WEAPONS_036: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "036");
+        const MY_WEAPONS_037: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "037");
+        const MY_WEAPONS_038: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "038");
+        const MY_WEAPONS_039: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "039");
+        const MY_WEAPONS_040: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "040");
+        const MY_WEAPONS_041: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "041");
+        const MY_WEAPONS_042: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "042");
+        const MY_WEAPONS_043: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "043");
+        const MY_WEAPONS_044: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "044");
+        const MY_WEAPONS_045: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "045");
+        const MY_WEAPONS_046: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "046");
+        const MY_WEAPONS_047: SendPropIdentifier = SendPropIdentifier::new("m_hMyWeapons", "047");
+        const MY_WEARABLES_000: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "000");
+        const MY_WEARABLES_001: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "001");
+        const MY_WEARABLES_002: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "002");
+        const MY_WEARABLES_003: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "003");
+        const MY_WEARABLES_004: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "004");
+        const MY_WEARABLES_005: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "005");
+        const MY_WEARABLES_006: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "006");
+        const MY_WEARABLES_007: SendPropIdentifier =
+            SendPropIdentifier::new("_ST_m_hMyWearables_8", "007");
+        const AREA_BITS_000: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "000");
+        const AREA_BITS_001: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "001");
+        const AREA_BITS_002: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "002");
+        const AREA_BITS_003: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "003");
+        const AREA_BITS_004: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "004");
+        const AREA_BITS_005: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "005");
+        const AREA_BITS_006: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "006");
+        const AREA_BITS_007: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "007");
+        const AREA_BITS_008: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "008");
+        const AREA_BITS_009: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "009");
+        const AREA_BITS_010: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "010");
+        const AREA_BITS_011: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "011");
+        const AREA_BITS_012: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "012");
+        const AREA_BITS_013: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "013");
+        const AREA_BITS_014: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "014");
+        const AREA_BITS_015: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "015");
+        const AREA_BITS_016: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "016");
+        const AREA_BITS_017: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "017");
+        const AREA_BITS_018: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "018");
+        const AREA_BITS_019: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "019");
+        const AREA_BITS_020: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "020");
+        const AREA_BITS_021: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "021");
+        const AREA_BITS_022: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "022");
+        const AREA_BITS_023: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "023");
+        const AREA_BITS_024: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "024");
+        const AREA_BITS_025: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "025");
+        const AREA_BITS_026: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "026");
+        const AREA_BITS_027: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "027");
+        const AREA_BITS_028: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "028");
+        const AREA_BITS_029: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "029");
+        const AREA_BITS_030: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "030");
+        const AREA_BITS_031: SendPropIdentifier = SendPropIdentifier::new("m_chAreaBits", "031");
+        const AREA_PORTAL_BITS_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "000");
+        const AREA_PORTAL_BITS_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "001");
+        const AREA_PORTAL_BITS_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "002");
+        const AREA_PORTAL_BITS_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "003");
+        const AREA_PORTAL_BITS_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "004");
+        const AREA_PORTAL_BITS_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "005");
+        const AREA_PORTAL_BITS_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "006");
+        const AREA_PORTAL_BITS_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "007");
+        const AREA_PORTAL_BITS_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "008");
+        const AREA_PORTAL_BITS_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "009");
+        const AREA_PORTAL_BITS_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "010");
+        const AREA_PORTAL_BITS_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "011");
+        const AREA_PORTAL_BITS_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "012");
+        const AREA_PORTAL_BITS_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "013");
+        const AREA_PORTAL_BITS_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "014");
+        const AREA_PORTAL_BITS_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "015");
+        const AREA_PORTAL_BITS_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "016");
+        const AREA_PORTAL_BITS_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "017");
+        const AREA_PORTAL_BITS_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "018");
+        const AREA_PORTAL_BITS_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "019");
+        const AREA_PORTAL_BITS_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "020");
+        const AREA_PORTAL_BITS_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "021");
+        const AREA_PORTAL_BITS_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "022");
+        const AREA_PORTAL_BITS_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_chAreaPortalBits", "023");
+        const AMMO_000: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "000");
+        const AMMO_001: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "001");
+        const AMMO_002: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "002");
+        const AMMO_003: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "003");
+        const AMMO_004: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "004");
+        const AMMO_005: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "005");
+        const AMMO_006: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "006");
+        const AMMO_007: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "007");
+        const AMMO_008: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "008");
+        const AMMO_009: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "009");
+        const AMMO_010: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "010");
+        const AMMO_011: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "011");
+        const AMMO_012: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "012");
+        const AMMO_013: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "013");
+        const AMMO_014: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "014");
+        const AMMO_015: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "015");
+        const AMMO_016: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "016");
+        const AMMO_017: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "017");
+        const AMMO_018: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "018");
+        const AMMO_019: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "019");
+        const AMMO_020: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "020");
+        const AMMO_021: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "021");
+        const AMMO_022: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "022");
+        const AMMO_023: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "023");
+        const AMMO_024: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "024");
+        const AMMO_025: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "025");
+        const AMMO_026: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "026");
+        const AMMO_027: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "027");
+        const AMMO_028: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "028");
+        const AMMO_029: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "029");
+        const AMMO_030: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "030");
+        const AMMO_031: SendPropIdentifier = SendPropIdentifier::new("m_iAmmo", "031");
+        const ITEM_CHARGE_METER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "000");
+        const ITEM_CHARGE_METER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "001");
+        const ITEM_CHARGE_METER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "002");
+        const ITEM_CHARGE_METER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "003");
+        const ITEM_CHARGE_METER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "004");
+        const ITEM_CHARGE_METER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "005");
+        const ITEM_CHARGE_METER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "006");
+        const ITEM_CHARGE_METER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "007");
+        const ITEM_CHARGE_METER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "008");
+        const ITEM_CHARGE_METER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "009");
+        const ITEM_CHARGE_METER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flItemChargeMeter", "010");
+        const PLAYER_DOMINATED_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "000");
+        const PLAYER_DOMINATED_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "001");
+        const PLAYER_DOMINATED_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "002");
+        const PLAYER_DOMINATED_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "003");
+        const PLAYER_DOMINATED_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "004");
+        const PLAYER_DOMINATED_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "005");
+        const PLAYER_DOMINATED_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "006");
+        const PLAYER_DOMINATED_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "007");
+        const PLAYER_DOMINATED_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "008");
+        const PLAYER_DOMINATED_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "009");
+        const PLAYER_DOMINATED_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "010");
+        const PLAYER_DOMINATED_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "011");
+        const PLAYER_DOMINATED_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "012");
+        const PLAYER_DOMINATED_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "013");
+        const PLAYER_DOMINATED_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "014");
+        const PLAYER_DOMINATED_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "015");
+        const PLAYER_DOMINATED_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "016");
+        const PLAYER_DOMINATED_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "017");
+        const PLAYER_DOMINATED_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "018");
+        const PLAYER_DOMINATED_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "019");
+        const PLAYER_DOMINATED_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "020");
+        const PLAYER_DOMINATED_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "021");
+        const PLAYER_DOMINATED_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "022");
+        const PLAYER_DOMINATED_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "023");
+        const PLAYER_DOMINATED_024: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "024");
+        const PLAYER_DOMINATED_025: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "025");
+        const PLAYER_DOMINATED_026: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "026");
+        const PLAYER_DOMINATED_027: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "027");
+        const PLAYER_DOMINATED_028: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "028");
+        const PLAYER_DOMINATED_029: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "029");
+        const PLAYER_DOMINATED_030: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "030");
+        const PLAYER_DOMINATED_031: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "031");
+        const PLAYER_DOMINATED_032: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "032");
+        const PLAYER_DOMINATED_033: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "033");
+        const PLAYER_DOMINATED_034: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "034");
+        const PLAYER_DOMINATED_035: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "035");
+        const PLAYER_DOMINATED_036: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "036");
+        const PLAYER_DOMINATED_037: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "037");
+        const PLAYER_DOMINATED_038: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "038");
+        const PLAYER_DOMINATED_039: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "039");
+        const PLAYER_DOMINATED_040: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "040");
+        const PLAYER_DOMINATED_041: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "041");
+        const PLAYER_DOMINATED_042: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "042");
+        const PLAYER_DOMINATED_043: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "043");
+        const PLAYER_DOMINATED_044: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "044");
+        const PLAYER_DOMINATED_045: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "045");
+        const PLAYER_DOMINATED_046: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "046");
+        const PLAYER_DOMINATED_047: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "047");
+        const PLAYER_DOMINATED_048: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "048");
+        const PLAYER_DOMINATED_049: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "049");
+        const PLAYER_DOMINATED_050: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "050");
+        const PLAYER_DOMINATED_051: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "051");
+        const PLAYER_DOMINATED_052: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "052");
+        const PLAYER_DOMINATED_053: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "053");
+        const PLAYER_DOMINATED_054: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "054");
+        const PLAYER_DOMINATED_055: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "055");
+        const PLAYER_DOMINATED_056: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "056");
+        const PLAYER_DOMINATED_057: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "057");
+        const PLAYER_DOMINATED_058: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "058");
+        const PLAYER_DOMINATED_059: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "059");
+        const PLAYER_DOMINATED_060: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "060");
+        const PLAYER_DOMINATED_061: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "061");
+        const PLAYER_DOMINATED_062: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "062");
+        const PLAYER_DOMINATED_063: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "063");
+        const PLAYER_DOMINATED_064: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "064");
+        const PLAYER_DOMINATED_065: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "065");
+        const PLAYER_DOMINATED_066: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "066");
+        const PLAYER_DOMINATED_067: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "067");
+        const PLAYER_DOMINATED_068: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "068");
+        const PLAYER_DOMINATED_069: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "069");
+        const PLAYER_DOMINATED_070: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "070");
+        const PLAYER_DOMINATED_071: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "071");
+        const PLAYER_DOMINATED_072: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "072");
+        const PLAYER_DOMINATED_073: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "073");
+        const PLAYER_DOMINATED_074: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "074");
+        const PLAYER_DOMINATED_075: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "075");
+        const PLAYER_DOMINATED_076: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "076");
+        const PLAYER_DOMINATED_077: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "077");
+        const PLAYER_DOMINATED_078: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "078");
+        const PLAYER_DOMINATED_079: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "079");
+        const PLAYER_DOMINATED_080: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "080");
+        const PLAYER_DOMINATED_081: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "081");
+        const PLAYER_DOMINATED_082: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "082");
+        const PLAYER_DOMINATED_083: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "083");
+        const PLAYER_DOMINATED_084: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "084");
+        const PLAYER_DOMINATED_085: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "085");
+        const PLAYER_DOMINATED_086: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "086");
+        const PLAYER_DOMINATED_087: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "087");
+        const PLAYER_DOMINATED_088: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "088");
+        const PLAYER_DOMINATED_089: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "089");
+        const PLAYER_DOMINATED_090: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "090");
+        const PLAYER_DOMINATED_091: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "091");
+        const PLAYER_DOMINATED_092: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "092");
+        const PLAYER_DOMINATED_093: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "093");
+        const PLAYER_DOMINATED_094: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "094");
+        const PLAYER_DOMINATED_095: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "095");
+        const PLAYER_DOMINATED_096: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "096");
+        const PLAYER_DOMINATED_097: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "097");
+        const PLAYER_DOMINATED_098: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "098");
+        const PLAYER_DOMINATED_099: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "099");
+        const PLAYER_DOMINATED_100: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "100");
+        const PLAYER_DOMINATED_101: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominated", "101");
+        const PLAYER_DOMINATING_ME_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "000");
+        const PLAYER_DOMINATING_ME_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "001");
+        const PLAYER_DOMINATING_ME_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "002");
+        const PLAYER_DOMINATING_ME_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "003");
+        const PLAYER_DOMINATING_ME_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "004");
+        const PLAYER_DOMINATING_ME_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "005");
+        const PLAYER_DOMINATING_ME_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "006");
+        const PLAYER_DOMINATING_ME_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "007");
+        const PLAYER_DOMINATING_ME_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "008");
+        const PLAYER_DOMINATING_ME_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "009");
+        const PLAYER_DOMINATING_ME_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "010");
+        const PLAYER_DOMINATING_ME_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "011");
+        const PLAYER_DOMINATING_ME_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "012");
+        const PLAYER_DOMINATING_ME_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "013");
+        const PLAYER_DOMINATING_ME_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "014");
+        const PLAYER_DOMINATING_ME_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "015");
+        const PLAYER_DOMINATING_ME_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "016");
+        const PLAYER_DOMINATING_ME_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "017");
+        const PLAYER_DOMINATING_ME_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "018");
+        const PLAYER_DOMINATING_ME_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "019");
+        const PLAYER_DOMINATING_ME_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "020");
+        const PLAYER_DOMINATING_ME_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "021");
+        const PLAYER_DOMINATING_ME_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "022");
+        const PLAYER_DOMINATING_ME_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "023");
+        const PLAYER_DOMINATING_ME_024: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "024");
+        const PLAYER_DOMINATING_ME_025: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "025");
+        const PLAYER_DOMINATING_ME_026: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "026");
+        const PLAYER_DOMINATING_ME_027: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "027");
+        const PLAYER_DOMINATING_ME_028: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "028");
+        const PLAYER_DOMINATING_ME_029: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "029");
+        const PLAYER_DOMINATING_ME_030: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "030");
+        const PLAYER_DOMINATING_ME_031: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "031");
+        const PLAYER_DOMINATING_ME_032: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "032");
+        const PLAYER_DOMINATING_ME_033: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "033");
+        const PLAYER_DOMINATING_ME_034: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "034");
+        const PLAYER_DOMINATING_ME_035: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "035");
+        const PLAYER_DOMINATING_ME_036: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "036");
+        const PLAYER_DOMINATING_ME_037: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "037");
+        const PLAYER_DOMINATING_ME_038: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "038");
+        const PLAYER_DOMINATING_ME_039: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "039");
+        const PLAYER_DOMINATING_ME_040: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "040");
+        const PLAYER_DOMINATING_ME_041: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "041");
+        const PLAYER_DOMINATING_ME_042: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "042");
+        const PLAYER_DOMINATING_ME_043: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "043");
+        const PLAYER_DOMINATING_ME_044: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "044");
+        const PLAYER_DOMINATING_ME_045: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "045");
+        const PLAYER_DOMINATING_ME_046: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "046");
+        const PLAYER_DOMINATING_ME_047: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "047");
+        const PLAYER_DOMINATING_ME_048: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "048");
+        const PLAYER_DOMINATING_ME_049: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "049");
+        const PLAYER_DOMINATING_ME_050: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "050");
+        const PLAYER_DOMINATING_ME_051: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "051");
+        const PLAYER_DOMINATING_ME_052: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "052");
+        const PLAYER_DOMINATING_ME_053: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "053");
+        const PLAYER_DOMINATING_ME_054: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "054");
+        const PLAYER_DOMINATING_ME_055: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "055");
+        const PLAYER_DOMINATING_ME_056: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "056");
+        const PLAYER_DOMINATING_ME_057: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "057");
+        const PLAYER_DOMINATING_ME_058: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "058");
+        const PLAYER_DOMINATING_ME_059: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "059");
+        const PLAYER_DOMINATING_ME_060: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "060");
+        const PLAYER_DOMINATING_ME_061: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "061");
+        const PLAYER_DOMINATING_ME_062: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "062");
+        const PLAYER_DOMINATING_ME_063: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "063");
+        const PLAYER_DOMINATING_ME_064: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "064");
+        const PLAYER_DOMINATING_ME_065: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "065");
+        const PLAYER_DOMINATING_ME_066: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "066");
+        const PLAYER_DOMINATING_ME_067: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "067");
+        const PLAYER_DOMINATING_ME_068: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "068");
+        const PLAYER_DOMINATING_ME_069: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "069");
+        const PLAYER_DOMINATING_ME_070: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "070");
+        const PLAYER_DOMINATING_ME_071: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "071");
+        const PLAYER_DOMINATING_ME_072: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "072");
+        const PLAYER_DOMINATING_ME_073: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "073");
+        const PLAYER_DOMINATING_ME_074: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "074");
+        const PLAYER_DOMINATING_ME_075: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "075");
+        const PLAYER_DOMINATING_ME_076: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "076");
+        const PLAYER_DOMINATING_ME_077: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "077");
+        const PLAYER_DOMINATING_ME_078: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "078");
+        const PLAYER_DOMINATING_ME_079: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "079");
+        const PLAYER_DOMINATING_ME_080: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "080");
+        const PLAYER_DOMINATING_ME_081: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "081");
+        const PLAYER_DOMINATING_ME_082: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "082");
+        const PLAYER_DOMINATING_ME_083: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "083");
+        const PLAYER_DOMINATING_ME_084: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "084");
+        const PLAYER_DOMINATING_ME_085: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "085");
+        const PLAYER_DOMINATING_ME_086: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "086");
+        const PLAYER_DOMINATING_ME_087: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "087");
+        const PLAYER_DOMINATING_ME_088: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "088");
+        const PLAYER_DOMINATING_ME_089: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "089");
+        const PLAYER_DOMINATING_ME_090: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "090");
+        const PLAYER_DOMINATING_ME_091: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "091");
+        const PLAYER_DOMINATING_ME_092: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "092");
+        const PLAYER_DOMINATING_ME_093: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "093");
+        const PLAYER_DOMINATING_ME_094: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "094");
+        const PLAYER_DOMINATING_ME_095: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "095");
+        const PLAYER_DOMINATING_ME_096: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "096");
+        const PLAYER_DOMINATING_ME_097: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "097");
+        const PLAYER_DOMINATING_ME_098: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "098");
+        const PLAYER_DOMINATING_ME_099: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "099");
+        const PLAYER_DOMINATING_ME_100: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "100");
+        const PLAYER_DOMINATING_ME_101: SendPropIdentifier =
+            SendPropIdentifier::new("m_bPlayerDominatingMe", "101");
+        const STREAKS_000: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "000");
+        const STREAKS_001: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "001");
+        const STREAKS_002: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "002");
+        const STREAKS_003: SendPropIdentifier = SendPropIdentifier::new("m_nStreaks", "003");
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const DUCKTIME: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flDucktime");
+        const FALL_VELOCITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_flFallVelocity");
+        const PUNCH_ANGLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngle");
+        const VIEW_OFFSET_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[2]");
+        const TICK_BASE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nTickBase");
+        const GROUND_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hGroundEntity");
+        const VELOCITY_0: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[0]");
+        const VELOCITY_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[1]");
+        const VELOCITY_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecVelocity[2]");
+        const TF_LOCAL_PLAYER_EXCLUSIVE_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin");
+        const TF_LOCAL_PLAYER_EXCLUSIVE_ORIGIN_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_vecOrigin[2]");
+        const TF_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_0: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[0]");
+        const TF_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_angEyeAngles[1]");
+        const TF_NON_LOCAL_PLAYER_EXCLUSIVE_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin");
+        const TF_NON_LOCAL_PLAYER_EXCLUSIVE_ORIGIN_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin[2]");
+        const TF_NON_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_0: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[0]");
+        const TF_NON_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_angEyeAngles[1]");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const HEALTH: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+        const FLAGS: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_fFlags");
+        const NEXT_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BCCLocalPlayerExclusive", "m_flNextAttack");
+        const ATTRIBUTES_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_Attributes_20", "lengthprop20");
+        const ATTRIBUTE_DEFINITION_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        const RAW_VALUE_32: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        const REFUNDABLE_CURRENCY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        const DEADFLAG: SendPropIdentifier = SendPropIdentifier::new("DT_PlayerState", "deadflag");
+        const MY_WEARABLES_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_hMyWearables_8", "lengthprop8");
+        const HIDE_HUD: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_iHideHUD");
+        const FOV_RATE: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flFOVRate");
+        const DUCKED: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bDucked");
+        const DUCKING: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bDucking");
+        const IN_DUCK_JUMP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bInDuckJump");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const DUCK_JUMP_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_flDuckJumpTime");
+        const JUMP_TIME: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flJumpTime");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const PUNCH_ANGLE_VEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_vecPunchAngleVel");
+        const DRAW_VIEWMODEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bDrawViewmodel");
+        const WEARING_SUIT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bWearingSuit");
+        const POISONED: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_bPoisoned");
+        const FORCE_LOCAL_PLAYER_DRAW: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bForceLocalPlayerDraw");
+        const STEP_SIZE: SendPropIdentifier = SendPropIdentifier::new("DT_Local", "m_flStepSize");
+        const ALLOW_AUTO_MOVEMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_bAllowAutoMovement");
+        const SKYBOX_3D_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.scale");
+        const SKYBOX_3D_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.origin");
+        const SKYBOX_3D_AREA: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.area");
+        const SKYBOX_3D_FOG_ENABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.enable");
+        const SKYBOX_3D_FOG_BLEND: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.blend");
+        const SKYBOX_3D_FOG_DIR_PRIMARY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.dirPrimary");
+        const SKYBOX_3D_FOG_COLOR_PRIMARY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorPrimary");
+        const SKYBOX_3D_FOG_COLOR_SECONDARY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.colorSecondary");
+        const SKYBOX_3D_FOG_START: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.start");
+        const SKYBOX_3D_FOG_END: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.end");
+        const SKYBOX_3D_FOG_MAXDENSITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_skybox3d.fog.maxdensity");
+        const PLAYER_FOG_CTRL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_PlayerFog.m_hCtrl");
+        const AUDIO_LOCAL_SOUND_0: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[0]");
+        const AUDIO_LOCAL_SOUND_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[1]");
+        const AUDIO_LOCAL_SOUND_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[2]");
+        const AUDIO_LOCAL_SOUND_3: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[3]");
+        const AUDIO_LOCAL_SOUND_4: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[4]");
+        const AUDIO_LOCAL_SOUND_5: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[5]");
+        const AUDIO_LOCAL_SOUND_6: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[6]");
+        const AUDIO_LOCAL_SOUND_7: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localSound[7]");
+        const AUDIO_SOUNDSCAPE_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.soundscapeIndex");
+        const AUDIO_LOCAL_BITS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.localBits");
+        const AUDIO_ENT_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_audio.entIndex");
+        const SCRIPT_OVERLAY_MATERIAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Local", "m_szScriptOverlayMaterial");
+        const VIEW_OFFSET_0: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[0]");
+        const VIEW_OFFSET_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecViewOffset[1]");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const FRICTION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flFriction");
+        const ON_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_fOnTarget");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const NEXT_THINK_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nNextThinkTick");
+        const LAST_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hLastWeapon");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const BASE_VELOCITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecBaseVelocity");
+        const CONSTRAINT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_hConstraintEntity");
+        const CONSTRAINT_CENTER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_vecConstraintCenter");
+        const CONSTRAINT_RADIUS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintRadius");
+        const CONSTRAINT_WIDTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintWidth");
+        const CONSTRAINT_SPEED_FACTOR: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flConstraintSpeedFactor");
+        const DEATH_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flDeathTime");
+        const LOCAL_PLAYER_EXCLUSIVE_WATER_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nWaterLevel");
+        const LAGGED_MOVEMENT_VALUE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_flLaggedMovementValue");
+        const CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClass");
+        const CLASS_ICON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszClassIcon");
+        const CUSTOM_MODEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iszCustomModel");
+        const CUSTOM_MODEL_OFFSET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_vecCustomModelOffset");
+        const CUSTOM_MODEL_ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_angCustomModelRotation");
+        const CUSTOM_MODEL_ROTATES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotates");
+        const CUSTOM_MODEL_ROTATION_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelRotationSet");
+        const CUSTOM_MODEL_VISIBLE_TO_SELF: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bCustomModelVisibleToSelf");
+        const USE_CLASS_ANIMATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_bUseClassAnimations");
+        const CLASS_MODEL_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerClassShared", "m_iClassModelParity");
+        const SCORE_DATA_CAPTURES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCaptures");
+        const SCORE_DATA_DEFENSES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDefenses");
+        const SCORE_DATA_KILLS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKills");
+        const SCORE_DATA_DEATHS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDeaths");
+        const SCORE_DATA_SUICIDES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iSuicides");
+        const SCORE_DATA_DOMINATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDominations");
+        const SCORE_DATA_REVENGE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iRevenge");
+        const SCORE_DATA_BUILDINGS_BUILT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsBuilt");
+        const SCORE_DATA_BUILDINGS_DESTROYED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBuildingsDestroyed");
+        const SCORE_DATA_HEADSHOTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHeadshots");
+        const SCORE_DATA_BACKSTABS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBackstabs");
+        const SCORE_DATA_HEAL_POINTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iHealPoints");
+        const SCORE_DATA_INVULNS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iInvulns");
+        const SCORE_DATA_TELEPORTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iTeleports");
+        const SCORE_DATA_DAMAGE_DONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iDamageDone");
+        const SCORE_DATA_CRITS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iCrits");
+        const SCORE_DATA_RESUPPLY_POINTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iResupplyPoints");
+        const SCORE_DATA_KILL_ASSISTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iKillAssists");
+        const SCORE_DATA_BONUS_POINTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iBonusPoints");
+        const SCORE_DATA_POINTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerScoringDataExclusive", "m_iPoints");
+        const DESIRED_DISGUISE_TEAM: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseTeam");
+        const DESIRED_DISGUISE_CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_nDesiredDisguiseClass");
+        const LAST_DISGUISED_AS_OWN_TEAM: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bLastDisguisedAsOwnTeam");
+        const STEALTH_NO_ATTACK_EXPIRE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNoAttackExpire");
+        const STEALTH_NEXT_CHANGE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flStealthNextChangeTime");
+        const RAGE_METER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flRageMeter");
+        const RAGE_DRAINING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bRageDraining");
+        const NEXT_RAGE_EARN_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_flNextRageEarnTime");
+        const IN_UPGRADE_ZONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerSharedLocal", "m_bInUpgradeZone");
+        const CONDITION_BITS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionListExclusive", "_condition_bits");
+        const CONDITION_DATA_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_ConditionData_131", "lengthprop131");
+        const P_PROVIDER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerConditionSource", "m_pProvider");
+        const PLAYER_COND: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCond");
+        const JUMPING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bJumping");
+        const NUM_HEALERS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nNumHealers");
+        const CRIT_MULT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iCritMult");
+        const AIR_DASH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iAirDash");
+        const AIR_DUCKED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nAirDucked");
+        const DUCK_TIMER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flDuckTimer");
+        const PLAYER_STATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerState");
+        const DESIRED_PLAYER_CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDesiredPlayerClass");
+        const MOVEMENT_STUN_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flMovementStunTime");
+        const MOVEMENT_STUN_AMOUNT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunAmount");
+        const MOVEMENT_STUN_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iMovementStunParity");
+        const STUNNER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hStunner");
+        const STUN_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunFlags");
+        const ARENA_NUM_CHANGES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nArenaNumChanges");
+        const ARENA_FIRST_BLOOD_BOOST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bArenaFirstBloodBoost");
+        const WEAPON_KNOCKBACK_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iWeaponKnockbackID");
+        const LOADOUT_UNAVAILABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bLoadoutUnavailable");
+        const ITEM_FIND_BONUS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iItemFindBonus");
+        const SHIELD_EQUIPPED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bShieldEquipped");
+        const PARACHUTE_EQUIPPED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bParachuteEquipped");
+        const NEXT_MELEE_CRIT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iNextMeleeCrit");
+        const DECAPITATIONS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDecapitations");
+        const REVENGE_CRITS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iRevengeCrits");
+        const DISGUISE_BODY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseBody");
+        const CARRIED_OBJECT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hCarriedObject");
+        const CARRYING_OBJECT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bCarryingObject");
+        const NEXT_NOISE_MAKER_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flNextNoiseMakerTime");
+        const SPAWN_ROOM_TOUCH_COUNT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iSpawnRoomTouchCount");
+        const KILL_COUNT_SINCE_LAST_DEPLOY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iKillCountSinceLastDeploy");
+        const FIRST_PRIMARY_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flFirstPrimaryAttack");
+        const ENERGY_DRINK_METER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flEnergyDrinkMeter");
+        const HYPE_METER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flHypeMeter");
+        const CHARGE_METER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flChargeMeter");
+        const INVIS_CHANGE_COMPLETE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flInvisChangeCompleteTime");
+        const DISGUISE_TEAM: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseTeam");
+        const DISGUISE_CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseClass");
+        const DISGUISE_SKIN_OVERRIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nDisguiseSkinOverride");
+        const MASK_CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nMaskClass");
+        const DISGUISE_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hDisguiseTarget");
+        const DISGUISE_HEALTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iDisguiseHealth");
+        const FEIGN_DEATH_READY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bFeignDeathReady");
+        const DISGUISE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hDisguiseWeapon");
+        const TEAM_TELEPORTER_USED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nTeamTeleporterUsed");
+        const CLOAK_METER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flCloakMeter");
+        const SPY_TRANQ_BUFF_DURATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flSpyTranqBuffDuration");
+        const TAUNT_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntIndex");
+        const TAUNT_CONCEPT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iTauntConcept");
+        const PLAYER_COND_EX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx");
+        const STUN_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_iStunIndex");
+        const HALLOWEEN_BOMB_HEAD_STAGE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nHalloweenBombHeadStage");
+        const PLAYER_COND_EX_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx2");
+        const PLAYER_COND_EX_3: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx3");
+        const TAUNT_SOURCE_ITEM_ID_LOW: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_Low");
+        const TAUNT_SOURCE_ITEM_ID_HIGH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_unTauntSourceItemID_High");
+        const RUNE_CHARGE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flRuneCharge");
+        const HAS_PASSTIME_BALL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bHasPasstimeBall");
+        const IS_TARGETED_FOR_PASSTIME_PASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bIsTargetedForPasstimePass");
+        const PASSTIME_PASS_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hPasstimePassTarget");
+        const ASK_FOR_BALL_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_askForBallTime");
+        const KING_RUNE_BUFF_ACTIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_bKingRuneBuffActive");
+        const PLAYER_COND_EX_4: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_nPlayerCondEx4");
+        const HOLSTER_ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_flHolsterAnimTime");
+        const SWITCH_TO: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayerShared", "m_hSwitchTo");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const PLAYER_OBJECT_ARRAY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "\"player_object_array\"");
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const IS_COACHING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_bIsCoaching");
+        const COACH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hCoach");
+        const STUDENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_hStudent");
+        const CURRENCY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nCurrency");
+        const EXPERIENCE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevel");
+        const EXPERIENCE_LEVEL_PROGRESS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_nExperienceLevelProgress");
+        const MATCH_SAFE_TO_LEAVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFLocalPlayerExclusive", "m_bMatchSafeToLeave");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const OUTER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_hOuter");
+        const PROVIDER_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_ProviderType");
+        const REAPPLY_PROVISION_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeManager", "m_iReapplyProvisionParity");
+        const ACTIVE_WPN_CLIP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFSendHealersDataTable", "m_nActiveWpnClip");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const GLOW_ENABLED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_bGlowEnabled");
+        const ACTIVE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+        const VEHICLE: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_hVehicle");
+        const USE_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hUseEntity");
+        const LIFE_STATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_lifeState");
+        const BONUS_PROGRESS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusProgress");
+        const BONUS_CHALLENGE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iBonusChallenge");
+        const MAXSPEED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_flMaxspeed");
+        const OBSERVER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iObserverMode");
+        const OBSERVER_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hObserverTarget");
+        const FOV: SendPropIdentifier = SendPropIdentifier::new("DT_BasePlayer", "m_iFOV");
+        const FOV_START: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iFOVStart");
+        const FOV_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_flFOVTime");
+        const DEFAULT_FOV: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_iDefaultFOV");
+        const ZOOM_OWNER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hZoomOwner");
+        const VIEW_MODEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_hViewModel");
+        const LAST_PLACE_NAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BasePlayer", "m_szLastPlaceName");
+        const SAVE_ME_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bSaveMeParity");
+        const IS_MINI_BOSS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsMiniBoss");
+        const IS_A_BOT: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_bIsABot");
+        const BOT_SKILL: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_nBotSkill");
+        const TF_PLAYER_WATER_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nWaterLevel");
+        const RAGDOLL: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_hRagdoll");
+        const ITEM: SendPropIdentifier = SendPropIdentifier::new("DT_TFPlayer", "m_hItem");
+        const ALLOW_MOVE_DURING_TAUNT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bAllowMoveDuringTaunt");
+        const IS_READY_TO_HIGH_FIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bIsReadyToHighFive");
+        const HIGH_FIVE_PARTNER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hHighFivePartner");
+        const FORCE_TAUNT_CAM: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForceTauntCam");
+        const TAUNT_YAW: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTauntYaw");
+        const ACTIVE_TAUNT_SLOT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nActiveTauntSlot");
+        const TAUNT_ITEM_DEF_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iTauntItemDefIndex");
+        const CURRENT_TAUNT_MOVE_SPEED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flCurrentTauntMoveSpeed");
+        const VEHICLE_REVERSE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flVehicleReverseTime");
+        const MV_M_LAST_DAMAGE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flMvMLastDamageTime");
+        const IN_POWER_PLAY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bInPowerPlay");
+        const SPAWN_COUNTER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iSpawnCounter");
+        const ARENA_SPECTATOR: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bArenaSpectator");
+        const HEAD_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHeadScale");
+        const TORSO_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flTorsoScale");
+        const HAND_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHandScale");
+        const USE_BOSS_HEALTH_BAR: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUseBossHealthBar");
+        const USING_VR_HEADSET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingVRHeadset");
+        const TF_PLAYER_FORCED_SKIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bForcedSkin");
+        const TF_PLAYER_FORCED_SKIN_: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_nForcedSkin");
+        const KART_NEXT_AVAILABLE_BOOST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flKartNextAvailableBoost");
+        const KART_HEALTH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartHealth");
+        const KART_STATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iKartState");
+        const GRAPPLING_HOOK_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hGrapplingHookTarget");
+        const SECONDARY_LAST_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_hSecondaryLastWeapon");
+        const USING_ACTION_SLOT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bUsingActionSlot");
+        const INSPECT_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flInspectTime");
+        const HELPME_BUTTON_PRESS_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_flHelpmeButtonPressTime");
+        const CAMPAIGN_MEDALS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iCampaignMedals");
+        const PLAYER_SKIN_OVERRIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_iPlayerSkinOverride");
+        const VIEWING_CYOAPDA: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bViewingCYOAPDA");
+        const REGENERATING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFPlayer", "m_bRegenerating");
+        match prop.identifier {
+            DUCKTIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.ducktime = value;
+                }
+            }
+            FALL_VELOCITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fall_velocity = value;
+                }
+            }
+            PUNCH_ANGLE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.punch_angle = value;
+                }
+            }
+            VIEW_OFFSET_2 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.view_offset_2 = value;
+                }
+            }
+            TICK_BASE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.tick_base = value;
+                }
+            }
+            GROUND_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.ground_entity = value;
+                }
+            }
+            VELOCITY_0 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.velocity_0 = value;
+                }
+            }
+            VELOCITY_1 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.velocity_1 = value;
+                }
+            }
+            VELOCITY_2 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.velocity_2 = value;
+                }
+            }
+            TF_LOCAL_PLAYER_EXCLUSIVE_ORIGIN => {
+                let value: Option<VectorXY> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_local_player_exclusive_origin = value;
+                }
+            }
+            TF_LOCAL_PLAYER_EXCLUSIVE_ORIGIN_2 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_local_player_exclusive_origin_2 = value;
+                }
+            }
+            TF_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_0 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_local_player_exclusive_eye_angles_0 = value;
+                }
+            }
+            TF_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_1 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_local_player_exclusive_eye_angles_1 = value;
+                }
+            }
+            TF_NON_LOCAL_PLAYER_EXCLUSIVE_ORIGIN => {
+                let value: Option<VectorXY> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_non_local_player_exclusive_origin = value;
+                }
+            }
+            TF_NON_LOCAL_PLAYER_EXCLUSIVE_ORIGIN_2 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_non_local_player_exclusive_origin_2 = value;
+                }
+            }
+            TF_NON_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_0 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_non_local_player_exclusive_eye_angles_0 = value;
+                }
+            }
+            TF_NON_LOCAL_PLAYER_EXCLUSIVE_EYE_ANGLES_1 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_non_local_player_exclusive_eye_angles_1 = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.health = value;
+                }
+            }
+            FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.flags = value;
+                }
+            }
+            NEXT_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_attack = value;
+                }
+            }
+            ATTRIBUTES_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.attributes_len = value;
+                }
+            }
+            ATTRIBUTE_DEFINITION_INDEX => {
+                if let Some(PropLocation {
+                    path: 3166890018434471022,
+                    element,
+                }) = location
+                {
+                    let field = element.and_then(|element| {
+                        self.attribute_definition_index
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            RAW_VALUE_32 => {
+                if let Some(PropLocation {
+                    path: 3166890018434471022,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.raw_value_32.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            REFUNDABLE_CURRENCY => {
+                if let Some(PropLocation {
+                    path: 3166890018434471022,
+                    element,
+                }) = location
+                {
+                    let field = element
+                        .and_then(|element| self.refundable_currency.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            DEADFLAG => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.deadflag = value;
+                }
+            }
+            MY_WEARABLES_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.my_wearables_len = value;
+                }
+            }
+            HIDE_HUD => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hide_hud = value;
+                }
+            }
+            FOV_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fov_rate = value;
+                }
+            }
+            DUCKED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.ducked = value;
+                }
+            }
+            DUCKING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.ducking = value;
+                }
+            }
+            IN_DUCK_JUMP => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.in_duck_jump = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            DUCK_JUMP_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.duck_jump_time = value;
+                }
+            }
+            JUMP_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.jump_time = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            PUNCH_ANGLE_VEL => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.punch_angle_vel = value;
+                }
+            }
+            DRAW_VIEWMODEL => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.draw_viewmodel = value;
+                }
+            }
+            WEARING_SUIT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.wearing_suit = value;
+                }
+            }
+            POISONED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.poisoned = value;
+                }
+            }
+            FORCE_LOCAL_PLAYER_DRAW => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.force_local_player_draw = value;
+                }
+            }
+            STEP_SIZE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.step_size = value;
+                }
+            }
+            ALLOW_AUTO_MOVEMENT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.allow_auto_movement = value;
+                }
+            }
+            SKYBOX_3D_SCALE => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skybox_3d_scale = value;
+                }
+            }
+            SKYBOX_3D_ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.skybox_3d_origin = value;
+                }
+            }
+            SKYBOX_3D_AREA => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skybox_3d_area = value;
+                }
+            }
+            SKYBOX_3D_FOG_ENABLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.skybox_3d_fog_enable = value;
+                }
+            }
+            SKYBOX_3D_FOG_BLEND => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.skybox_3d_fog_blend = value;
+                }
+            }
+            SKYBOX_3D_FOG_DIR_PRIMARY => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.skybox_3d_fog_dir_primary = value;
+                }
+            }
+            SKYBOX_3D_FOG_COLOR_PRIMARY => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skybox_3d_fog_color_primary = value;
+                }
+            }
+            SKYBOX_3D_FOG_COLOR_SECONDARY => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skybox_3d_fog_color_secondary = value;
+                }
+            }
+            SKYBOX_3D_FOG_START => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.skybox_3d_fog_start = value;
+                }
+            }
+            SKYBOX_3D_FOG_END => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.skybox_3d_fog_end = value;
+                }
+            }
+            SKYBOX_3D_FOG_MAXDENSITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.skybox_3d_fog_maxdensity = value;
+                }
+            }
+            PLAYER_FOG_CTRL => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.player_fog_ctrl = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_0 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_0 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_1 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_1 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_2 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_2 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_3 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_3 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_4 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_4 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_5 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_5 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_6 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_6 = value;
+                }
+            }
+            AUDIO_LOCAL_SOUND_7 => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.audio_local_sound_7 = value;
+                }
+            }
+            AUDIO_SOUNDSCAPE_INDEX => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.audio_soundscape_index = value;
+                }
+            }
+            AUDIO_LOCAL_BITS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.audio_local_bits = value;
+                }
+            }
+            AUDIO_ENT_INDEX => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.audio_ent_index = value;
+                }
+            }
+            SCRIPT_OVERLAY_MATERIAL => {
+                let value: Option<String> = <&str>::try_from(&prop.value).ok().map(String::from);
+                if let Some(value) = value {
+                    self.script_overlay_material = value;
+                }
+            }
+            VIEW_OFFSET_0 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.view_offset_0 = value;
+                }
+            }
+            VIEW_OFFSET_1 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.view_offset_1 = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            FRICTION => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.friction = value;
+                }
+            }
+            ON_TARGET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.on_target = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            NEXT_THINK_TICK => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.next_think_tick = value;
+                }
+            }
+            LAST_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.last_weapon = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            BASE_VELOCITY => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.base_velocity = value;
+                }
+            }
+            CONSTRAINT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.constraint_entity = value;
+                }
+            }
+            CONSTRAINT_CENTER => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.constraint_center = value;
+                }
+            }
+            CONSTRAINT_RADIUS => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.constraint_radius = value;
+                }
+            }
+            CONSTRAINT_WIDTH => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.constraint_width = value;
+                }
+            }
+            CONSTRAINT_SPEED_FACTOR => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.constraint_speed_factor = value;
+                }
+            }
+            DEATH_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.death_time = value;
+                }
+            }
+            LOCAL_PLAYER_EXCLUSIVE_WATER_LEVEL => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.local_player_exclusive_water_level = value;
+                }
+            }
+            LAGGED_MOVEMENT_VALUE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.lagged_movement_value = value;
+                }
+            }
+            CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.class = value;
+                }
+            }
+            CLASS_ICON => {
+                let value: Option<String> = <&str>::try_from(&prop.value).ok().map(String::from);
+                if let Some(value) = value {
+                    self.class_icon = value;
+                }
+            }
+            CUSTOM_MODEL => {
+                let value: Option<String> = <&str>::try_from(&prop.value).ok().map(String::from);
+                if let Some(value) = value {
+                    self.custom_model = value;
+                }
+            }
+            CUSTOM_MODEL_OFFSET => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.custom_model_offset = value;
+                }
+            }
+            CUSTOM_MODEL_ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.custom_model_rotation = value;
+                }
+            }
+            CUSTOM_MODEL_ROTATES => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.custom_model_rotates = value;
+                }
+            }
+            CUSTOM_MODEL_ROTATION_SET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.custom_model_rotation_set = value;
+                }
+            }
+            CUSTOM_MODEL_VISIBLE_TO_SELF => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.custom_model_visible_to_self = value;
+                }
+            }
+            USE_CLASS_ANIMATIONS => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.use_class_animations = value;
+                }
+            }
+            CLASS_MODEL_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.class_model_parity = value;
+                }
+            }
+            SCORE_DATA_CAPTURES => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_captures = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_captures = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_DEFENSES => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_defenses = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_defenses = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_KILLS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_kills = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_kills = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_DEATHS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_deaths = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_deaths = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_SUICIDES => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_suicides = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_suicides = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_DOMINATIONS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_dominations = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_dominations = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_REVENGE => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_revenge = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_revenge = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_BUILDINGS_BUILT => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_buildings_built = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_buildings_built = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_BUILDINGS_DESTROYED => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_buildings_destroyed = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_buildings_destroyed = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_HEADSHOTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_headshots = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_headshots = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_BACKSTABS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_backstabs = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_backstabs = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_HEAL_POINTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u32> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u32::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_heal_points = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u32> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u32::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_heal_points = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_INVULNS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_invulns = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_invulns = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_TELEPORTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_teleports = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_teleports = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_DAMAGE_DONE => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u32> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u32::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_damage_done = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u32> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u32::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_damage_done = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_CRITS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_crits = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_crits = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_RESUPPLY_POINTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_resupply_points = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_resupply_points = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_KILL_ASSISTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_kill_assists = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_kill_assists = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_BONUS_POINTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_bonus_points = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_bonus_points = value;
+                    }
+                }
+                _ => {}
+            },
+            SCORE_DATA_POINTS => match location {
+                Some(PropLocation {
+                    path: 14236147406971506424,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.score_data_points = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 1181457319175277642,
+                    ..
+                }) => {
+                    let value: Option<u16> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u16::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.round_score_data_points = value;
+                    }
+                }
+                _ => {}
+            },
+            DESIRED_DISGUISE_TEAM => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_disguise_team = value;
+                }
+            }
+            DESIRED_DISGUISE_CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_disguise_class = value;
+                }
+            }
+            LAST_DISGUISED_AS_OWN_TEAM => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.last_disguised_as_own_team = value;
+                }
+            }
+            STEALTH_NO_ATTACK_EXPIRE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.stealth_no_attack_expire = value;
+                }
+            }
+            STEALTH_NEXT_CHANGE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.stealth_next_change_time = value;
+                }
+            }
+            RAGE_METER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rage_meter = value;
+                }
+            }
+            RAGE_DRAINING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.rage_draining = value;
+                }
+            }
+            NEXT_RAGE_EARN_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_rage_earn_time = value;
+                }
+            }
+            IN_UPGRADE_ZONE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.in_upgrade_zone = value;
+                }
+            }
+            CONDITION_BITS => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.condition_bits = value;
+                }
+            }
+            CONDITION_DATA_LEN => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.condition_data_len = value;
+                }
+            }
+            P_PROVIDER => {
+                if let Some(PropLocation {
+                    path: 16862025985285259243,
+                    element,
+                }) = location
+                {
+                    let field =
+                        element.and_then(|element| self.p_provider.get_mut(usize::from(element)));
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+            }
+            PLAYER_COND => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_cond = value;
+                }
+            }
+            JUMPING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.jumping = value;
+                }
+            }
+            NUM_HEALERS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.num_healers = value;
+                }
+            }
+            CRIT_MULT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.crit_mult = value;
+                }
+            }
+            AIR_DASH => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.air_dash = value;
+                }
+            }
+            AIR_DUCKED => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.air_ducked = value;
+                }
+            }
+            DUCK_TIMER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.duck_timer = value;
+                }
+            }
+            PLAYER_STATE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_state = value;
+                }
+            }
+            DESIRED_PLAYER_CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.desired_player_class = value;
+                }
+            }
+            MOVEMENT_STUN_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.movement_stun_time = value;
+                }
+            }
+            MOVEMENT_STUN_AMOUNT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movement_stun_amount = value;
+                }
+            }
+            MOVEMENT_STUN_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movement_stun_parity = value;
+                }
+            }
+            STUNNER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.stunner = value;
+                }
+            }
+            STUN_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.stun_flags = value;
+                }
+            }
+            ARENA_NUM_CHANGES => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.arena_num_changes = value;
+                }
+            }
+            ARENA_FIRST_BLOOD_BOOST => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.arena_first_blood_boost = value;
+                }
+            }
+            WEAPON_KNOCKBACK_ID => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.weapon_knockback_id = value;
+                }
+            }
+            LOADOUT_UNAVAILABLE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.loadout_unavailable = value;
+                }
+            }
+            ITEM_FIND_BONUS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.item_find_bonus = value;
+                }
+            }
+            SHIELD_EQUIPPED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.shield_equipped = value;
+                }
+            }
+            PARACHUTE_EQUIPPED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.parachute_equipped = value;
+                }
+            }
+            NEXT_MELEE_CRIT => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.next_melee_crit = value;
+                }
+            }
+            DECAPITATIONS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.decapitations = value;
+                }
+            }
+            REVENGE_CRITS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.revenge_crits = value;
+                }
+            }
+            DISGUISE_BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.disguise_body = value;
+                }
+            }
+            CARRIED_OBJECT => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.carried_object = value;
+                }
+            }
+            CARRYING_OBJECT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.carrying_object = value;
+                }
+            }
+            NEXT_NOISE_MAKER_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_noise_maker_time = value;
+                }
+            }
+            SPAWN_ROOM_TOUCH_COUNT => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.spawn_room_touch_count = value;
+                }
+            }
+            KILL_COUNT_SINCE_LAST_DEPLOY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kill_count_since_last_deploy = value;
+                }
+            }
+            FIRST_PRIMARY_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.first_primary_attack = value;
+                }
+            }
+            ENERGY_DRINK_METER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.energy_drink_meter = value;
+                }
+            }
+            HYPE_METER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.hype_meter = value;
+                }
+            }
+            CHARGE_METER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.charge_meter = value;
+                }
+            }
+            INVIS_CHANGE_COMPLETE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.invis_change_complete_time = value;
+                }
+            }
+            DISGUISE_TEAM => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.disguise_team = value;
+                }
+            }
+            DISGUISE_CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.disguise_class = value;
+                }
+            }
+            DISGUISE_SKIN_OVERRIDE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disguise_skin_override = value;
+                }
+            }
+            MASK_CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.mask_class = value;
+                }
+            }
+            DISGUISE_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.disguise_target = value;
+                }
+            }
+            DISGUISE_HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.disguise_health = value;
+                }
+            }
+            FEIGN_DEATH_READY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.feign_death_ready = value;
+                }
+            }
+            DISGUISE_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.disguise_weapon = value;
+                }
+            }
+            TEAM_TELEPORTER_USED => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_teleporter_used = value;
+                }
+            }
+            CLOAK_METER => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.cloak_meter = value;
+                }
+            }
+            SPY_TRANQ_BUFF_DURATION => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.spy_tranq_buff_duration = value;
+                }
+            }
+            TAUNT_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.taunt_index = value;
+                }
+            }
+            TAUNT_CONCEPT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.taunt_concept = value;
+                }
+            }
+            PLAYER_COND_EX => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_cond_ex = value;
+                }
+            }
+            STUN_INDEX => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.stun_index = value;
+                }
+            }
+            HALLOWEEN_BOMB_HEAD_STAGE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.halloween_bomb_head_stage = value;
+                }
+            }
+            PLAYER_COND_EX_2 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_cond_ex_2 = value;
+                }
+            }
+            PLAYER_COND_EX_3 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_cond_ex_3 = value;
+                }
+            }
+            TAUNT_SOURCE_ITEM_ID_LOW => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.taunt_source_item_id_low = value;
+                }
+            }
+            TAUNT_SOURCE_ITEM_ID_HIGH => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.taunt_source_item_id_high = value;
+                }
+            }
+            RUNE_CHARGE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rune_charge = value;
+                }
+            }
+            HAS_PASSTIME_BALL => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.has_passtime_ball = value;
+                }
+            }
+            IS_TARGETED_FOR_PASSTIME_PASS => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_targeted_for_passtime_pass = value;
+                }
+            }
+            PASSTIME_PASS_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.passtime_pass_target = value;
+                }
+            }
+            ASK_FOR_BALL_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.ask_for_ball_time = value;
+                }
+            }
+            KING_RUNE_BUFF_ACTIVE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.king_rune_buff_active = value;
+                }
+            }
+            PLAYER_COND_EX_4 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_cond_ex_4 = value;
+                }
+            }
+            HOLSTER_ANIM_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.holster_anim_time = value;
+                }
+            }
+            SWITCH_TO => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.switch_to = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            PLAYER_OBJECT_ARRAY => {
+                let value: Option<Vec<SendPropValue>> = <&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec);
+                if let Some(value) = value {
+                    self.player_object_array = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            IS_COACHING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_coaching = value;
+                }
+            }
+            COACH => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.coach = value;
+                }
+            }
+            STUDENT => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.student = value;
+                }
+            }
+            CURRENCY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.currency = value;
+                }
+            }
+            EXPERIENCE_LEVEL => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.experience_level = value;
+                }
+            }
+            EXPERIENCE_LEVEL_PROGRESS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.experience_level_progress = value;
+                }
+            }
+            MATCH_SAFE_TO_LEAVE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.match_safe_to_leave = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            OUTER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.outer = value;
+                }
+            }
+            PROVIDER_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.provider_type = value;
+                }
+            }
+            REAPPLY_PROVISION_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reapply_provision_parity = value;
+                }
+            }
+            ACTIVE_WPN_CLIP => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.active_wpn_clip = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            GLOW_ENABLED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.glow_enabled = value;
+                }
+            }
+            ACTIVE_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.active_weapon = value;
+                }
+            }
+            VEHICLE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.vehicle = value;
+                }
+            }
+            USE_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.use_entity = value;
+                }
+            }
+            LIFE_STATE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.life_state = value;
+                }
+            }
+            BONUS_PROGRESS => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.bonus_progress = value;
+                }
+            }
+            BONUS_CHALLENGE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.bonus_challenge = value;
+                }
+            }
+            MAXSPEED => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxspeed = value;
+                }
+            }
+            OBSERVER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.observer_mode = value;
+                }
+            }
+            OBSERVER_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.observer_target = value;
+                }
+            }
+            FOV => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.fov = value;
+                }
+            }
+            FOV_START => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.fov_start = value;
+                }
+            }
+            FOV_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fov_time = value;
+                }
+            }
+            DEFAULT_FOV => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.default_fov = value;
+                }
+            }
+            ZOOM_OWNER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.zoom_owner = value;
+                }
+            }
+            VIEW_MODEL => {
+                let value: Option<Vec<SendPropValue>> = <&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec);
+                if let Some(value) = value {
+                    self.view_model = value;
+                }
+            }
+            LAST_PLACE_NAME => {
+                let value: Option<String> = <&str>::try_from(&prop.value).ok().map(String::from);
+                if let Some(value) = value {
+                    self.last_place_name = value;
+                }
+            }
+            SAVE_ME_PARITY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.save_me_parity = value;
+                }
+            }
+            IS_MINI_BOSS => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_mini_boss = value;
+                }
+            }
+            IS_A_BOT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_a_bot = value;
+                }
+            }
+            BOT_SKILL => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.bot_skill = value;
+                }
+            }
+            TF_PLAYER_WATER_LEVEL => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.tf_player_water_level = value;
+                }
+            }
+            RAGDOLL => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.ragdoll = value;
+                }
+            }
+            ITEM => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.item = value;
+                }
+            }
+            ALLOW_MOVE_DURING_TAUNT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.allow_move_during_taunt = value;
+                }
+            }
+            IS_READY_TO_HIGH_FIVE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_ready_to_high_five = value;
+                }
+            }
+            HIGH_FIVE_PARTNER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.high_five_partner = value;
+                }
+            }
+            FORCE_TAUNT_CAM => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_taunt_cam = value;
+                }
+            }
+            TAUNT_YAW => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.taunt_yaw = value;
+                }
+            }
+            ACTIVE_TAUNT_SLOT => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.active_taunt_slot = value;
+                }
+            }
+            TAUNT_ITEM_DEF_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.taunt_item_def_index = value;
+                }
+            }
+            CURRENT_TAUNT_MOVE_SPEED => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.current_taunt_move_speed = value;
+                }
+            }
+            VEHICLE_REVERSE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.vehicle_reverse_time = value;
+                }
+            }
+            MV_M_LAST_DAMAGE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mv_m_last_damage_time = value;
+                }
+            }
+            IN_POWER_PLAY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.in_power_play = value;
+                }
+            }
+            SPAWN_COUNTER => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.spawn_counter = value;
+                }
+            }
+            ARENA_SPECTATOR => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.arena_spectator = value;
+                }
+            }
+            HEAD_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.head_scale = value;
+                }
+            }
+            TORSO_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.torso_scale = value;
+                }
+            }
+            HAND_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.hand_scale = value;
+                }
+            }
+            USE_BOSS_HEALTH_BAR => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.use_boss_health_bar = value;
+                }
+            }
+            USING_VR_HEADSET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.using_vr_headset = value;
+                }
+            }
+            TF_PLAYER_FORCED_SKIN => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.tf_player_forced_skin = value;
+                }
+            }
+            TF_PLAYER_FORCED_SKIN_ => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.tf_player_forced_skin_ = value;
+                }
+            }
+            KART_NEXT_AVAILABLE_BOOST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.kart_next_available_boost = value;
+                }
+            }
+            KART_HEALTH => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kart_health = value;
+                }
+            }
+            KART_STATE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kart_state = value;
+                }
+            }
+            GRAPPLING_HOOK_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.grappling_hook_target = value;
+                }
+            }
+            SECONDARY_LAST_WEAPON => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.secondary_last_weapon = value;
+                }
+            }
+            USING_ACTION_SLOT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.using_action_slot = value;
+                }
+            }
+            INSPECT_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.inspect_time = value;
+                }
+            }
+            HELPME_BUTTON_PRESS_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.helpme_button_press_time = value;
+                }
+            }
+            CAMPAIGN_MEDALS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.campaign_medals = value;
+                }
+            }
+            PLAYER_SKIN_OVERRIDE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.player_skin_override = value;
+                }
+            }
+            VIEWING_CYOAPDA => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.viewing_cyoapda = value;
+                }
+            }
+            REGENERATING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.regenerating = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            MY_WEAPONS_000 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[0] = value;
+                }
+            }
+            MY_WEAPONS_001 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[1] = value;
+                }
+            }
+            MY_WEAPONS_002 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[2] = value;
+                }
+            }
+            MY_WEAPONS_003 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[3] = value;
+                }
+            }
+            MY_WEAPONS_004 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[4] = value;
+                }
+            }
+            MY_WEAPONS_005 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[5] = value;
+                }
+            }
+            MY_WEAPONS_006 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[6] = value;
+                }
+            }
+            MY_WEAPONS_007 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[7] = value;
+                }
+            }
+            MY_WEAPONS_008 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[8] = value;
+                }
+            }
+            MY_WEAPONS_009 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[9] = value;
+                }
+            }
+            MY_WEAPONS_010 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[10] = value;
+                }
+            }
+            MY_WEAPONS_011 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[11] = value;
+                }
+            }
+            MY_WEAPONS_012 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[12] = value;
+                }
+            }
+            MY_WEAPONS_013 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[13] = value;
+                }
+            }
+            MY_WEAPONS_014 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[14] = value;
+                }
+            }
+            MY_WEAPONS_015 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[15] = value;
+                }
+            }
+            MY_WEAPONS_016 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[16] = value;
+                }
+            }
+            MY_WEAPONS_017 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[17] = value;
+                }
+            }
+            MY_WEAPONS_018 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[18] = value;
+                }
+            }
+            MY_WEAPONS_019 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[19] = value;
+                }
+            }
+            MY_WEAPONS_020 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[20] = value;
+                }
+            }
+            MY_WEAPONS_021 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[21] = value;
+                }
+            }
+            MY_WEAPONS_022 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[22] = value;
+                }
+            }
+            MY_WEAPONS_023 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[23] = value;
+                }
+            }
+            MY_WEAPONS_024 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[24] = value;
+                }
+            }
+            MY_WEAPONS_025 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[25] = value;
+                }
+            }
+            MY_WEAPONS_026 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[26] = value;
+                }
+            }
+            MY_WEAPONS_027 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[27] = value;
+                }
+            }
+            MY_WEAPONS_028 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[28] = value;
+                }
+            }
+            MY_WEAPONS_029 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[29] = value;
+                }
+            }
+            MY_WEAPONS_030 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[30] = value;
+                }
+            }
+            MY_WEAPONS_031 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[31] = value;
+                }
+            }
+            MY_WEAPONS_032 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[32] = value;
+                }
+            }
+            MY_WEAPONS_033 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[33] = value;
+                }
+            }
+            MY_WEAPONS_034 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[34] = value;
+                }
+            }
+            MY_WEAPONS_035 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[35] = value;
+                }
+            }
+            MY_WEAPONS_036 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[36] = value;
+                }
+            }
+            MY_WEAPONS_037 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[37] = value;
+                }
+            }
+            MY_WEAPONS_038 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[38] = value;
+                }
+            }
+            MY_WEAPONS_039 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[39] = value;
+                }
+            }
+            MY_WEAPONS_040 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[40] = value;
+                }
+            }
+            MY_WEAPONS_041 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[41] = value;
+                }
+            }
+            MY_WEAPONS_042 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[42] = value;
+                }
+            }
+            MY_WEAPONS_043 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[43] = value;
+                }
+            }
+            MY_WEAPONS_044 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[44] = value;
+                }
+            }
+            MY_WEAPONS_045 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[45] = value;
+                }
+            }
+            MY_WEAPONS_046 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[46] = value;
+                }
+            }
+            MY_WEAPONS_047 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_weapons[47] = value;
+                }
+            }
+            MY_WEARABLES_000 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[0] = value;
+                }
+            }
+            MY_WEARABLES_001 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[1] = value;
+                }
+            }
+            MY_WEARABLES_002 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[2] = value;
+                }
+            }
+            MY_WEARABLES_003 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[3] = value;
+                }
+            }
+            MY_WEARABLES_004 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[4] = value;
+                }
+            }
+            MY_WEARABLES_005 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[5] = value;
+                }
+            }
+            MY_WEARABLES_006 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[6] = value;
+                }
+            }
+            MY_WEARABLES_007 => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.my_wearables[7] = value;
+                }
+            }
+            AREA_BITS_000 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[0] = value;
+                }
+            }
+            AREA_BITS_001 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[1] = value;
+                }
+            }
+            AREA_BITS_002 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[2] = value;
+                }
+            }
+            AREA_BITS_003 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[3] = value;
+                }
+            }
+            AREA_BITS_004 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[4] = value;
+                }
+            }
+            AREA_BITS_005 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[5] = value;
+                }
+            }
+            AREA_BITS_006 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[6] = value;
+                }
+            }
+            AREA_BITS_007 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[7] = value;
+                }
+            }
+            AREA_BITS_008 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[8] = value;
+                }
+            }
+            AREA_BITS_009 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[9] = value;
+                }
+            }
+            AREA_BITS_010 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[10] = value;
+                }
+            }
+            AREA_BITS_011 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[11] = value;
+                }
+            }
+            AREA_BITS_012 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[12] = value;
+                }
+            }
+            AREA_BITS_013 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[13] = value;
+                }
+            }
+            AREA_BITS_014 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[14] = value;
+                }
+            }
+            AREA_BITS_015 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[15] = value;
+                }
+            }
+            AREA_BITS_016 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[16] = value;
+                }
+            }
+            AREA_BITS_017 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[17] = value;
+                }
+            }
+            AREA_BITS_018 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[18] = value;
+                }
+            }
+            AREA_BITS_019 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[19] = value;
+                }
+            }
+            AREA_BITS_020 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[20] = value;
+                }
+            }
+            AREA_BITS_021 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[21] = value;
+                }
+            }
+            AREA_BITS_022 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[22] = value;
+                }
+            }
+            AREA_BITS_023 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[23] = value;
+                }
+            }
+            AREA_BITS_024 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[24] = value;
+                }
+            }
+            AREA_BITS_025 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[25] = value;
+                }
+            }
+            AREA_BITS_026 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[26] = value;
+                }
+            }
+            AREA_BITS_027 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[27] = value;
+                }
+            }
+            AREA_BITS_028 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[28] = value;
+                }
+            }
+            AREA_BITS_029 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[29] = value;
+                }
+            }
+            AREA_BITS_030 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[30] = value;
+                }
+            }
+            AREA_BITS_031 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_bits[31] = value;
+                }
+            }
+            AREA_PORTAL_BITS_000 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[0] = value;
+                }
+            }
+            AREA_PORTAL_BITS_001 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[1] = value;
+                }
+            }
+            AREA_PORTAL_BITS_002 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[2] = value;
+                }
+            }
+            AREA_PORTAL_BITS_003 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[3] = value;
+                }
+            }
+            AREA_PORTAL_BITS_004 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[4] = value;
+                }
+            }
+            AREA_PORTAL_BITS_005 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[5] = value;
+                }
+            }
+            AREA_PORTAL_BITS_006 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[6] = value;
+                }
+            }
+            AREA_PORTAL_BITS_007 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[7] = value;
+                }
+            }
+            AREA_PORTAL_BITS_008 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[8] = value;
+                }
+            }
+            AREA_PORTAL_BITS_009 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[9] = value;
+                }
+            }
+            AREA_PORTAL_BITS_010 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[10] = value;
+                }
+            }
+            AREA_PORTAL_BITS_011 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[11] = value;
+                }
+            }
+            AREA_PORTAL_BITS_012 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[12] = value;
+                }
+            }
+            AREA_PORTAL_BITS_013 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[13] = value;
+                }
+            }
+            AREA_PORTAL_BITS_014 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[14] = value;
+                }
+            }
+            AREA_PORTAL_BITS_015 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[15] = value;
+                }
+            }
+            AREA_PORTAL_BITS_016 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[16] = value;
+                }
+            }
+            AREA_PORTAL_BITS_017 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[17] = value;
+                }
+            }
+            AREA_PORTAL_BITS_018 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[18] = value;
+                }
+            }
+            AREA_PORTAL_BITS_019 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[19] = value;
+                }
+            }
+            AREA_PORTAL_BITS_020 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[20] = value;
+                }
+            }
+            AREA_PORTAL_BITS_021 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[21] = value;
+                }
+            }
+            AREA_PORTAL_BITS_022 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[22] = value;
+                }
+            }
+            AREA_PORTAL_BITS_023 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.area_portal_bits[23] = value;
+                }
+            }
+            AMMO_000 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[0] = value;
+                }
+            }
+            AMMO_001 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[1] = value;
+                }
+            }
+            AMMO_002 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[2] = value;
+                }
+            }
+            AMMO_003 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[3] = value;
+                }
+            }
+            AMMO_004 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[4] = value;
+                }
+            }
+            AMMO_005 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[5] = value;
+                }
+            }
+            AMMO_006 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[6] = value;
+                }
+            }
+            AMMO_007 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[7] = value;
+                }
+            }
+            AMMO_008 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[8] = value;
+                }
+            }
+            AMMO_009 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[9] = value;
+                }
+            }
+            AMMO_010 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[10] = value;
+                }
+            }
+            AMMO_011 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[11] = value;
+                }
+            }
+            AMMO_012 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[12] = value;
+                }
+            }
+            AMMO_013 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[13] = value;
+                }
+            }
+            AMMO_014 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[14] = value;
+                }
+            }
+            AMMO_015 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[15] = value;
+                }
+            }
+            AMMO_016 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[16] = value;
+                }
+            }
+            AMMO_017 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[17] = value;
+                }
+            }
+            AMMO_018 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[18] = value;
+                }
+            }
+            AMMO_019 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[19] = value;
+                }
+            }
+            AMMO_020 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[20] = value;
+                }
+            }
+            AMMO_021 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[21] = value;
+                }
+            }
+            AMMO_022 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[22] = value;
+                }
+            }
+            AMMO_023 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[23] = value;
+                }
+            }
+            AMMO_024 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[24] = value;
+                }
+            }
+            AMMO_025 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[25] = value;
+                }
+            }
+            AMMO_026 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[26] = value;
+                }
+            }
+            AMMO_027 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[27] = value;
+                }
+            }
+            AMMO_028 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[28] = value;
+                }
+            }
+            AMMO_029 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[29] = value;
+                }
+            }
+            AMMO_030 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[30] = value;
+                }
+            }
+            AMMO_031 => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.ammo[31] = value;
+                }
+            }
+            ITEM_CHARGE_METER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[0] = value;
+                }
+            }
+            ITEM_CHARGE_METER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[1] = value;
+                }
+            }
+            ITEM_CHARGE_METER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[2] = value;
+                }
+            }
+            ITEM_CHARGE_METER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[3] = value;
+                }
+            }
+            ITEM_CHARGE_METER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[4] = value;
+                }
+            }
+            ITEM_CHARGE_METER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[5] = value;
+                }
+            }
+            ITEM_CHARGE_METER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[6] = value;
+                }
+            }
+            ITEM_CHARGE_METER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[7] = value;
+                }
+            }
+            ITEM_CHARGE_METER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[8] = value;
+                }
+            }
+            ITEM_CHARGE_METER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[9] = value;
+                }
+            }
+            ITEM_CHARGE_METER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.item_charge_meter[10] = value;
+                }
+            }
+            PLAYER_DOMINATED_000 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[0] = value;
+                }
+            }
+            PLAYER_DOMINATED_001 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[1] = value;
+                }
+            }
+            PLAYER_DOMINATED_002 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[2] = value;
+                }
+            }
+            PLAYER_DOMINATED_003 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[3] = value;
+                }
+            }
+            PLAYER_DOMINATED_004 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[4] = value;
+                }
+            }
+            PLAYER_DOMINATED_005 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[5] = value;
+                }
+            }
+            PLAYER_DOMINATED_006 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[6] = value;
+                }
+            }
+            PLAYER_DOMINATED_007 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[7] = value;
+                }
+            }
+            PLAYER_DOMINATED_008 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[8] = value;
+                }
+            }
+            PLAYER_DOMINATED_009 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[9] = value;
+                }
+            }
+            PLAYER_DOMINATED_010 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[10] = value;
+                }
+            }
+            PLAYER_DOMINATED_011 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[11] = value;
+                }
+            }
+            PLAYER_DOMINATED_012 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[12] = value;
+                }
+            }
+            PLAYER_DOMINATED_013 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[13] = value;
+                }
+            }
+            PLAYER_DOMINATED_014 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[14] = value;
+                }
+            }
+            PLAYER_DOMINATED_015 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[15] = value;
+                }
+            }
+            PLAYER_DOMINATED_016 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[16] = value;
+                }
+            }
+            PLAYER_DOMINATED_017 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[17] = value;
+                }
+            }
+            PLAYER_DOMINATED_018 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[18] = value;
+                }
+            }
+            PLAYER_DOMINATED_019 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[19] = value;
+                }
+            }
+            PLAYER_DOMINATED_020 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[20] = value;
+                }
+            }
+            PLAYER_DOMINATED_021 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[21] = value;
+                }
+            }
+            PLAYER_DOMINATED_022 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[22] = value;
+                }
+            }
+            PLAYER_DOMINATED_023 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[23] = value;
+                }
+            }
+            PLAYER_DOMINATED_024 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[24] = value;
+                }
+            }
+            PLAYER_DOMINATED_025 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[25] = value;
+                }
+            }
+            PLAYER_DOMINATED_026 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[26] = value;
+                }
+            }
+            PLAYER_DOMINATED_027 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[27] = value;
+                }
+            }
+            PLAYER_DOMINATED_028 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[28] = value;
+                }
+            }
+            PLAYER_DOMINATED_029 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[29] = value;
+                }
+            }
+            PLAYER_DOMINATED_030 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[30] = value;
+                }
+            }
+            PLAYER_DOMINATED_031 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[31] = value;
+                }
+            }
+            PLAYER_DOMINATED_032 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[32] = value;
+                }
+            }
+            PLAYER_DOMINATED_033 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[33] = value;
+                }
+            }
+            PLAYER_DOMINATED_034 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[34] = value;
+                }
+            }
+            PLAYER_DOMINATED_035 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[35] = value;
+                }
+            }
+            PLAYER_DOMINATED_036 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[36] = value;
+                }
+            }
+            PLAYER_DOMINATED_037 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[37] = value;
+                }
+            }
+            PLAYER_DOMINATED_038 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[38] = value;
+                }
+            }
+            PLAYER_DOMINATED_039 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[39] = value;
+                }
+            }
+            PLAYER_DOMINATED_040 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[40] = value;
+                }
+            }
+            PLAYER_DOMINATED_041 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[41] = value;
+                }
+            }
+            PLAYER_DOMINATED_042 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[42] = value;
+                }
+            }
+            PLAYER_DOMINATED_043 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[43] = value;
+                }
+            }
+            PLAYER_DOMINATED_044 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[44] = value;
+                }
+            }
+            PLAYER_DOMINATED_045 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[45] = value;
+                }
+            }
+            PLAYER_DOMINATED_046 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[46] = value;
+                }
+            }
+            PLAYER_DOMINATED_047 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[47] = value;
+                }
+            }
+            PLAYER_DOMINATED_048 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[48] = value;
+                }
+            }
+            PLAYER_DOMINATED_049 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[49] = value;
+                }
+            }
+            PLAYER_DOMINATED_050 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[50] = value;
+                }
+            }
+            PLAYER_DOMINATED_051 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[51] = value;
+                }
+            }
+            PLAYER_DOMINATED_052 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[52] = value;
+                }
+            }
+            PLAYER_DOMINATED_053 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[53] = value;
+                }
+            }
+            PLAYER_DOMINATED_054 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[54] = value;
+                }
+            }
+            PLAYER_DOMINATED_055 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[55] = value;
+                }
+            }
+            PLAYER_DOMINATED_056 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[56] = value;
+                }
+            }
+            PLAYER_DOMINATED_057 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[57] = value;
+                }
+            }
+            PLAYER_DOMINATED_058 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[58] = value;
+                }
+            }
+            PLAYER_DOMINATED_059 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[59] = value;
+                }
+            }
+            PLAYER_DOMINATED_060 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[60] = value;
+                }
+            }
+            PLAYER_DOMINATED_061 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[61] = value;
+                }
+            }
+            PLAYER_DOMINATED_062 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[62] = value;
+                }
+            }
+            PLAYER_DOMINATED_063 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[63] = value;
+                }
+            }
+            PLAYER_DOMINATED_064 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[64] = value;
+                }
+            }
+            PLAYER_DOMINATED_065 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[65] = value;
+                }
+            }
+            PLAYER_DOMINATED_066 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[66] = value;
+                }
+            }
+            PLAYER_DOMINATED_067 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[67] = value;
+                }
+            }
+            PLAYER_DOMINATED_068 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[68] = value;
+                }
+            }
+            PLAYER_DOMINATED_069 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[69] = value;
+                }
+            }
+            PLAYER_DOMINATED_070 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[70] = value;
+                }
+            }
+            PLAYER_DOMINATED_071 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[71] = value;
+                }
+            }
+            PLAYER_DOMINATED_072 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[72] = value;
+                }
+            }
+            PLAYER_DOMINATED_073 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[73] = value;
+                }
+            }
+            PLAYER_DOMINATED_074 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[74] = value;
+                }
+            }
+            PLAYER_DOMINATED_075 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[75] = value;
+                }
+            }
+            PLAYER_DOMINATED_076 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[76] = value;
+                }
+            }
+            PLAYER_DOMINATED_077 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[77] = value;
+                }
+            }
+            PLAYER_DOMINATED_078 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[78] = value;
+                }
+            }
+            PLAYER_DOMINATED_079 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[79] = value;
+                }
+            }
+            PLAYER_DOMINATED_080 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[80] = value;
+                }
+            }
+            PLAYER_DOMINATED_081 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[81] = value;
+                }
+            }
+            PLAYER_DOMINATED_082 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[82] = value;
+                }
+            }
+            PLAYER_DOMINATED_083 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[83] = value;
+                }
+            }
+            PLAYER_DOMINATED_084 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[84] = value;
+                }
+            }
+            PLAYER_DOMINATED_085 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[85] = value;
+                }
+            }
+            PLAYER_DOMINATED_086 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[86] = value;
+                }
+            }
+            PLAYER_DOMINATED_087 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[87] = value;
+                }
+            }
+            PLAYER_DOMINATED_088 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[88] = value;
+                }
+            }
+            PLAYER_DOMINATED_089 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[89] = value;
+                }
+            }
+            PLAYER_DOMINATED_090 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[90] = value;
+                }
+            }
+            PLAYER_DOMINATED_091 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[91] = value;
+                }
+            }
+            PLAYER_DOMINATED_092 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[92] = value;
+                }
+            }
+            PLAYER_DOMINATED_093 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[93] = value;
+                }
+            }
+            PLAYER_DOMINATED_094 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[94] = value;
+                }
+            }
+            PLAYER_DOMINATED_095 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[95] = value;
+                }
+            }
+            PLAYER_DOMINATED_096 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[96] = value;
+                }
+            }
+            PLAYER_DOMINATED_097 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[97] = value;
+                }
+            }
+            PLAYER_DOMINATED_098 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[98] = value;
+                }
+            }
+            PLAYER_DOMINATED_099 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[99] = value;
+                }
+            }
+            PLAYER_DOMINATED_100 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[100] = value;
+                }
+            }
+            PLAYER_DOMINATED_101 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominated[101] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_000 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[0] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_001 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[1] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_002 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[2] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_003 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[3] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_004 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[4] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_005 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[5] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_006 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[6] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_007 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[7] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_008 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[8] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_009 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[9] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_010 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[10] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_011 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[11] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_012 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[12] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_013 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[13] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_014 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[14] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_015 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[15] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_016 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[16] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_017 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[17] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_018 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[18] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_019 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[19] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_020 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[20] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_021 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[21] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_022 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[22] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_023 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[23] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_024 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[24] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_025 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[25] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_026 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[26] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_027 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[27] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_028 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[28] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_029 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[29] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_030 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[30] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_031 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[31] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_032 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[32] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_033 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[33] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_034 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[34] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_035 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[35] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_036 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[36] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_037 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[37] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_038 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[38] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_039 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[39] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_040 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[40] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_041 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[41] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_042 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[42] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_043 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[43] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_044 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[44] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_045 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[45] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_046 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[46] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_047 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[47] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_048 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[48] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_049 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[49] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_050 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[50] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_051 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[51] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_052 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[52] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_053 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[53] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_054 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[54] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_055 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[55] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_056 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[56] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_057 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[57] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_058 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[58] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_059 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[59] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_060 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[60] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_061 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[61] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_062 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[62] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_063 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[63] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_064 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[64] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_065 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[65] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_066 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[66] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_067 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[67] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_068 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[68] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_069 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[69] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_070 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[70] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_071 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[71] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_072 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[72] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_073 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[73] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_074 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[74] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_075 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[75] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_076 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[76] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_077 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[77] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_078 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[78] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_079 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[79] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_080 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[80] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_081 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[81] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_082 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[82] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_083 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[83] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_084 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[84] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_085 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[85] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_086 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[86] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_087 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[87] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_088 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[88] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_089 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[89] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_090 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[90] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_091 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[91] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_092 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[92] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_093 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[93] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_094 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[94] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_095 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[95] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_096 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[96] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_097 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[97] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_098 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[98] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_099 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[99] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_100 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[100] = value;
+                }
+            }
+            PLAYER_DOMINATING_ME_101 => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.player_dominating_me[101] = value;
+                }
+            }
+            STREAKS_000 => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.streaks[0] = value;
+                }
+            }
+            STREAKS_001 => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.streaks[1] = value;
+                }
+            }
+            STREAKS_002 => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.streaks[2] = value;
+                }
+            }
+            STREAKS_003 => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.streaks[3] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CTFProjectile_Rocket` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct TFProjectileRocket {
+    ///`DT_AnimTimeMustBeFirst.m_flAnimTime`
+    pub anim_time: u8,
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub cycle: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_TFBaseRocket.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_TFBaseRocket.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_BaseProjectile.m_hOriginalLauncher`
+    pub original_launcher: Handle,
+    ///`DT_TFBaseRocket.m_vInitialVelocity`
+    pub v_initial_velocity: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_TFBaseRocket.m_iDeflected`
+    pub deflected: u8,
+    ///`DT_TFBaseRocket.m_hLauncher`
+    pub launcher: Handle,
+    ///`DT_TFProjectile_Rocket.m_bCritical`
+    pub critical: bool,
+}
+impl Default for TFProjectileRocket {
+    fn default() -> Self {
+        TFProjectileRocket {
+            anim_time: Default::default(),
+            cycle: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            solid_type: Default::default(),
+            solid_flags: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            maxs_pre_scaled: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            original_launcher: Default::default(),
+            v_initial_velocity: Default::default(),
+            mins: Default::default(),
+            maxs: Default::default(),
+            deflected: Default::default(),
+            launcher: Default::default(),
+            critical: Default::default(),
+        }
+    }
+}
+impl TFProjectileRocket {
+    pub const CLASS_NAME: &'static str = "CTFProjectile_Rocket";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, _location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const ANIM_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AnimTimeMustBeFirst", "m_flAnimTime");
+        const CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_angRotation");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const ORIGINAL_LAUNCHER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseProjectile", "m_hOriginalLauncher");
+        const V_INITIAL_VELOCITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_vInitialVelocity");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const DEFLECTED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_iDeflected");
+        const LAUNCHER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFBaseRocket", "m_hLauncher");
+        const CRITICAL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFProjectile_Rocket", "m_bCritical");
+        match prop.identifier {
+            ANIM_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.anim_time = value;
+                }
+            }
+            CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.cycle = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            ORIGINAL_LAUNCHER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.original_launcher = value;
+                }
+            }
+            V_INITIAL_VELOCITY => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.v_initial_velocity = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            DEFLECTED => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.deflected = value;
+                }
+            }
+            LAUNCHER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.launcher = value;
+                }
+            }
+            CRITICAL => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.critical = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CTFTeam` server class
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TFTeam {
+    ///`DT_Team.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_Team.m_iScore`
+    pub score: i32,
+    ///`DT_Team.m_iRoundsWon`
+    pub rounds_won: i8,
+    ///`DT_Team.m_szTeamname`
+    pub teamname: String,
+    ///`DT_Team."player_array"`
+    pub player_array: Vec<SendPropValue>,
+    ///`DT_TFTeam.m_nFlagCaptures`
+    pub flag_captures: i8,
+    ///`DT_TFTeam.m_iRole`
+    pub role: u8,
+    ///`DT_TFTeam."team_object_array"`
+    pub team_object_array: Vec<SendPropValue>,
+    ///`DT_TFTeam.m_hLeader`
+    pub leader: Handle,
+}
+impl TFTeam {
+    pub const CLASS_NAME: &'static str = "CTFTeam";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, _location: Option<PropLocation>) {
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_Team", "m_iTeamNum");
+        const SCORE: SendPropIdentifier = SendPropIdentifier::new("DT_Team", "m_iScore");
+        const ROUNDS_WON: SendPropIdentifier = SendPropIdentifier::new("DT_Team", "m_iRoundsWon");
+        const TEAMNAME: SendPropIdentifier = SendPropIdentifier::new("DT_Team", "m_szTeamname");
+        const PLAYER_ARRAY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_Team", "\"player_array\"");
+        const FLAG_CAPTURES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFTeam", "m_nFlagCaptures");
+        const ROLE: SendPropIdentifier = SendPropIdentifier::new("DT_TFTeam", "m_iRole");
+        const TEAM_OBJECT_ARRAY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFTeam", "\"team_object_array\"");
+        const LEADER: SendPropIdentifier = SendPropIdentifier::new("DT_TFTeam", "m_hLeader");
+        match prop.identifier {
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            SCORE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.score = value;
+                }
+            }
+            ROUNDS_WON => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.rounds_won = value;
+                }
+            }
+            TEAMNAME => {
+                let value: Option<String> = <&str>::try_from(&prop.value).ok().map(String::from);
+                if let Some(value) = value {
+                    self.teamname = value;
+                }
+            }
+            PLAYER_ARRAY => {
+                let value: Option<Vec<SendPropValue>> = <&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec);
+                if let Some(value) = value {
+                    self.player_array = value;
+                }
+            }
+            FLAG_CAPTURES => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.flag_captures = value;
+                }
+            }
+            ROLE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.role = value;
+                }
+            }
+            TEAM_OBJECT_ARRAY => {
+                let value: Option<Vec<SendPropValue>> = <&[SendPropValue]>::try_from(&prop.value)
+                    .ok()
+                    .map(<[SendPropValue]>::to_vec);
+                if let Some(value) = value {
+                    self.team_object_array = value;
+                }
+            }
+            LEADER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.leader = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+///Typed props for the `CWeaponMedigun` server class
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeaponMedigun {
+    ///`DT_ServerAnimationData.m_flCycle`
+    pub cycle: f32,
+    ///`DT_LocalTFWeaponMedigunData.m_flChargeLevel`
+    pub local_tf_weapon_medigun_data_charge_level: f32,
+    ///`DT_TFWeaponMedigunDataNonLocal.m_flChargeLevel`
+    pub tf_weapon_medigun_data_non_local_charge_level: f32,
+    ///`DT_BaseEntity.m_flSimulationTime`
+    pub simulation_time: u8,
+    ///`DT_BaseEntity.m_vecOrigin`
+    pub origin: Vector,
+    ///`DT_BaseEntity.m_angRotation`
+    pub rotation: Vector,
+    ///`DT_CollisionProperty.m_nSurroundType`
+    pub surround_type: u8,
+    ///`DT_CollisionProperty.m_triggerBloat`
+    pub trigger_bloat: u8,
+    ///`DT_CollisionProperty.m_bUniformTriggerBloat`
+    pub uniform_trigger_bloat: bool,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMinsPreScaled`
+    pub specified_surrounding_mins_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxsPreScaled`
+    pub specified_surrounding_maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMins`
+    pub specified_surrounding_mins: Vector,
+    ///`DT_CollisionProperty.m_vecSpecifiedSurroundingMaxs`
+    pub specified_surrounding_maxs: Vector,
+    ///`DT_PredictableId.m_PredictableID`
+    pub predictable_id: u32,
+    ///`DT_PredictableId.m_bIsPlayerSimulated`
+    pub is_player_simulated: bool,
+    ///The elements of the `m_nModelIndexOverrides` array table
+    pub model_index_overrides: [i16; 4],
+    ///The elements of the `m_flPoseParameter` array table
+    pub pose_parameter: [f32; 24],
+    ///The elements of the `m_flEncodedController` array table
+    pub encoded_controller: [f32; 4],
+    ///`DT_CollisionProperty.m_vecMinsPreScaled`
+    pub mins_pre_scaled: Vector,
+    ///The number of elements in `m_Attributes` in `m_AttributeList`
+    pub attribute_list_attributes_len: u8,
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex` in `m_AttributeList`
+    pub attribute_list_attribute_definition_index: [u16; 20],
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32` in `m_AttributeList`
+    pub attribute_list_raw_value_32: [u32; 20],
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency` in `m_AttributeList`
+    pub attribute_list_refundable_currency: [u32; 20],
+    ///The number of elements in `m_Attributes` in `m_NetworkedDynamicAttributesForDemos`
+    pub networked_dynamic_attributes_for_demos_attributes_len: u8,
+    ///`DT_ScriptCreatedAttribute.m_iAttributeDefinitionIndex` in `m_NetworkedDynamicAttributesForDemos`
+    pub networked_dynamic_attributes_for_demos_attribute_definition_index: [u16; 20],
+    ///`DT_ScriptCreatedAttribute.m_iRawValue32` in `m_NetworkedDynamicAttributesForDemos`
+    pub networked_dynamic_attributes_for_demos_raw_value_32: [u32; 20],
+    ///`DT_ScriptCreatedAttribute.m_nRefundableCurrency` in `m_NetworkedDynamicAttributesForDemos`
+    pub networked_dynamic_attributes_for_demos_refundable_currency: [u32; 20],
+    ///`DT_ScriptCreatedItem.m_iItemDefinitionIndex`
+    pub item_definition_index: u32,
+    ///`DT_ScriptCreatedItem.m_iEntityLevel`
+    pub entity_level: i8,
+    ///`DT_ScriptCreatedItem.m_iItemIDHigh`
+    pub item_id_high: u32,
+    ///`DT_ScriptCreatedItem.m_iItemIDLow`
+    pub item_id_low: u32,
+    ///`DT_ScriptCreatedItem.m_iAccountID`
+    pub account_id: u32,
+    ///`DT_ScriptCreatedItem.m_iEntityQuality`
+    pub entity_quality: i8,
+    ///`DT_ScriptCreatedItem.m_bInitialized`
+    pub initialized: bool,
+    ///`DT_ScriptCreatedItem.m_bOnlyIterateItemViewAttributes`
+    pub only_iterate_item_view_attributes: bool,
+    ///`DT_ScriptCreatedItem.m_iTeamNumber`
+    pub team_number: i32,
+    ///`DT_AttributeContainer.m_hOuter`
+    pub outer: Handle,
+    ///`DT_AttributeContainer.m_ProviderType`
+    pub provider_type: u8,
+    ///`DT_AttributeContainer.m_iReapplyProvisionParity`
+    pub reapply_provision_parity: u8,
+    ///`DT_LocalWeaponData.m_iClip1`
+    pub clip_1: u8,
+    ///`DT_LocalWeaponData.m_iClip2`
+    pub clip_2: u8,
+    ///`DT_LocalWeaponData.m_iPrimaryAmmoType`
+    pub primary_ammo_type: i8,
+    ///`DT_LocalWeaponData.m_iSecondaryAmmoType`
+    pub secondary_ammo_type: i8,
+    ///`DT_LocalWeaponData.m_nViewModelIndex`
+    pub local_weapon_data_view_model_index: bool,
+    ///`DT_LocalWeaponData.m_nCustomViewmodelModelIndex`
+    pub custom_viewmodel_model_index: i16,
+    ///`DT_LocalWeaponData.m_bFlipViewModel`
+    pub flip_view_model: i8,
+    ///`DT_LocalActiveWeaponData.m_flNextPrimaryAttack`
+    pub next_primary_attack: f32,
+    ///`DT_LocalActiveWeaponData.m_flNextSecondaryAttack`
+    pub next_secondary_attack: f32,
+    ///`DT_LocalActiveWeaponData.m_nNextThinkTick`
+    pub next_think_tick: i32,
+    ///`DT_LocalActiveWeaponData.m_flTimeWeaponIdle`
+    pub time_weapon_idle: f32,
+    ///`DT_LocalTFWeaponData.m_flLastCritCheckTime`
+    pub last_crit_check_time: f32,
+    ///`DT_LocalTFWeaponData.m_flReloadPriorNextFire`
+    pub reload_prior_next_fire: f32,
+    ///`DT_LocalTFWeaponData.m_flLastFireTime`
+    pub last_fire_time: f32,
+    ///`DT_LocalTFWeaponData.m_flEffectBarRegenTime`
+    pub effect_bar_regen_time: f32,
+    ///`DT_LocalTFWeaponData.m_flObservedCritChance`
+    pub observed_crit_chance: f32,
+    ///`DT_CollisionProperty.m_vecMaxsPreScaled`
+    pub maxs_pre_scaled: Vector,
+    ///`DT_CollisionProperty.m_vecMins`
+    pub mins: Vector,
+    ///`DT_CollisionProperty.m_vecMaxs`
+    pub maxs: Vector,
+    ///`DT_CollisionProperty.m_nSolidType`
+    pub solid_type: u8,
+    ///`DT_BaseEntity.m_ubInterpolationFrame`
+    pub interpolation_frame: u8,
+    ///`DT_BaseEntity.m_nModelIndex`
+    pub model_index: i16,
+    ///`DT_BaseEntity.m_nRenderFX`
+    pub render_fx: u8,
+    ///`DT_BaseEntity.m_nRenderMode`
+    pub render_mode: u8,
+    ///`DT_BaseEntity.m_fEffects`
+    pub effects: u16,
+    ///`DT_BaseEntity.m_clrRender`
+    pub render: u32,
+    ///`DT_BaseEntity.m_iTeamNum`
+    pub team_num: i8,
+    ///`DT_BaseEntity.m_CollisionGroup`
+    pub collision_group: u8,
+    ///`DT_BaseEntity.m_flElasticity`
+    pub elasticity: f32,
+    ///`DT_BaseEntity.m_flShadowCastDistance`
+    pub shadow_cast_distance: f32,
+    ///`DT_BaseEntity.m_hOwnerEntity`
+    pub owner_entity: Handle,
+    ///`DT_BaseEntity.m_hEffectEntity`
+    pub effect_entity: Handle,
+    ///`DT_BaseEntity.moveparent`
+    pub moveparent: u32,
+    ///`DT_BaseEntity.m_iParentAttachment`
+    pub parent_attachment: u8,
+    ///`DT_BaseEntity.movetype`
+    pub movetype: u8,
+    ///`DT_BaseEntity.movecollide`
+    pub movecollide: u8,
+    ///`DT_CollisionProperty.m_usSolidFlags`
+    pub solid_flags: u16,
+    ///`DT_BaseEntity.m_iTextureFrameIndex`
+    pub texture_frame_index: u8,
+    ///`DT_BaseEntity.m_bSimulatedEveryTick`
+    pub simulated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAnimatedEveryTick`
+    pub animated_every_tick: bool,
+    ///`DT_BaseEntity.m_bAlternateSorting`
+    pub alternate_sorting: bool,
+    ///`DT_BaseAnimating.m_nForceBone`
+    pub force_bone: i8,
+    ///`DT_BaseAnimating.m_vecForce`
+    pub force: Vector,
+    ///`DT_BaseAnimating.m_nSkin`
+    pub skin: i16,
+    ///`DT_BaseAnimating.m_nBody`
+    pub body: i32,
+    ///`DT_BaseAnimating.m_nHitboxSet`
+    pub hitbox_set: u8,
+    ///`DT_BaseAnimating.m_flModelScale`
+    pub model_scale: f32,
+    ///`DT_BaseAnimating.m_nSequence`
+    pub sequence: u16,
+    ///`DT_BaseAnimating.m_flPlaybackRate`
+    pub playback_rate: f32,
+    ///`DT_BaseAnimating.m_bClientSideAnimation`
+    pub client_side_animation: bool,
+    ///`DT_BaseAnimating.m_bClientSideFrameReset`
+    pub client_side_frame_reset: bool,
+    ///`DT_BaseAnimating.m_nNewSequenceParity`
+    pub new_sequence_parity: u8,
+    ///`DT_BaseAnimating.m_nResetEventsParity`
+    pub reset_events_parity: u8,
+    ///`DT_BaseAnimating.m_nMuzzleFlashParity`
+    pub muzzle_flash_parity: u8,
+    ///`DT_BaseAnimating.m_hLightingOrigin`
+    pub lighting_origin: Handle,
+    ///`DT_BaseAnimating.m_hLightingOriginRelative`
+    pub lighting_origin_relative: Handle,
+    ///`DT_BaseAnimating.m_fadeMinDist`
+    pub fade_min_dist: f32,
+    ///`DT_BaseAnimating.m_fadeMaxDist`
+    pub fade_max_dist: f32,
+    ///`DT_BaseAnimating.m_flFadeScale`
+    pub fade_scale: f32,
+    ///`DT_EconEntity.m_bValidatedAttachedEntity`
+    pub validated_attached_entity: bool,
+    ///`DT_BaseCombatWeapon.m_iViewModelIndex`
+    pub base_combat_weapon_view_model_index: i16,
+    ///`DT_BaseCombatWeapon.m_iWorldModelIndex`
+    pub world_model_index: i16,
+    ///`DT_BaseCombatWeapon.m_iState`
+    pub state: u8,
+    ///`DT_BaseCombatWeapon.m_hOwner`
+    pub owner: Handle,
+    ///`DT_TFWeaponBase.m_bLowered`
+    pub lowered: bool,
+    ///`DT_TFWeaponBase.m_bResetParity`
+    pub reset_parity: bool,
+    ///`DT_TFWeaponBase.m_iReloadMode`
+    pub reload_mode: u8,
+    ///`DT_TFWeaponBase.m_bReloadedThroughAnimEvent`
+    pub reloaded_through_anim_event: bool,
+    ///`DT_TFWeaponBase.m_bDisguiseWeapon`
+    pub disguise_weapon: bool,
+    ///`DT_TFWeaponBase.m_flEnergy`
+    pub energy: f32,
+    ///`DT_TFWeaponBase.m_hExtraWearable`
+    pub extra_wearable: Handle,
+    ///`DT_TFWeaponBase.m_hExtraWearableViewModel`
+    pub extra_wearable_view_model: Handle,
+    ///`DT_TFWeaponBase.m_bBeingRepurposedForTaunt`
+    pub being_repurposed_for_taunt: bool,
+    ///`DT_TFWeaponBase.m_nKillComboClass`
+    pub kill_combo_class: u8,
+    ///`DT_TFWeaponBase.m_nKillComboCount`
+    pub kill_combo_count: u8,
+    ///`DT_TFWeaponBase.m_flInspectAnimEndTime`
+    pub inspect_anim_end_time: f32,
+    ///`DT_TFWeaponBase.m_nInspectStage`
+    pub inspect_stage: i32,
+    ///`DT_TFWeaponBase.m_iConsecutiveShots`
+    pub consecutive_shots: i32,
+    ///`DT_WeaponMedigun.m_hHealingTarget`
+    pub healing_target: Handle,
+    ///`DT_WeaponMedigun.m_bHealing`
+    pub healing: bool,
+    ///`DT_WeaponMedigun.m_bAttacking`
+    pub attacking: bool,
+    ///`DT_WeaponMedigun.m_bChargeRelease`
+    pub charge_release: bool,
+    ///`DT_WeaponMedigun.m_bHolstered`
+    pub holstered: bool,
+    ///`DT_WeaponMedigun.m_nChargeResistType`
+    pub charge_resist_type: i32,
+    ///`DT_WeaponMedigun.m_hLastHealingTarget`
+    pub last_healing_target: Handle,
+}
+impl Default for WeaponMedigun {
+    fn default() -> Self {
+        WeaponMedigun {
+            cycle: Default::default(),
+            local_tf_weapon_medigun_data_charge_level: Default::default(),
+            tf_weapon_medigun_data_non_local_charge_level: Default::default(),
+            simulation_time: Default::default(),
+            origin: Default::default(),
+            rotation: Default::default(),
+            surround_type: Default::default(),
+            trigger_bloat: Default::default(),
+            uniform_trigger_bloat: Default::default(),
+            specified_surrounding_mins_pre_scaled: Default::default(),
+            specified_surrounding_maxs_pre_scaled: Default::default(),
+            specified_surrounding_mins: Default::default(),
+            specified_surrounding_maxs: Default::default(),
+            predictable_id: Default::default(),
+            is_player_simulated: Default::default(),
+            model_index_overrides: std::array::from_fn(|_| Default::default()),
+            pose_parameter: std::array::from_fn(|_| Default::default()),
+            encoded_controller: std::array::from_fn(|_| Default::default()),
+            mins_pre_scaled: Default::default(),
+            attribute_list_attributes_len: Default::default(),
+            attribute_list_attribute_definition_index: std::array::from_fn(|_| Default::default()),
+            attribute_list_raw_value_32: std::array::from_fn(|_| Default::default()),
+            attribute_list_refundable_currency: std::array::from_fn(|_| Default::default()),
+            networked_dynamic_attributes_for_demos_attributes_len: Default::default(),
+            networked_dynamic_attributes_for_demos_attribute_definition_index: std::array::from_fn(
+                |_| Default::default(),
+            ),
+            networked_dynamic_attributes_for_demos_raw_value_32: std::array::from_fn(|_| {
+                Default::default()
+            }),
+            networked_dynamic_attributes_for_demos_refundable_currency: std::array::from_fn(|_| {
+                Default::default()
+            }),
+            item_definition_index: Default::default(),
+            entity_level: Default::default(),
+            item_id_high: Default::default(),
+            item_id_low: Default::default(),
+            account_id: Default::default(),
+            entity_quality: Default::default(),
+            initialized: Default::default(),
+            only_iterate_item_view_attributes: Default::default(),
+            team_number: Default::default(),
+            outer: Default::default(),
+            provider_type: Default::default(),
+            reapply_provision_parity: Default::default(),
+            clip_1: Default::default(),
+            clip_2: Default::default(),
+            primary_ammo_type: Default::default(),
+            secondary_ammo_type: Default::default(),
+            local_weapon_data_view_model_index: Default::default(),
+            custom_viewmodel_model_index: Default::default(),
+            flip_view_model: Default::default(),
+            next_primary_attack: Default::default(),
+            next_secondary_attack: Default::default(),
+            next_think_tick: Default::default(),
+            time_weapon_idle: Default::default(),
+            last_crit_check_time: Default::default(),
+            reload_prior_next_fire: Default::default(),
+            last_fire_time: Default::default(),
+            effect_bar_regen_time: Default::default(),
+            observed_crit_chance: Default::default(),
+            maxs_pre_scaled: Default::default(),
+            mins: Default::default(),
+            maxs: Default::default(),
+            solid_type: Default::default(),
+            interpolation_frame: Default::default(),
+            model_index: Default::default(),
+            render_fx: Default::default(),
+            render_mode: Default::default(),
+            effects: Default::default(),
+            render: Default::default(),
+            team_num: Default::default(),
+            collision_group: Default::default(),
+            elasticity: Default::default(),
+            shadow_cast_distance: Default::default(),
+            owner_entity: Default::default(),
+            effect_entity: Default::default(),
+            moveparent: Default::default(),
+            parent_attachment: Default::default(),
+            movetype: Default::default(),
+            movecollide: Default::default(),
+            solid_flags: Default::default(),
+            texture_frame_index: Default::default(),
+            simulated_every_tick: Default::default(),
+            animated_every_tick: Default::default(),
+            alternate_sorting: Default::default(),
+            force_bone: Default::default(),
+            force: Default::default(),
+            skin: Default::default(),
+            body: Default::default(),
+            hitbox_set: Default::default(),
+            model_scale: Default::default(),
+            sequence: Default::default(),
+            playback_rate: Default::default(),
+            client_side_animation: Default::default(),
+            client_side_frame_reset: Default::default(),
+            new_sequence_parity: Default::default(),
+            reset_events_parity: Default::default(),
+            muzzle_flash_parity: Default::default(),
+            lighting_origin: Default::default(),
+            lighting_origin_relative: Default::default(),
+            fade_min_dist: Default::default(),
+            fade_max_dist: Default::default(),
+            fade_scale: Default::default(),
+            validated_attached_entity: Default::default(),
+            base_combat_weapon_view_model_index: Default::default(),
+            world_model_index: Default::default(),
+            state: Default::default(),
+            owner: Default::default(),
+            lowered: Default::default(),
+            reset_parity: Default::default(),
+            reload_mode: Default::default(),
+            reloaded_through_anim_event: Default::default(),
+            disguise_weapon: Default::default(),
+            energy: Default::default(),
+            extra_wearable: Default::default(),
+            extra_wearable_view_model: Default::default(),
+            being_repurposed_for_taunt: Default::default(),
+            kill_combo_class: Default::default(),
+            kill_combo_count: Default::default(),
+            inspect_anim_end_time: Default::default(),
+            inspect_stage: Default::default(),
+            consecutive_shots: Default::default(),
+            healing_target: Default::default(),
+            healing: Default::default(),
+            attacking: Default::default(),
+            charge_release: Default::default(),
+            holstered: Default::default(),
+            charge_resist_type: Default::default(),
+            last_healing_target: Default::default(),
+        }
+    }
+}
+impl WeaponMedigun {
+    pub const CLASS_NAME: &'static str = "CWeaponMedigun";
+    /// Create the struct from an entity containing the full set of props
+    pub fn from_entity(entity: &PacketEntity, state: &ParserState) -> Result<Self> {
+        let mut result = Self::default();
+        result.apply(entity, state)?;
+        Ok(result)
+    }
+    /// Update the fields with the props of the entity
+    ///
+    /// Fails if the lazily decoded props of the entity can't be decoded
+    pub fn apply(&mut self, entity: &PacketEntity, state: &ParserState) -> Result<()> {
+        for prop in entity.try_props(state)? {
+            let location = state.prop_location(entity.server_class, prop.index);
+            self.apply_prop(&prop, location);
+        }
+        Ok(())
+    }
+    /// Update the field matching the prop, if any
+    ///
+    /// `location` is the location of the prop as returned by
+    /// [`ParserState::prop_location`]
+    pub fn apply_prop(&mut self, prop: &SendProp, location: Option<PropLocation>) {
+        const MODEL_INDEX_OVERRIDES_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "000");
+        const MODEL_INDEX_OVERRIDES_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "001");
+        const MODEL_INDEX_OVERRIDES_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "002");
+        const MODEL_INDEX_OVERRIDES_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_nModelIndexOverrides", "003");
+        const POSE_PARAMETER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "000");
+        const POSE_PARAMETER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "001");
+        const POSE_PARAMETER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "002");
+        const POSE_PARAMETER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "003");
+        const POSE_PARAMETER_004: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "004");
+        const POSE_PARAMETER_005: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "005");
+        const POSE_PARAMETER_006: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "006");
+        const POSE_PARAMETER_007: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "007");
+        const POSE_PARAMETER_008: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "008");
+        const POSE_PARAMETER_009: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "009");
+        const POSE_PARAMETER_010: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "010");
+        const POSE_PARAMETER_011: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "011");
+        const POSE_PARAMETER_012: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "012");
+        const POSE_PARAMETER_013: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "013");
+        const POSE_PARAMETER_014: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "014");
+        const POSE_PARAMETER_015: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "015");
+        const POSE_PARAMETER_016: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "016");
+        const POSE_PARAMETER_017: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "017");
+        const POSE_PARAMETER_018: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "018");
+        const POSE_PARAMETER_019: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "019");
+        const POSE_PARAMETER_020: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "020");
+        const POSE_PARAMETER_021: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "021");
+        const POSE_PARAMETER_022: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "022");
+        const POSE_PARAMETER_023: SendPropIdentifier =
+            SendPropIdentifier::new("m_flPoseParameter", "023");
+        const ENCODED_CONTROLLER_000: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "000");
+        const ENCODED_CONTROLLER_001: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "001");
+        const ENCODED_CONTROLLER_002: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "002");
+        const ENCODED_CONTROLLER_003: SendPropIdentifier =
+            SendPropIdentifier::new("m_flEncodedController", "003");
+        const CYCLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ServerAnimationData", "m_flCycle");
+        const LOCAL_TF_WEAPON_MEDIGUN_DATA_CHARGE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponMedigunData", "m_flChargeLevel");
+        const TF_WEAPON_MEDIGUN_DATA_NON_LOCAL_CHARGE_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponMedigunDataNonLocal", "m_flChargeLevel");
+        const SIMULATION_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flSimulationTime");
+        const ORIGIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_vecOrigin");
+        const ROTATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_angRotation");
+        const SURROUND_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSurroundType");
+        const TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_triggerBloat");
+        const UNIFORM_TRIGGER_BLOAT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_bUniformTriggerBloat");
+        const SPECIFIED_SURROUNDING_MINS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMinsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MAXS_PRE_SCALED: SendPropIdentifier = SendPropIdentifier::new(
+            "DT_CollisionProperty",
+            "m_vecSpecifiedSurroundingMaxsPreScaled",
+        );
+        const SPECIFIED_SURROUNDING_MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMins");
+        const SPECIFIED_SURROUNDING_MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecSpecifiedSurroundingMaxs");
+        const PREDICTABLE_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_PredictableID");
+        const IS_PLAYER_SIMULATED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_PredictableId", "m_bIsPlayerSimulated");
+        const MINS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMinsPreScaled");
+        const ATTRIBUTE_LIST_ATTRIBUTES_LEN: SendPropIdentifier =
+            SendPropIdentifier::new("_LPT_m_Attributes_20", "lengthprop20");
+        const ATTRIBUTE_LIST_ATTRIBUTE_DEFINITION_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+        const ATTRIBUTE_LIST_RAW_VALUE_32: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iRawValue32");
+        const ATTRIBUTE_LIST_REFUNDABLE_CURRENCY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_nRefundableCurrency");
+        const ITEM_DEFINITION_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemDefinitionIndex");
+        const ENTITY_LEVEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityLevel");
+        const ITEM_ID_HIGH: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemIDHigh");
+        const ITEM_ID_LOW: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iItemIDLow");
+        const ACCOUNT_ID: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iAccountID");
+        const ENTITY_QUALITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iEntityQuality");
+        const INITIALIZED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_bInitialized");
+        const ONLY_ITERATE_ITEM_VIEW_ATTRIBUTES: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_bOnlyIterateItemViewAttributes");
+        const TEAM_NUMBER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_ScriptCreatedItem", "m_iTeamNumber");
+        const OUTER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeContainer", "m_hOuter");
+        const PROVIDER_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeContainer", "m_ProviderType");
+        const REAPPLY_PROVISION_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_AttributeContainer", "m_iReapplyProvisionParity");
+        const CLIP_1: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iClip1");
+        const CLIP_2: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iClip2");
+        const PRIMARY_AMMO_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iPrimaryAmmoType");
+        const SECONDARY_AMMO_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_iSecondaryAmmoType");
+        const LOCAL_WEAPON_DATA_VIEW_MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_nViewModelIndex");
+        const CUSTOM_VIEWMODEL_MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_nCustomViewmodelModelIndex");
+        const FLIP_VIEW_MODEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalWeaponData", "m_bFlipViewModel");
+        const NEXT_PRIMARY_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flNextPrimaryAttack");
+        const NEXT_SECONDARY_ATTACK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flNextSecondaryAttack");
+        const NEXT_THINK_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_nNextThinkTick");
+        const TIME_WEAPON_IDLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalActiveWeaponData", "m_flTimeWeaponIdle");
+        const LAST_CRIT_CHECK_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flLastCritCheckTime");
+        const RELOAD_PRIOR_NEXT_FIRE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flReloadPriorNextFire");
+        const LAST_FIRE_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flLastFireTime");
+        const EFFECT_BAR_REGEN_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flEffectBarRegenTime");
+        const OBSERVED_CRIT_CHANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_LocalTFWeaponData", "m_flObservedCritChance");
+        const MAXS_PRE_SCALED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxsPreScaled");
+        const MINS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMins");
+        const MAXS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_vecMaxs");
+        const SOLID_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_nSolidType");
+        const INTERPOLATION_FRAME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_ubInterpolationFrame");
+        const MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nModelIndex");
+        const RENDER_FX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderFX");
+        const RENDER_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_nRenderMode");
+        const EFFECTS: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_fEffects");
+        const RENDER: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_clrRender");
+        const TEAM_NUM: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "m_iTeamNum");
+        const COLLISION_GROUP: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_CollisionGroup");
+        const ELASTICITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flElasticity");
+        const SHADOW_CAST_DISTANCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_flShadowCastDistance");
+        const OWNER_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hOwnerEntity");
+        const EFFECT_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_hEffectEntity");
+        const MOVEPARENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "moveparent");
+        const PARENT_ATTACHMENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iParentAttachment");
+        const MOVETYPE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseEntity", "movetype");
+        const MOVECOLLIDE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "movecollide");
+        const SOLID_FLAGS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_CollisionProperty", "m_usSolidFlags");
+        const TEXTURE_FRAME_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_iTextureFrameIndex");
+        const SIMULATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bSimulatedEveryTick");
+        const ANIMATED_EVERY_TICK: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAnimatedEveryTick");
+        const ALTERNATE_SORTING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseEntity", "m_bAlternateSorting");
+        const FORCE_BONE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nForceBone");
+        const FORCE: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_vecForce");
+        const SKIN: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nSkin");
+        const BODY: SendPropIdentifier = SendPropIdentifier::new("DT_BaseAnimating", "m_nBody");
+        const HITBOX_SET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nHitboxSet");
+        const MODEL_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flModelScale");
+        const SEQUENCE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nSequence");
+        const PLAYBACK_RATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flPlaybackRate");
+        const CLIENT_SIDE_ANIMATION: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideAnimation");
+        const CLIENT_SIDE_FRAME_RESET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_bClientSideFrameReset");
+        const NEW_SEQUENCE_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nNewSequenceParity");
+        const RESET_EVENTS_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nResetEventsParity");
+        const MUZZLE_FLASH_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_nMuzzleFlashParity");
+        const LIGHTING_ORIGIN: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOrigin");
+        const LIGHTING_ORIGIN_RELATIVE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_hLightingOriginRelative");
+        const FADE_MIN_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMinDist");
+        const FADE_MAX_DIST: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_fadeMaxDist");
+        const FADE_SCALE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseAnimating", "m_flFadeScale");
+        const VALIDATED_ATTACHED_ENTITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_EconEntity", "m_bValidatedAttachedEntity");
+        const BASE_COMBAT_WEAPON_VIEW_MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iViewModelIndex");
+        const WORLD_MODEL_INDEX: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iWorldModelIndex");
+        const STATE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_iState");
+        const OWNER: SendPropIdentifier =
+            SendPropIdentifier::new("DT_BaseCombatWeapon", "m_hOwner");
+        const LOWERED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bLowered");
+        const RESET_PARITY: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bResetParity");
+        const RELOAD_MODE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_iReloadMode");
+        const RELOADED_THROUGH_ANIM_EVENT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bReloadedThroughAnimEvent");
+        const DISGUISE_WEAPON: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bDisguiseWeapon");
+        const ENERGY: SendPropIdentifier = SendPropIdentifier::new("DT_TFWeaponBase", "m_flEnergy");
+        const EXTRA_WEARABLE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_hExtraWearable");
+        const EXTRA_WEARABLE_VIEW_MODEL: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_hExtraWearableViewModel");
+        const BEING_REPURPOSED_FOR_TAUNT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_bBeingRepurposedForTaunt");
+        const KILL_COMBO_CLASS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_nKillComboClass");
+        const KILL_COMBO_COUNT: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_nKillComboCount");
+        const INSPECT_ANIM_END_TIME: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_flInspectAnimEndTime");
+        const INSPECT_STAGE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_nInspectStage");
+        const CONSECUTIVE_SHOTS: SendPropIdentifier =
+            SendPropIdentifier::new("DT_TFWeaponBase", "m_iConsecutiveShots");
+        const HEALING_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_hHealingTarget");
+        const HEALING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bHealing");
+        const ATTACKING: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bAttacking");
+        const CHARGE_RELEASE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bChargeRelease");
+        const HOLSTERED: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_bHolstered");
+        const CHARGE_RESIST_TYPE: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_nChargeResistType");
+        const LAST_HEALING_TARGET: SendPropIdentifier =
+            SendPropIdentifier::new("DT_WeaponMedigun", "m_hLastHealingTarget");
+        match prop.identifier {
+            CYCLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.cycle = value;
+                }
+            }
+            LOCAL_TF_WEAPON_MEDIGUN_DATA_CHARGE_LEVEL => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.local_tf_weapon_medigun_data_charge_level = value;
+                }
+            }
+            TF_WEAPON_MEDIGUN_DATA_NON_LOCAL_CHARGE_LEVEL => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.tf_weapon_medigun_data_non_local_charge_level = value;
+                }
+            }
+            SIMULATION_TIME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.simulation_time = value;
+                }
+            }
+            ORIGIN => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.origin = value;
+                }
+            }
+            ROTATION => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.rotation = value;
+                }
+            }
+            SURROUND_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.surround_type = value;
+                }
+            }
+            TRIGGER_BLOAT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.trigger_bloat = value;
+                }
+            }
+            UNIFORM_TRIGGER_BLOAT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.uniform_trigger_bloat = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs_pre_scaled = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_mins = value;
+                }
+            }
+            SPECIFIED_SURROUNDING_MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.specified_surrounding_maxs = value;
+                }
+            }
+            PREDICTABLE_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.predictable_id = value;
+                }
+            }
+            IS_PLAYER_SIMULATED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.is_player_simulated = value;
+                }
+            }
+            MINS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins_pre_scaled = value;
+                }
+            }
+            ATTRIBUTE_LIST_ATTRIBUTES_LEN => match location {
+                Some(PropLocation {
+                    path: 9771355131433063680,
+                    ..
+                }) => {
+                    let value: Option<u8> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u8::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.attribute_list_attributes_len = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 13989459992870841021,
+                    ..
+                }) => {
+                    let value: Option<u8> = i64::try_from(&prop.value)
+                        .ok()
+                        .and_then(|value| u8::try_from(value).ok());
+                    if let Some(value) = value {
+                        self.networked_dynamic_attributes_for_demos_attributes_len = value;
+                    }
+                }
+                _ => {}
+            },
+            ATTRIBUTE_LIST_ATTRIBUTE_DEFINITION_INDEX => match location {
+                Some(PropLocation {
+                    path: 2517231263402970613,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.attribute_list_attribute_definition_index
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 13872821505359382006,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.networked_dynamic_attributes_for_demos_attribute_definition_index
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u16::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                _ => {}
+            },
+            ATTRIBUTE_LIST_RAW_VALUE_32 => match location {
+                Some(PropLocation {
+                    path: 2517231263402970613,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.attribute_list_raw_value_32
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 13872821505359382006,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.networked_dynamic_attributes_for_demos_raw_value_32
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                _ => {}
+            },
+            ATTRIBUTE_LIST_REFUNDABLE_CURRENCY => match location {
+                Some(PropLocation {
+                    path: 2517231263402970613,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.attribute_list_refundable_currency
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                Some(PropLocation {
+                    path: 13872821505359382006,
+                    element,
+                }) => {
+                    let field = element.and_then(|element| {
+                        self.networked_dynamic_attributes_for_demos_refundable_currency
+                            .get_mut(usize::from(element))
+                    });
+                    if let (Some(value), Some(field)) = (
+                        i64::try_from(&prop.value)
+                            .ok()
+                            .and_then(|value| u32::try_from(value).ok()),
+                        field,
+                    ) {
+                        *field = value;
+                    }
+                }
+                _ => {}
+            },
+            ITEM_DEFINITION_INDEX => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.item_definition_index = value;
+                }
+            }
+            ENTITY_LEVEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.entity_level = value;
+                }
+            }
+            ITEM_ID_HIGH => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.item_id_high = value;
+                }
+            }
+            ITEM_ID_LOW => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.item_id_low = value;
+                }
+            }
+            ACCOUNT_ID => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.account_id = value;
+                }
+            }
+            ENTITY_QUALITY => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.entity_quality = value;
+                }
+            }
+            INITIALIZED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.initialized = value;
+                }
+            }
+            ONLY_ITERATE_ITEM_VIEW_ATTRIBUTES => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.only_iterate_item_view_attributes = value;
+                }
+            }
+            TEAM_NUMBER => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_number = value;
+                }
+            }
+            OUTER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.outer = value;
+                }
+            }
+            PROVIDER_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.provider_type = value;
+                }
+            }
+            REAPPLY_PROVISION_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reapply_provision_parity = value;
+                }
+            }
+            CLIP_1 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.clip_1 = value;
+                }
+            }
+            CLIP_2 => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.clip_2 = value;
+                }
+            }
+            PRIMARY_AMMO_TYPE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.primary_ammo_type = value;
+                }
+            }
+            SECONDARY_AMMO_TYPE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.secondary_ammo_type = value;
+                }
+            }
+            LOCAL_WEAPON_DATA_VIEW_MODEL_INDEX => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.local_weapon_data_view_model_index = value;
+                }
+            }
+            CUSTOM_VIEWMODEL_MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.custom_viewmodel_model_index = value;
+                }
+            }
+            FLIP_VIEW_MODEL => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.flip_view_model = value;
+                }
+            }
+            NEXT_PRIMARY_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_primary_attack = value;
+                }
+            }
+            NEXT_SECONDARY_ATTACK => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.next_secondary_attack = value;
+                }
+            }
+            NEXT_THINK_TICK => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.next_think_tick = value;
+                }
+            }
+            TIME_WEAPON_IDLE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.time_weapon_idle = value;
+                }
+            }
+            LAST_CRIT_CHECK_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.last_crit_check_time = value;
+                }
+            }
+            RELOAD_PRIOR_NEXT_FIRE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.reload_prior_next_fire = value;
+                }
+            }
+            LAST_FIRE_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.last_fire_time = value;
+                }
+            }
+            EFFECT_BAR_REGEN_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.effect_bar_regen_time = value;
+                }
+            }
+            OBSERVED_CRIT_CHANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.observed_crit_chance = value;
+                }
+            }
+            MAXS_PRE_SCALED => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs_pre_scaled = value;
+                }
+            }
+            MINS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.mins = value;
+                }
+            }
+            MAXS => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.maxs = value;
+                }
+            }
+            SOLID_TYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_type = value;
+                }
+            }
+            INTERPOLATION_FRAME => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.interpolation_frame = value;
+                }
+            }
+            MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index = value;
+                }
+            }
+            RENDER_FX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_fx = value;
+                }
+            }
+            RENDER_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render_mode = value;
+                }
+            }
+            EFFECTS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.effects = value;
+                }
+            }
+            RENDER => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.render = value;
+                }
+            }
+            TEAM_NUM => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.team_num = value;
+                }
+            }
+            COLLISION_GROUP => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.collision_group = value;
+                }
+            }
+            ELASTICITY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.elasticity = value;
+                }
+            }
+            SHADOW_CAST_DISTANCE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.shadow_cast_distance = value;
+                }
+            }
+            OWNER_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner_entity = value;
+                }
+            }
+            EFFECT_ENTITY => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.effect_entity = value;
+                }
+            }
+            MOVEPARENT => {
+                let value: Option<u32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.moveparent = value;
+                }
+            }
+            PARENT_ATTACHMENT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.parent_attachment = value;
+                }
+            }
+            MOVETYPE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movetype = value;
+                }
+            }
+            MOVECOLLIDE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.movecollide = value;
+                }
+            }
+            SOLID_FLAGS => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.solid_flags = value;
+                }
+            }
+            TEXTURE_FRAME_INDEX => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.texture_frame_index = value;
+                }
+            }
+            SIMULATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.simulated_every_tick = value;
+                }
+            }
+            ANIMATED_EVERY_TICK => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.animated_every_tick = value;
+                }
+            }
+            ALTERNATE_SORTING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.alternate_sorting = value;
+                }
+            }
+            FORCE_BONE => {
+                let value: Option<i8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.force_bone = value;
+                }
+            }
+            FORCE => {
+                let value: Option<Vector> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.force = value;
+                }
+            }
+            SKIN => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.skin = value;
+                }
+            }
+            BODY => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.body = value;
+                }
+            }
+            HITBOX_SET => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.hitbox_set = value;
+                }
+            }
+            MODEL_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.model_scale = value;
+                }
+            }
+            SEQUENCE => {
+                let value: Option<u16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.sequence = value;
+                }
+            }
+            PLAYBACK_RATE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.playback_rate = value;
+                }
+            }
+            CLIENT_SIDE_ANIMATION => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_animation = value;
+                }
+            }
+            CLIENT_SIDE_FRAME_RESET => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.client_side_frame_reset = value;
+                }
+            }
+            NEW_SEQUENCE_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.new_sequence_parity = value;
+                }
+            }
+            RESET_EVENTS_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reset_events_parity = value;
+                }
+            }
+            MUZZLE_FLASH_PARITY => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.muzzle_flash_parity = value;
+                }
+            }
+            LIGHTING_ORIGIN => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin = value;
+                }
+            }
+            LIGHTING_ORIGIN_RELATIVE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.lighting_origin_relative = value;
+                }
+            }
+            FADE_MIN_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_min_dist = value;
+                }
+            }
+            FADE_MAX_DIST => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_max_dist = value;
+                }
+            }
+            FADE_SCALE => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.fade_scale = value;
+                }
+            }
+            VALIDATED_ATTACHED_ENTITY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.validated_attached_entity = value;
+                }
+            }
+            BASE_COMBAT_WEAPON_VIEW_MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.base_combat_weapon_view_model_index = value;
+                }
+            }
+            WORLD_MODEL_INDEX => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.world_model_index = value;
+                }
+            }
+            STATE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.state = value;
+                }
+            }
+            OWNER => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.owner = value;
+                }
+            }
+            LOWERED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.lowered = value;
+                }
+            }
+            RESET_PARITY => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.reset_parity = value;
+                }
+            }
+            RELOAD_MODE => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.reload_mode = value;
+                }
+            }
+            RELOADED_THROUGH_ANIM_EVENT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.reloaded_through_anim_event = value;
+                }
+            }
+            DISGUISE_WEAPON => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.disguise_weapon = value;
+                }
+            }
+            ENERGY => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.energy = value;
+                }
+            }
+            EXTRA_WEARABLE => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.extra_wearable = value;
+                }
+            }
+            EXTRA_WEARABLE_VIEW_MODEL => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.extra_wearable_view_model = value;
+                }
+            }
+            BEING_REPURPOSED_FOR_TAUNT => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.being_repurposed_for_taunt = value;
+                }
+            }
+            KILL_COMBO_CLASS => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kill_combo_class = value;
+                }
+            }
+            KILL_COMBO_COUNT => {
+                let value: Option<u8> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| u8::try_from(value).ok());
+                if let Some(value) = value {
+                    self.kill_combo_count = value;
+                }
+            }
+            INSPECT_ANIM_END_TIME => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.inspect_anim_end_time = value;
+                }
+            }
+            INSPECT_STAGE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.inspect_stage = value;
+                }
+            }
+            CONSECUTIVE_SHOTS => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.consecutive_shots = value;
+                }
+            }
+            HEALING_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.healing_target = value;
+                }
+            }
+            HEALING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.healing = value;
+                }
+            }
+            ATTACKING => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.attacking = value;
+                }
+            }
+            CHARGE_RELEASE => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.charge_release = value;
+                }
+            }
+            HOLSTERED => {
+                let value: Option<bool> = i64::try_from(&prop.value).ok().map(|value| value != 0);
+                if let Some(value) = value {
+                    self.holstered = value;
+                }
+            }
+            CHARGE_RESIST_TYPE => {
+                let value: Option<i32> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i32::try_from(value).ok());
+                if let Some(value) = value {
+                    self.charge_resist_type = value;
+                }
+            }
+            LAST_HEALING_TARGET => {
+                let value: Option<Handle> = i64::try_from(&prop.value).ok().map(Handle);
+                if let Some(value) = value {
+                    self.last_healing_target = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_000 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[0] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_001 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[1] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_002 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[2] = value;
+                }
+            }
+            MODEL_INDEX_OVERRIDES_003 => {
+                let value: Option<i16> = i64::try_from(&prop.value)
+                    .ok()
+                    .and_then(|value| i16::try_from(value).ok());
+                if let Some(value) = value {
+                    self.model_index_overrides[3] = value;
+                }
+            }
+            POSE_PARAMETER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[0] = value;
+                }
+            }
+            POSE_PARAMETER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[1] = value;
+                }
+            }
+            POSE_PARAMETER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[2] = value;
+                }
+            }
+            POSE_PARAMETER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[3] = value;
+                }
+            }
+            POSE_PARAMETER_004 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[4] = value;
+                }
+            }
+            POSE_PARAMETER_005 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[5] = value;
+                }
+            }
+            POSE_PARAMETER_006 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[6] = value;
+                }
+            }
+            POSE_PARAMETER_007 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[7] = value;
+                }
+            }
+            POSE_PARAMETER_008 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[8] = value;
+                }
+            }
+            POSE_PARAMETER_009 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[9] = value;
+                }
+            }
+            POSE_PARAMETER_010 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[10] = value;
+                }
+            }
+            POSE_PARAMETER_011 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[11] = value;
+                }
+            }
+            POSE_PARAMETER_012 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[12] = value;
+                }
+            }
+            POSE_PARAMETER_013 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[13] = value;
+                }
+            }
+            POSE_PARAMETER_014 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[14] = value;
+                }
+            }
+            POSE_PARAMETER_015 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[15] = value;
+                }
+            }
+            POSE_PARAMETER_016 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[16] = value;
+                }
+            }
+            POSE_PARAMETER_017 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[17] = value;
+                }
+            }
+            POSE_PARAMETER_018 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[18] = value;
+                }
+            }
+            POSE_PARAMETER_019 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[19] = value;
+                }
+            }
+            POSE_PARAMETER_020 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[20] = value;
+                }
+            }
+            POSE_PARAMETER_021 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[21] = value;
+                }
+            }
+            POSE_PARAMETER_022 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[22] = value;
+                }
+            }
+            POSE_PARAMETER_023 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.pose_parameter[23] = value;
+                }
+            }
+            ENCODED_CONTROLLER_000 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[0] = value;
+                }
+            }
+            ENCODED_CONTROLLER_001 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[1] = value;
+                }
+            }
+            ENCODED_CONTROLLER_002 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[2] = value;
+                }
+            }
+            ENCODED_CONTROLLER_003 => {
+                let value: Option<f32> = (&prop.value).try_into().ok();
+                if let Some(value) = value {
+                    self.encoded_controller[3] = value;
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -31,7 +31,7 @@ impl Eq for GameEventDefinition {}
 
 impl PartialOrd for GameEventDefinition {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.id.cmp(&other.id))
+        Some(self.cmp(other))
     }
 }
 
@@ -0,0 +1,268 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::usermessage::UserMessage;
+use crate::demo::message::Message;
+use crate::demo::packet::message::{MessagePacket, MessagePacketMeta, PacketPadding};
+use crate::demo::packet::Packet;
+use crate::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use crate::demo::writer::DemoWriter;
+use crate::{Demo, ParseError, Result};
+use bitbuffer::BitRead;
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io::{Read, Seek, Write};
+
+/// Write a demo as json
+///
+/// The json is an object with the `header` of the demo and a list of all `packets`, every packet
+/// is written on its own line. The packets are written while the demo is being parsed, so the full
+/// json never has to be kept in memory.
+///
+/// User messages are written as a `UserMessage` message with the user message in `user_message`,
+/// so the `type` of the message and the user message don't clash.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::json::demo_to_json;
+/// # use std::fs::File;
+/// # use std::io::BufWriter;
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// let file = std::fs::read("in.dem")?;
+/// demo_to_json(&Demo::new(&file), BufWriter::new(File::create("in.json")?))?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn demo_to_json<W: Write>(demo: &Demo, mut out: W) -> Result<W> {
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+
+    out.write_all(b"{\"header\":")?;
+    serde_json::to_writer(&mut out, &header)?;
+    out.write_all(b",\"packets\":[")?;
+    let mut separator: &[u8] = b"\n";
+    while let Some(packet) = packets.next(&handler.state_handler)? {
+        out.write_all(separator)?;
+        let packet = JsonPacket::from(packet);
+        serde_json::to_writer(&mut out, &packet)?;
+        handler.handle_packet(packet.into())?;
+        separator = b",\n";
+    }
+    out.write_all(b"\n]}\n")?;
+    out.flush()?;
+    Ok(out)
+}
+
+/// Encode a demo from json in the format written by [`demo_to_json`]
+///
+/// The packets are encoded as they are read, which requires the `header` to come before the
+/// `packets` in the json. Like with [`DemoWriter`], the tick count, frame count, duration and signon
+/// size in the header are updated to match the packets, so packets can be removed or added freely.
+///
+/// ```no_run
+/// # use tf_demo_parser::demo::json::json_to_demo;
+/// # use std::fs::File;
+/// # use std::io::BufReader;
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// let json = BufReader::new(File::open("edited.json")?);
+/// json_to_demo(json, File::create("edited.dem")?)?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn json_to_demo<R: Read, W: Write + Seek>(input: R, out: W) -> Result<W> {
+    let mut deserializer = serde_json::Deserializer::from_reader(input);
+    let mut write_error = None;
+    let visitor = DemoVisitor {
+        out,
+        error: &mut write_error,
+    };
+    let writer = match deserializer.deserialize_map(visitor) {
+        Ok(writer) => writer,
+        // report the original error when encoding a packet failed
+        Err(e) => return Err(write_error.unwrap_or(e.into())),
+    };
+    deserializer.end()?;
+    writer.finish()
+}
+
+struct DemoVisitor<'e, W> {
+    out: W,
+    error: &'e mut Option<ParseError>,
+}
+
+impl<'de, W: Write + Seek> Visitor<'de> for DemoVisitor<'_, W> {
+    type Value = DemoWriter<'static, W>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a demo with a header and packets")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(
+        self,
+        mut map: A,
+    ) -> std::result::Result<Self::Value, A::Error> {
+        let mut out = Some(self.out);
+        let mut writer = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "header" => {
+                    let header: Header = map.next_value()?;
+                    let out = out
+                        .take()
+                        .ok_or_else(|| de::Error::duplicate_field("header"))?;
+                    let new_writer =
+                        DemoWriter::new(out, header).map_err(|e| store_error(self.error, e))?;
+                    writer = Some(new_writer.update_header(true));
+                }
+                "packets" => {
+                    let writer = writer.as_mut().ok_or_else(|| {
+                        de::Error::custom("the header needs to come before the packets")
+                    })?;
+                    map.next_value_seed(PacketsSeed {
+                        writer,
+                        error: self.error,
+                    })?;
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        writer.ok_or_else(|| de::Error::missing_field("header"))
+    }
+}
+
+struct PacketsSeed<'w, 'e, W: Write + Seek> {
+    writer: &'w mut DemoWriter<'static, W>,
+    error: &'e mut Option<ParseError>,
+}
+
+impl<'de, W: Write + Seek> DeserializeSeed<'de> for PacketsSeed<'_, '_, W> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> std::result::Result<(), D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de, W: Write + Seek> Visitor<'de> for PacketsSeed<'_, '_, W> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a list of packets")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> std::result::Result<(), A::Error> {
+        while let Some(packet) = seq.next_element::<JsonPacket<'static>>()? {
+            self.writer
+                .write_packet(packet.into())
+                .map_err(|e| store_error(self.error, e))?;
+        }
+        Ok(())
+    }
+}
+
+fn store_error<E: de::Error>(slot: &mut Option<ParseError>, error: ParseError) -> E {
+    let de_error = E::custom(&error);
+    *slot = Some(error);
+    de_error
+}
+
+/// A packet as written to json, with the user messages moved out of the message tag
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "'a: 'static"))]
+#[serde(tag = "type")]
+enum JsonPacket<'a> {
+    Signon(JsonMessagePacket<'a>),
+    Message(JsonMessagePacket<'a>),
+    #[serde(untagged)]
+    Other(Packet<'a>),
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "'a: 'static"))]
+struct JsonMessagePacket<'a> {
+    tick: DemoTick,
+    messages: Vec<JsonMessage<'a>>,
+    meta: MessagePacketMeta,
+    #[serde(default)]
+    padding: PacketPadding,
+}
+
+/// Both `Message` and `UserMessage` are tagged by `type`, so the user message is nested
+#[derive(Serialize, Deserialize)]
+#[serde(bound(deserialize = "'a: 'static"))]
+#[serde(tag = "type")]
+enum JsonMessage<'a> {
+    UserMessage {
+        user_message: UserMessage<'a>,
+    },
+    #[serde(untagged)]
+    Other(Message<'a>),
+}
+
+impl<'a> From<Packet<'a>> for JsonPacket<'a> {
+    fn from(packet: Packet<'a>) -> Self {
+        match packet {
+            Packet::Signon(packet) => JsonPacket::Signon(packet.into()),
+            Packet::Message(packet) => JsonPacket::Message(packet.into()),
+            packet => JsonPacket::Other(packet),
+        }
+    }
+}
+
+impl<'a> From<JsonPacket<'a>> for Packet<'a> {
+    fn from(packet: JsonPacket<'a>) -> Self {
+        match packet {
+            JsonPacket::Signon(packet) => Packet::Signon(packet.into()),
+            JsonPacket::Message(packet) => Packet::Message(packet.into()),
+            JsonPacket::Other(packet) => packet,
+        }
+    }
+}
+
+impl<'a> From<MessagePacket<'a>> for JsonMessagePacket<'a> {
+    fn from(packet: MessagePacket<'a>) -> Self {
+        JsonMessagePacket {
+            tick: packet.tick,
+            messages: packet.messages.into_iter().map(JsonMessage::from).collect(),
+            meta: packet.meta,
+            padding: packet.padding,
+        }
+    }
+}
+
+impl<'a> From<JsonMessagePacket<'a>> for MessagePacket<'a> {
+    fn from(packet: JsonMessagePacket<'a>) -> Self {
+        MessagePacket {
+            tick: packet.tick,
+            messages: packet.messages.into_iter().map(Message::from).collect(),
+            meta: packet.meta,
+            padding: packet.padding,
+            data_remaining: 0,
+        }
+    }
+}
+
+impl<'a> From<Message<'a>> for JsonMessage<'a> {
+    fn from(message: Message<'a>) -> Self {
+        match message {
+            Message::UserMessage(user_message) => JsonMessage::UserMessage { user_message },
+            message => JsonMessage::Other(message),
+        }
+    }
+}
+
+impl<'a> From<JsonMessage<'a>> for Message<'a> {
+    fn from(message: JsonMessage<'a>) -> Self {
+        match message {
+            JsonMessage::UserMessage { user_message } => Message::UserMessage(user_message),
+            JsonMessage::Other(message) => message,
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::VecDeque;
+
 pub fn decompress(input: &[u8], output: &mut Vec<u8>) {
     decompress_(input, output);
 }
@@ -44,3 +46,118 @@ fn decompress_(input: &[u8], output: &mut Vec<u8>) -> Option<()> {
         }
     }
 }
+
+const WINDOW_SIZE: usize = 4096;
+const LOOKAHEAD: usize = 16;
+
+/// Compress data in the same way as the source engine
+///
+/// The output starts with the decompressed size, the same as the input for [`decompress`]
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(input.len() / 2 + 8);
+    output.extend_from_slice(&(input.len() as u32).to_le_bytes());
+
+    // for every byte value, the positions in the window starting with it, most recent first
+    let mut chains: Vec<VecDeque<usize>> = vec![VecDeque::new(); 256];
+    let mut cmd_pos = 0;
+    let mut cmd_bits = 0;
+    let mut pos = 0;
+
+    while let Some(&byte) = input.get(pos) {
+        if cmd_bits == 0 {
+            cmd_pos = output.len();
+            output.push(0);
+        }
+        cmd_bits = (cmd_bits + 1) & 0x07;
+
+        let lookahead = input.get(pos..).unwrap_or_default();
+        let lookahead = lookahead.get(..LOOKAHEAD).unwrap_or(lookahead);
+        let mut best_length = 0;
+        let mut best_pos = 0;
+        for &candidate in chains.get(byte as usize).into_iter().flatten() {
+            let length = input
+                .iter()
+                .skip(candidate)
+                .zip(lookahead)
+                .take_while(|(a, b)| a == b)
+                .count();
+            if length > best_length {
+                best_length = length;
+                best_pos = candidate;
+            }
+            if length == lookahead.len() {
+                break;
+            }
+        }
+
+        let cmd = output.get(cmd_pos).copied().unwrap_or_default() >> 1;
+        let length = if best_length >= 3 {
+            let offset = pos - best_pos - 1;
+            output.push((offset >> 4) as u8);
+            output.push(((offset << 4) as u8) | (best_length - 1) as u8);
+            set_byte(&mut output, cmd_pos, cmd | 0x80);
+            best_length
+        } else {
+            output.push(byte);
+            set_byte(&mut output, cmd_pos, cmd);
+            1
+        };
+
+        for _ in 0..length {
+            add_to_window(&mut chains, input, pos);
+            pos += 1;
+        }
+    }
+
+    // a match with length 1 marks the end of the data
+    if cmd_bits == 0 {
+        output.push(0x01);
+    } else {
+        let cmd = output.get(cmd_pos).copied().unwrap_or_default();
+        set_byte(&mut output, cmd_pos, ((cmd >> 1) | 0x80) >> (7 - cmd_bits));
+    }
+    output.extend_from_slice(&[0, 0]);
+    output
+}
+
+fn set_byte(output: &mut [u8], pos: usize, value: u8) {
+    if let Some(byte) = output.get_mut(pos) {
+        *byte = value;
+    }
+}
+
+fn add_to_window(chains: &mut [VecDeque<usize>], input: &[u8], pos: usize) {
+    // the position that falls out of the window is always the oldest in its chain
+    if let Some(old) = pos.checked_sub(WINDOW_SIZE) {
+        if let Some(chain) = input
+            .get(old)
+            .and_then(|byte| chains.get_mut(*byte as usize))
+        {
+            chain.pop_back();
+        }
+    }
+    if let Some(chain) = input
+        .get(pos)
+        .and_then(|byte| chains.get_mut(*byte as usize))
+    {
+        chain.push_front(pos);
+    }
+}
+
+#[test]
+fn test_lzss_roundtrip() {
+    let repeated: Vec<u8> = (0..20_000).map(|i| (i % 7 * 3 + i / 1000) as u8).collect();
+    let inputs: [&[u8]; 5] = [
+        b"",
+        b"a",
+        b"abcabcabcabcabcabcabcabc",
+        b"a longer input with some repetition, some repetition, some repetition",
+        &repeated,
+    ];
+    for input in inputs {
+        let compressed = compress(input);
+        let mut decompressed = Vec::new();
+        decompress(&compressed, &mut decompressed);
+        assert_eq!(input, decompressed.as_slice());
+    }
+}
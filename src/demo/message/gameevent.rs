@@ -70,6 +70,7 @@ impl Encode for GameEventMessage {
 #[test]
 fn test_game_event_roundtrip() {
     use crate::demo::gameevent_gen::{GameInitEvent, ServerShutdownEvent};
+    use std::sync::Arc;
 
     let definitions = vec![
         GameEventDefinition {
@@ -95,7 +96,7 @@ fn test_game_event_roundtrip() {
         },
     ];
     let mut state = ParserState::new(24, |_| false, false);
-    state.event_definitions = definitions;
+    state.event_definitions = Arc::new(definitions);
 
     crate::test_roundtrip_encode(
         GameEventMessage {
@@ -67,6 +67,39 @@ pub enum MessageType {
     CmdKeyValues = 32,
 }
 
+impl MessageType {
+    pub(crate) const ALL: [MessageType; 28] = [
+        MessageType::Empty,
+        MessageType::File,
+        MessageType::NetTick,
+        MessageType::StringCmd,
+        MessageType::SetConVar,
+        MessageType::SignOnState,
+        MessageType::Print,
+        MessageType::ServerInfo,
+        MessageType::ClassInfo,
+        MessageType::SetPause,
+        MessageType::CreateStringTable,
+        MessageType::UpdateStringTable,
+        MessageType::VoiceInit,
+        MessageType::VoiceData,
+        MessageType::ParseSounds,
+        MessageType::SetView,
+        MessageType::FixAngle,
+        MessageType::BspDecal,
+        MessageType::UserMessage,
+        MessageType::EntityMessage,
+        MessageType::GameEvent,
+        MessageType::PacketEntities,
+        MessageType::TempEntities,
+        MessageType::PreFetch,
+        MessageType::Menu,
+        MessageType::GameEventList,
+        MessageType::GetCvarValue,
+        MessageType::CmdKeyValues,
+    ];
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone)]
 #[serde(bound(deserialize = "'a: 'static"))]
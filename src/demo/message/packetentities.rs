@@ -1,6 +1,6 @@
 use bitbuffer::{
-    BitRead, BitReadSized, BitReadStream, BitWrite, BitWriteSized, BitWriteStream, Endianness,
-    LittleEndian,
+    BitRead, BitReadBuffer, BitReadSized, BitReadStream, BitWrite, BitWriteSized, BitWriteStream,
+    Endianness, LittleEndian,
 };
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
@@ -8,8 +8,8 @@ use std::borrow::Cow;
 
 use crate::demo::message::stringtable::log_base2;
 use crate::demo::packet::datatable::{ClassId, SendTable};
-use crate::demo::parser::{Encode, ParseBitSkip};
-use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::demo::parser::{Encode, ParseBitSkip, ParseObserver};
+use crate::demo::sendprop::{SendProp, SendPropDefinition, SendPropIdentifier, SendPropValue};
 use crate::{Parse, ParseError, ParserState, ReadResult, Result, Stream};
 use parse_display::{Display, FromStr};
 use std::cmp::{min, Ordering};
@@ -18,6 +18,7 @@ use std::collections::HashSet;
 use crate::demo::data::ServerTick;
 use itertools::Either;
 use std::fmt;
+use std::sync::Arc;
 #[cfg(feature = "trace")]
 use tracing::trace;
 
@@ -151,6 +152,91 @@ pub struct PacketEntity {
     pub delay: Option<f32>,
     pub delta: Option<ServerTick>,
     pub baseline_index: BaselineIndex,
+    /// Undecoded props of the update, only set when lazy prop decoding is enabled
+    ///
+    /// These are included by [`try_props`](Self::try_props) and [`get_prop_by_identifier`](Self::get_prop_by_identifier)
+    /// but not in the [`props`](Self::props) field until [`decode_props`](Self::decode_props) is called.
+    ///
+    /// Decoding needs the send tables from the parser state, so the lazy props are not serialized,
+    /// call [`decode_props`](Self::decode_props) first to include them.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub lazy_props: Option<LazyProps>,
+}
+
+/// Props of an entity update that are only decoded on demand
+///
+/// Only the index and position of the changed props are read while parsing, the values are decoded
+/// from a copy of the message data when requested.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LazyProps {
+    data: Arc<[u8]>,
+    props: Vec<LazyProp>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct LazyProp {
+    index: u32,
+    identifier: SendPropIdentifier,
+    offset: usize,
+}
+
+impl LazyProps {
+    pub fn len(&self) -> usize {
+        self.props.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.props.is_empty()
+    }
+
+    /// Indexes of the changed props in the flattened props of the send table
+    pub fn indexes(&self) -> impl Iterator<Item = u32> + '_ {
+        self.props.iter().map(|prop| prop.index)
+    }
+
+    /// Decode a single prop, if it's part of the update
+    pub fn get(
+        &self,
+        identifier: &SendPropIdentifier,
+        send_table: &SendTable,
+    ) -> Result<Option<SendProp>> {
+        self.props
+            .iter()
+            .find(|prop| prop.identifier == *identifier)
+            .map(|prop| self.decode_prop(prop, send_table))
+            .transpose()
+    }
+
+    /// Decode all props of the update
+    pub fn decode(&self, send_table: &SendTable) -> Result<Vec<SendProp>> {
+        self.props
+            .iter()
+            .map(|prop| self.decode_prop(prop, send_table))
+            .collect()
+    }
+
+    fn decode_prop(&self, prop: &LazyProp, send_table: &SendTable) -> Result<SendProp> {
+        let definition = get_prop_definition(send_table, prop.index as i32)?;
+        let mut stream = Stream::new(BitReadBuffer::new(&self.data, LittleEndian));
+        stream.set_pos(prop.offset)?;
+        Ok(SendProp {
+            index: prop.index,
+            identifier: prop.identifier,
+            value: SendPropValue::parse(&mut stream, &definition.parse_definition)?,
+        })
+    }
+}
+
+fn get_prop_definition(send_table: &SendTable, index: i32) -> Result<&SendPropDefinition> {
+    send_table
+        .flattened_props
+        .get(index as usize)
+        .ok_or_else(|| ParseError::PropIndexOutOfBounds {
+            index,
+            prop_count: send_table.flattened_props.len(),
+            table: send_table.name.to_string(),
+        })
 }
 
 impl fmt::Display for PacketEntity {
@@ -168,13 +254,52 @@ impl PacketEntity {
         self.props.iter_mut().find(|prop| prop.identifier == *index)
     }
 
+    /// Get a prop of the entity, including lazily decoded props and baseline props
+    ///
+    /// Lazily decoded props that fail to decode are treated as missing.
     pub fn get_prop_by_identifier(
         &self,
         index: &SendPropIdentifier,
         parser_state: &ParserState,
     ) -> Option<SendProp> {
-        self.props(parser_state)
-            .find(|prop| prop.identifier == *index)
+        if let Some(prop) = self.props.iter().find(|prop| prop.identifier == *index) {
+            return Some(prop.clone());
+        }
+        // only decode the requested prop
+        if let (Some(lazy_props), Some(send_table)) = (
+            &self.lazy_props,
+            parser_state.send_tables.get(usize::from(self.server_class)),
+        ) {
+            if let Ok(Some(prop)) = lazy_props.get(index, send_table) {
+                return Some(prop);
+            }
+        }
+        if self.update_type == UpdateType::Enter {
+            self.get_baseline_props(parser_state)
+                .iter()
+                .find(|prop| prop.identifier == *index)
+                .cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Decode any lazily decoded props into the [`props`](Self::props) field
+    pub fn decode_props(&mut self, parser_state: &ParserState) -> Result<()> {
+        if let Some(lazy_props) = self.lazy_props.take() {
+            let send_table = get_send_table(parser_state, self.server_class)?;
+            let props = lazy_props.decode(send_table)?;
+            self.apply_update(&props);
+        }
+        Ok(())
+    }
+
+    /// Decode the lazily decoded props of the update, without the baseline props
+    pub fn decode_lazy_props(&self, parser_state: &ParserState) -> Result<Vec<SendProp>> {
+        match &self.lazy_props {
+            Some(lazy_props) => lazy_props.decode(get_send_table(parser_state, self.server_class)?),
+            None => Ok(Vec::new()),
+        }
     }
 
     pub fn apply_update(&mut self, props: &[SendProp]) {
@@ -211,25 +336,52 @@ impl PacketEntity {
             .unwrap_or_default()
     }
 
+    /// All props of the entity, including lazily decoded props and, for entities entering the
+    /// pvs, the baseline props
+    #[deprecated(
+        note = "lazily decoded props that fail to decode are left out, use `try_props` instead"
+    )]
     pub fn props<'a>(
         &'a self,
         parser_state: &'a ParserState,
+    ) -> impl Iterator<Item = SendProp> + 'a {
+        let lazy_props = self.decode_lazy_props(parser_state).unwrap_or_default();
+        self.props_with(lazy_props, parser_state)
+    }
+
+    /// All props of the entity, including lazily decoded props and, for entities entering the
+    /// pvs, the baseline props
+    ///
+    /// Fails if any of the lazily decoded props can't be decoded
+    pub fn try_props<'a>(
+        &'a self,
+        parser_state: &'a ParserState,
+    ) -> Result<impl Iterator<Item = SendProp> + 'a> {
+        let lazy_props = self.decode_lazy_props(parser_state)?;
+        Ok(self.props_with(lazy_props, parser_state))
+    }
+
+    fn props_with<'a>(
+        &'a self,
+        lazy_props: Vec<SendProp>,
+        parser_state: &'a ParserState,
     ) -> impl Iterator<Item = SendProp> + 'a {
         if self.update_type == UpdateType::Enter {
-            let mut found_props = HashSet::<SendPropIdentifier>::new();
-            let props = self.props.iter().cloned();
-            #[allow(clippy::unnecessary_to_owned)]
-            let baseline_props = self
-                .get_baseline_props(parser_state)
-                .into_owned()
-                .into_iter();
-            Either::Left(props.chain(baseline_props).filter(move |prop| {
-                let found = found_props.contains(&prop.identifier);
-                found_props.insert(prop.identifier);
-                !found
-            }))
+            // props from sub tables that are included multiple times share their identifier,
+            // so the index is used to find the baseline props that were overwritten
+            let mut found_props = HashSet::<u32>::new();
+            let props = self.props.iter().cloned().chain(lazy_props);
+            let baseline_props = match self.get_baseline_props(parser_state) {
+                Cow::Borrowed(props) => Either::Left(props.iter().cloned()),
+                Cow::Owned(props) => Either::Right(props.into_iter()),
+            };
+            Either::Left(
+                props
+                    .chain(baseline_props)
+                    .filter(move |prop| found_props.insert(prop.index)),
+            )
         } else {
-            Either::Right(self.props.iter().cloned())
+            Either::Right(self.props.iter().cloned().chain(lazy_props))
         }
     }
 }
@@ -333,26 +485,45 @@ fn get_entity_for_update(
         delay: None,
         delta,
         baseline_index: BaselineIndex::First,
+        lazy_props: None,
     })
 }
 
 impl Parse<'_> for PacketEntitiesMessage {
     fn parse(stream: &mut Stream, state: &ParserState) -> Result<Self> {
+        Self::parse_observed(stream, state, &mut ())
+    }
+}
+
+impl PacketEntitiesMessage {
+    /// Parse a `PacketEntities` message, passing the size of the entity updates and decoded props to the observer
+    pub fn parse_observed<O: ParseObserver>(
+        stream: &mut Stream,
+        state: &ParserState,
+        observer: &mut O,
+    ) -> Result<Self> {
         let max_entries = stream.read_sized(11)?;
         let delta: Option<ServerTick> = stream.read()?;
         let base_line = stream.read()?;
         let updated_entries: u16 = stream.read_sized(11)?;
         let length: u32 = stream.read_sized(20)?;
-        let updated_base_line = stream.read()?;
+        let updated_base_line: bool = stream.read()?;
 
         let mut data = stream.read_bits(length as usize)?;
 
+        let lazy_data = if state.should_decode_lazily() {
+            Some(copy_bits(&data)?)
+        } else {
+            None
+        };
+
         let mut entities = Vec::with_capacity(min(updated_entries, 128) as usize);
         let mut removed_entities = Vec::new();
 
         let mut last_index: i32 = -1;
 
         for _ in 0..updated_entries {
+            let update_start = data.pos();
             let diff: u32 = read_bit_var(&mut data)?;
             last_index = last_index.saturating_add(diff as i32).saturating_add(1);
             if last_index >= 2048 {
@@ -361,24 +532,54 @@ impl Parse<'_> for PacketEntitiesMessage {
             let entity_index = EntityId::from(last_index as u32);
 
             let update_type = data.read()?;
-            if update_type == UpdateType::Enter {
+            let entity = if update_type == UpdateType::Enter {
                 let mut entity =
                     Self::read_enter(&mut data, entity_index, state, base_line, delta)?;
                 let send_table = get_send_table(state, entity.server_class)?;
-                Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
+                // entering entities are stored in the instance baseline when it's updated
+                match &lazy_data {
+                    _ if !state.should_decode_class(entity.server_class) => {
+                        Self::skip_update(&mut data, send_table)?
+                    }
+                    Some(lazy_data) if !updated_base_line => {
+                        entity.lazy_props =
+                            Some(Self::read_update_lazy(&mut data, send_table, lazy_data)?);
+                    }
+                    _ => Self::read_update_observed(
+                        &mut data,
+                        send_table,
+                        &mut entity.props,
+                        entity_index,
+                        observer,
+                    )?,
+                }
 
-                entities.push(entity);
+                entity
             } else if update_type == UpdateType::Preserve {
                 let mut entity = get_entity_for_update(state, entity_index, update_type, delta)?;
                 let send_table = get_send_table(state, entity.server_class)?;
 
-                Self::read_update(&mut data, send_table, &mut entity.props, entity_index)?;
+                match &lazy_data {
+                    _ if !state.should_decode_class(entity.server_class) => {
+                        Self::skip_update(&mut data, send_table)?
+                    }
+                    Some(lazy_data) => {
+                        entity.lazy_props =
+                            Some(Self::read_update_lazy(&mut data, send_table, lazy_data)?);
+                    }
+                    None => Self::read_update_observed(
+                        &mut data,
+                        send_table,
+                        &mut entity.props,
+                        entity_index,
+                        observer,
+                    )?,
+                }
                 entity.in_pvs = true;
 
-                entities.push(entity);
+                entity
             } else if state.entity_classes.contains_key(&entity_index) {
-                let entity = get_entity_for_update(state, entity_index, update_type, delta)?;
-                entities.push(entity);
+                get_entity_for_update(state, entity_index, update_type, delta)?
             } else {
                 entities.push(PacketEntity {
                     server_class: 0.into(),
@@ -390,8 +591,13 @@ impl Parse<'_> for PacketEntitiesMessage {
                     delay: None,
                     delta,
                     baseline_index: BaselineIndex::First,
+                    lazy_props: None,
                 });
-            }
+                continue;
+            };
+
+            observer.entity_update(entity.server_class, data.pos() - update_start);
+            entities.push(entity);
         }
 
         if delta.is_some() {
@@ -436,13 +642,18 @@ impl Encode for PacketEntitiesMessage {
                 entity.update_type.write(stream)?;
 
                 let send_table = get_send_table(state, entity.server_class)?;
+                let lazy_props = match &entity.lazy_props {
+                    Some(lazy_props) => lazy_props.decode(send_table)?,
+                    None => Vec::new(),
+                };
+                let props = entity.props.iter().chain(lazy_props.iter());
                 match entity.update_type {
                     UpdateType::Enter => {
                         Self::write_enter(entity, stream, state)?;
-                        Self::write_update(&entity.props, stream, send_table, entity.entity_index)?;
+                        Self::write_update(props, stream, send_table, entity.entity_index)?;
                     }
                     UpdateType::Preserve => {
-                        Self::write_update(&entity.props, stream, send_table, entity.entity_index)?;
+                        Self::write_update(props, stream, send_table, entity.entity_index)?;
                     }
                     _ => {}
                 }
@@ -486,6 +697,7 @@ impl PacketEntitiesMessage {
             delay: None,
             delta,
             baseline_index,
+            lazy_props: None,
         })
     }
 
@@ -506,8 +718,19 @@ impl PacketEntitiesMessage {
         send_table: &SendTable,
         props: &mut Vec<SendProp>,
         entity_index: EntityId,
+    ) -> Result<()> {
+        Self::read_update_observed(stream, send_table, props, entity_index, &mut ())
+    }
+
+    fn read_update_observed<O: ParseObserver>(
+        stream: &mut Stream,
+        send_table: &SendTable,
+        props: &mut Vec<SendProp>,
+        entity_index: EntityId,
+        observer: &mut O,
     ) -> Result<()> {
         let mut index: i32 = -1;
+        let mut prop_start = stream.pos();
 
         #[cfg(feature = "trace")]
         trace!(entity_index = display(entity_index), "reading update");
@@ -530,6 +753,8 @@ impl PacketEntitiesMessage {
                         definition = display(definition.identifier),
                         "reading prop"
                     );
+                    observer.prop(definition.identifier, stream.pos() - prop_start);
+                    prop_start = stream.pos();
                     props.push(SendProp {
                         index: index as u32,
                         identifier: definition.identifier,
@@ -549,6 +774,49 @@ impl PacketEntitiesMessage {
         Ok(())
     }
 
+    /// Read the prop indexes of an update, skipping over the values
+    fn read_update_lazy(
+        stream: &mut Stream,
+        send_table: &SendTable,
+        data: &Arc<[u8]>,
+    ) -> Result<LazyProps> {
+        let mut index: i32 = -1;
+        let mut props = Vec::new();
+
+        while stream.read()? {
+            let diff: u32 = read_bit_var(stream)?;
+            index = index.saturating_add(diff as i32).saturating_add(1);
+
+            let definition = get_prop_definition(send_table, index)?;
+            props.push(LazyProp {
+                index: index as u32,
+                identifier: definition.identifier,
+                offset: stream.pos(),
+            });
+            SendPropValue::skip(stream, &definition.parse_definition)?;
+        }
+
+        Ok(LazyProps {
+            data: Arc::clone(data),
+            props,
+        })
+    }
+
+    /// Skip over an update without reading the props
+    fn skip_update(stream: &mut Stream, send_table: &SendTable) -> Result<()> {
+        let mut index: i32 = -1;
+
+        while stream.read()? {
+            let diff: u32 = read_bit_var(stream)?;
+            index = index.saturating_add(diff as i32).saturating_add(1);
+
+            let definition = get_prop_definition(send_table, index)?;
+            SendPropValue::skip(stream, &definition.parse_definition)?;
+        }
+
+        Ok(())
+    }
+
     pub fn write_update<'a, Props: IntoIterator<Item = &'a SendProp>>(
         props: Props,
         stream: &mut BitWriteStream<LittleEndian>,
@@ -558,7 +826,7 @@ impl PacketEntitiesMessage {
         let mut last_index: i32 = -1;
 
         let mut props: Vec<&SendProp> = props.into_iter().collect();
-        props.sort_by(|a, b| a.index.cmp(&b.index));
+        props.sort_by_key(|prop| prop.index);
 
         for prop in props {
             true.write(stream)?;
@@ -583,6 +851,17 @@ impl PacketEntitiesMessage {
     }
 }
 
+/// Copy the bits of the stream into a byte aligned buffer
+fn copy_bits(stream: &Stream) -> Result<Arc<[u8]>> {
+    let mut stream = stream.clone();
+    let mut bytes = stream.read_bytes(stream.bits_left() / 8)?.into_owned();
+    let remaining = stream.bits_left();
+    if remaining > 0 {
+        bytes.push(stream.read_sized(remaining)?);
+    }
+    Ok(bytes.into())
+}
+
 impl ParseBitSkip<'_> for PacketEntitiesMessage {
     fn parse_skip(stream: &mut Stream, _state: &ParserState) -> Result<()> {
         stream.skip_bits(11)?;
@@ -601,9 +880,10 @@ impl ParseBitSkip<'_> for PacketEntitiesMessage {
 fn test_packet_entitier_message_roundtrip() {
     use crate::demo::packet::datatable::{SendTable, SendTableName, ServerClass, ServerClassName};
     use crate::demo::sendprop::{FloatDefinition, SendPropDefinition, SendPropParseDefinition};
+    use std::sync::Arc;
 
     let mut state = ParserState::new(24, |_| false, false);
-    state.server_classes = vec![
+    state.server_classes = Arc::new(vec![
         ServerClass {
             id: ClassId::from(0),
             name: ServerClassName::from("class1"),
@@ -614,8 +894,8 @@ fn test_packet_entitier_message_roundtrip() {
             name: ServerClassName::from("class2"),
             data_table: SendTableName::from("table2"),
         },
-    ];
-    state.send_tables = vec![
+    ]);
+    state.send_tables = Arc::new(vec![
         SendTable {
             name: SendTableName::from("table1"),
             needs_decoder: false,
@@ -647,7 +927,7 @@ fn test_packet_entitier_message_roundtrip() {
                 },
             ],
         },
-    ];
+    ]);
     state
         .entity_classes
         .insert(EntityId::from(4u32), ClassId::from(1));
@@ -674,6 +954,7 @@ fn test_packet_entitier_message_roundtrip() {
                 delay: None,
                 delta: None,
                 baseline_index: BaselineIndex::First,
+                lazy_props: None,
             }],
             removed_entities: vec![],
             max_entries: 4,
@@ -696,6 +977,7 @@ fn test_packet_entitier_message_roundtrip() {
                     delay: None,
                     delta: None,
                     baseline_index: BaselineIndex::First,
+                    lazy_props: None,
                 },
                 PacketEntity {
                     server_class: ClassId::from(1),
@@ -718,12 +1000,14 @@ fn test_packet_entitier_message_roundtrip() {
                     delay: None,
                     delta: None,
                     baseline_index: BaselineIndex::First,
+                    lazy_props: None,
                 },
                 PacketEntity {
                     server_class: ClassId::from(1),
                     entity_index: EntityId::from(5u32),
                     delta: None,
                     baseline_index: BaselineIndex::First,
+                    lazy_props: None,
                     props: vec![
                         SendProp {
                             index: 0,
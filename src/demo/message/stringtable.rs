@@ -5,11 +5,13 @@ use num_traits::{PrimInt, Unsigned};
 use serde::{Deserialize, Serialize};
 use snap::raw::{decompress_len, Decoder};
 
-use crate::demo::lzss::decompress;
+use crate::demo::lzss::{self, decompress};
 use crate::demo::packet::stringtable::{
-    ExtraData, FixedUserDataSize, StringTable, StringTableEntry,
+    CompressionMethod, ExtraData, FixedUserDataSize, StringTable, StringTableCompression,
+    StringTableEntry,
 };
 use crate::demo::parser::{Encode, ParseBitSkip};
+use crate::demo::snappy;
 use crate::{Parse, ParseError, ParserState, ReadResult, Result, Stream};
 use std::borrow::Cow;
 use std::cmp::min;
@@ -55,17 +57,19 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
 
         let mut table_data = stream.read_bits(length as usize)?;
 
+        let mut compression_method = None;
         if compressed {
             let decompressed_size: u32 = table_data.read()?;
             let compressed_size: u32 = table_data.read()?;
 
-            if !(4..=10 * 1024 * 1024).contains(&compressed_size) {
+            let limits = state.config().get_limits();
+            if !(4..=limits.max_compressed_size).contains(&compressed_size) {
                 return Err(ParseError::InvalidDemo(
                     "Invalid compressed string table size",
                 ));
             }
 
-            if decompressed_size > 100 * 1024 * 1024 {
+            if decompressed_size > limits.max_decompressed_size {
                 return Err(ParseError::InvalidDemo(
                     "Invalid decompressed string table size",
                 ));
@@ -95,6 +99,7 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
 
                     let buffer = BitReadBuffer::new_owned(decompressed_data, LittleEndian);
                     table_data = BitReadStream::new(buffer);
+                    compression_method = Some(CompressionMethod::Snappy);
                 }
                 "LZSS" => {
                     let compressed_data = table_data.read_bytes(compressed_size as usize - 4)?;
@@ -110,6 +115,7 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
 
                     let buffer = BitReadBuffer::new_owned(decompressed_data, LittleEndian);
                     table_data = BitReadStream::new(buffer);
+                    compression_method = Some(CompressionMethod::Lzss);
                 }
                 _ => {
                     return Err(ParseError::UnexpectedCompressionType(magic.into_owned()));
@@ -124,12 +130,27 @@ impl<'a> Parse<'a> for CreateStringTableMessage<'a> {
 
         let entries = parse_string_table_update(&mut table_data, &table_meta, entity_count)?;
 
+        let compression = match compression_method {
+            Some(method) => {
+                // the decompressed data is padded to a whole byte
+                let padding_bits = table_data.bits_left();
+                let padding = if padding_bits < 8 {
+                    table_data.read_sized(padding_bits)?
+                } else {
+                    0
+                };
+                Some(StringTableCompression { method, padding })
+            }
+            None => None,
+        };
+
         let table = StringTable {
             entries,
             max_entries,
             fixed_user_data_size: fixed_userdata_size,
             client_entries: None,
             compressed,
+            compression,
             name,
         };
         Ok(CreateStringTableMessage { table })
@@ -157,35 +178,57 @@ impl<'a> ParseBitSkip<'a> for CreateStringTableMessage<'a> {
 }
 
 impl Encode for CreateStringTableMessage<'_> {
-    fn encode(
-        &self,
-        stream: &mut BitWriteStream<LittleEndian>,
-        _state: &ParserState,
-    ) -> Result<()> {
+    fn encode(&self, stream: &mut BitWriteStream<LittleEndian>, state: &ParserState) -> Result<()> {
         let table = &self.table;
+        let compression = table.effective_compression();
         table.name.write(stream)?;
         table.max_entries.write(stream)?;
         let encode_bits = log_base2(table.max_entries) as usize;
         (table.entries.len() as u16).write_sized(stream, encode_bits + 1)?;
 
-        stream.reserve_int::<ParseError, _>(40, |stream| {
-            table.fixed_user_data_size.is_some().write(stream)?;
-            if let Some(fixed_size) = table.fixed_user_data_size {
-                fixed_size.write(stream)?;
+        let mut table_data = Vec::new();
+        let mut table_bits = {
+            let mut table_stream = BitWriteStream::new(&mut table_data, LittleEndian);
+            write_string_table_update(&table.entries, &mut table_stream, &table.get_table_meta())?;
+            if let Some(compression) = compression {
+                let padding_bits = (8 - table_stream.bit_len() % 8) % 8;
+                if padding_bits > 0 {
+                    let padding = compression.padding & ((1 << padding_bits) - 1);
+                    padding.write_sized(&mut table_stream, padding_bits)?;
+                }
             }
+            table_stream.bit_len()
+        };
 
-            // no compression for now
-            false.write(stream)?;
+        if let Some(compression) = compression {
+            let compressed_data = match compression.method {
+                CompressionMethod::Lzss => lzss::compress(&table_data),
+                CompressionMethod::Snappy => snappy::compress(&table_data),
+            };
+            let mut data = Vec::with_capacity(compressed_data.len() + 12);
+            data.extend_from_slice(&(table_data.len() as u32).to_le_bytes());
+            data.extend_from_slice(&(compressed_data.len() as u32 + 4).to_le_bytes());
+            data.extend_from_slice(compression.method.magic().as_bytes());
+            data.extend_from_slice(&compressed_data);
+            table_bits = data.len() * 8;
+            table_data = data;
+        }
 
-            let start = stream.bit_len();
+        if state.protocol_version > 23 {
+            write_var_int(table_bits as u32, stream)?;
+        } else {
+            (table_bits as u32).write_sized(stream, 20)?;
+        }
 
-            let table_meta = table.get_table_meta();
+        table.fixed_user_data_size.is_some().write(stream)?;
+        if let Some(fixed_size) = table.fixed_user_data_size {
+            fixed_size.write(stream)?;
+        }
 
-            write_string_table_update(&table.entries, stream, &table_meta)?;
+        compression.is_some().write(stream)?;
 
-            let end = stream.bit_len();
-            Ok(encode_var_int_fixed((end - start) as u32))
-        })?;
+        let mut table_stream = BitReadStream::new(BitReadBuffer::new(&table_data, LittleEndian));
+        stream.write_bits(&table_stream.read_bits(table_bits)?)?;
 
         Ok(())
     }
@@ -203,6 +246,7 @@ fn test_create_string_table_roundtrip() {
                 fixed_user_data_size: None,
                 client_entries: None,
                 compressed: false,
+                compression: None,
             },
         },
         &state,
@@ -231,10 +275,56 @@ fn test_create_string_table_roundtrip() {
                 fixed_user_data_size: Some(FixedUserDataSize { size: 12, bits: 4 }),
                 client_entries: None,
                 compressed: false,
+                compression: None,
             },
         },
         &state,
     );
+
+    for method in [CompressionMethod::Lzss, CompressionMethod::Snappy] {
+        crate::test_roundtrip_encode(
+            CreateStringTableMessage {
+                table: StringTable {
+                    name: "table1".into(),
+                    entries: (0..64)
+                        .map(|index| {
+                            (
+                                index,
+                                StringTableEntry {
+                                    text: Some(format!("models/props/crate{}.mdl", index).into()),
+                                    extra_data: None,
+                                },
+                            )
+                        })
+                        .collect(),
+                    max_entries: 128,
+                    fixed_user_data_size: None,
+                    client_entries: None,
+                    compressed: true,
+                    compression: Some(method.into()),
+                },
+            },
+            &state,
+        );
+    }
+
+    // tables that only set `compressed` are encoded with lzss
+    let table = StringTable {
+        name: "table1".into(),
+        entries: vec![(
+            0,
+            StringTableEntry {
+                text: Some("foo".into()),
+                extra_data: None,
+            },
+        )],
+        max_entries: 16,
+        fixed_user_data_size: None,
+        client_entries: None,
+        compressed: true,
+        compression: None,
+    };
+    crate::test_roundtrip_encode(CreateStringTableMessage { table }, &state);
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
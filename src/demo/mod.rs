@@ -1,16 +1,25 @@
 use bitbuffer::{BitReadBuffer, BitReadStream, LittleEndian};
 
+pub mod cut;
 pub mod data;
+pub mod entity_gen;
 pub mod gameevent_gen;
 pub mod gamevent;
 pub mod header;
+pub mod json;
 pub mod lzss;
 pub mod message;
 pub mod packet;
 pub mod parser;
+pub mod profile;
+pub mod repair;
 pub mod sendprop;
 mod sendprop_gen;
+pub mod snappy;
+pub mod transform;
+pub mod validate;
 pub mod vector;
+pub mod writer;
 
 pub type Buffer<'a> = BitReadBuffer<'a, LittleEndian>;
 pub type Stream<'a> = BitReadStream<'a, LittleEndian>;
@@ -1,3 +1,4 @@
+use crate::consthash::ConstFnvHash;
 use crate::demo::data::DemoTick;
 use crate::demo::parser::MalformedSendPropDefinitionError;
 use crate::demo::sendprop::{
@@ -318,19 +319,35 @@ fn test_parse_send_table_roundtrip() {
 
 impl ParseSendTable {
     pub fn flatten_props(&self, tables: &[ParseSendTable]) -> Result<Vec<SendPropDefinition>> {
+        Ok(self
+            .flatten_props_with_locations(tables)?
+            .into_iter()
+            .map(|(prop, _)| prop)
+            .collect())
+    }
+
+    /// Flatten the props, together with the location each prop was included from
+    ///
+    /// Sub tables that are included multiple times, like the elements of `m_AnimOverlay`, have
+    /// the same prop identifiers for every time they are included, the location tells them apart.
+    pub fn flatten_props_with_locations(
+        &self,
+        tables: &[ParseSendTable],
+    ) -> Result<Vec<(SendPropDefinition, PropLocation)>> {
         let mut flat = Vec::with_capacity(32);
         self.push_props_end(
             tables,
             &self.get_excludes(tables),
             &mut flat,
             &mut Vec::with_capacity(16),
+            PropLocation::default(),
         )?;
 
         // sort often changed props before the others
         let mut start = 0;
         for i in 0..flat.len() {
             #[allow(clippy::indexing_slicing)]
-            if flat[i].parse_definition.changes_often() {
+            if flat[i].0.parse_definition.changes_often() {
                 if i != start {
                     flat.swap(i, start);
                 }
@@ -376,12 +393,20 @@ impl ParseSendTable {
         &'a self,
         tables: &'a [ParseSendTable],
         excludes: &[SendPropIdentifier],
-        props: &mut Vec<SendPropDefinition>,
+        props: &mut Vec<(SendPropDefinition, PropLocation)>,
         table_stack: &mut Vec<&'a SendTableName>,
+        location: PropLocation,
     ) -> Result<()> {
         let mut local_props = Vec::new();
 
-        self.push_props_collapse(tables, excludes, &mut local_props, props, table_stack)?;
+        self.push_props_collapse(
+            tables,
+            excludes,
+            &mut local_props,
+            props,
+            table_stack,
+            location,
+        )?;
         props.extend_from_slice(&local_props);
         Ok(())
     }
@@ -390,9 +415,10 @@ impl ParseSendTable {
         &'a self,
         tables: &'a [ParseSendTable],
         excludes: &[SendPropIdentifier],
-        local_props: &mut Vec<SendPropDefinition>,
-        props: &mut Vec<SendPropDefinition>,
+        local_props: &mut Vec<(SendPropDefinition, PropLocation)>,
+        props: &mut Vec<(SendPropDefinition, PropLocation)>,
         table_stack: &mut Vec<&'a SendTableName>,
+        location: PropLocation,
     ) -> Result<()> {
         table_stack.push(&self.name);
 
@@ -404,6 +430,7 @@ impl ParseSendTable {
             .try_for_each(|prop| {
                 if let Some(table) = prop.get_data_table(tables) {
                     if !table_stack.contains(&&table.name) {
+                        let location = location.enter(prop.name.as_str());
                         if prop.flags.contains(SendPropFlag::Collapsible) {
                             table.push_props_collapse(
                                 tables,
@@ -411,13 +438,14 @@ impl ParseSendTable {
                                 local_props,
                                 props,
                                 table_stack,
+                                location,
                             )?;
                         } else {
-                            table.push_props_end(tables, excludes, props, table_stack)?;
+                            table.push_props_end(tables, excludes, props, table_stack, location)?;
                         }
                     }
                 } else {
-                    local_props.push(SendPropDefinition::try_from(prop)?);
+                    local_props.push((SendPropDefinition::try_from(prop)?, location));
                 }
                 Ok(())
             });
@@ -428,6 +456,40 @@ impl ParseSendTable {
     }
 }
 
+/// The data table props a flattened prop was included through
+///
+/// Arrays of sub tables include the same sub table once for every element, through a prop named
+/// after the index of the element, like `m_AnimOverlay.003`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct PropLocation {
+    /// Hash of the names of the data table props, leaving out the array element
+    pub path: u64,
+    /// The element of the array of sub tables the prop belongs to
+    pub element: Option<u16>,
+}
+
+impl PropLocation {
+    /// The location of the props included through the data table prop with the name
+    pub fn enter(self, name: &str) -> Self {
+        let path = ConstFnvHash::new().update(&self.path.to_le_bytes());
+        match (name.parse::<u16>(), self.element) {
+            (Ok(element), None) => PropLocation {
+                path: self.path,
+                element: Some(element),
+            },
+            // for nested arrays, the outer element becomes part of the path
+            (Ok(element), Some(outer)) => PropLocation {
+                path: path.push_string(&outer.to_string()).finish(),
+                element: Some(element),
+            },
+            (Err(_), element) => PropLocation {
+                path: path.push_string(name).finish(),
+                element,
+            },
+        }
+    }
+}
+
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SendTable {
@@ -1,11 +1,12 @@
-use bitbuffer::{BitRead, BitWrite, BitWriteStream, LittleEndian};
+use bitbuffer::{BitRead, BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
 use serde::{Deserialize, Serialize};
 
 use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::PacketEntitiesMessage;
 use crate::demo::message::{Message, MessageType};
-use crate::demo::parser::Encode;
+use crate::demo::parser::{Encode, ErrorContext, ParseObserver};
 use crate::demo::vector::Vector;
-use crate::{Parse, ParserState, Result, Stream};
+use crate::{Parse, ParseError, ParserState, Result, Stream};
 #[cfg(feature = "trace")]
 use tracing::{event, span, Level};
 
@@ -25,6 +26,25 @@ pub struct MessagePacket<'a> {
     pub tick: DemoTick,
     pub messages: Vec<Message<'a>>,
     pub meta: MessagePacketMeta,
+    #[serde(default)]
+    pub padding: PacketPadding,
+    /// The number of bits after the messages that are more than the padding to a whole byte
+    ///
+    /// These bits aren't part of any message and are not written when the packet is encoded.
+    #[serde(skip)]
+    #[cfg_attr(feature = "schema", schemars(skip))]
+    pub data_remaining: usize,
+}
+
+/// The bits after the last message in a packet
+///
+/// These are the padding to a whole byte and any empty messages, which are kept so an unmodified
+/// packet encodes to the exact bytes it was parsed from.
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy, Default)]
+pub struct PacketPadding {
+    pub bits: u8,
+    pub value: u64,
 }
 
 #[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
@@ -59,6 +79,17 @@ fn test_view_angles_roundtrip() {
 
 impl<'a> Parse<'a> for MessagePacket<'a> {
     fn parse(stream: &mut Stream<'a>, state: &ParserState) -> Result<Self> {
+        Self::parse_observed(stream, state, &mut ())
+    }
+}
+
+impl<'a> MessagePacket<'a> {
+    /// Parse a message packet, passing the messages and entity updates that are read to the observer
+    pub fn parse_observed<O: ParseObserver>(
+        stream: &mut Stream<'a>,
+        state: &ParserState,
+        observer: &mut O,
+    ) -> Result<Self> {
         let tick = stream.read()?;
 
         let meta = stream.read()?;
@@ -67,28 +98,74 @@ impl<'a> Parse<'a> for MessagePacket<'a> {
         let mut packet_data = stream.read_bits(length as usize * 8)?;
 
         let mut messages = Vec::with_capacity(8);
+        let mut messages_end = 0;
         while packet_data.bits_left() > 6 {
-            let message_type = MessageType::read(&mut packet_data)?;
+            let message_position = packet_data.pos();
+            let message_type = MessageType::read(&mut packet_data).map_err(|e| {
+                ParseError::from(e)
+                    .with_context(|context| context.message_position = Some(message_position))
+            })?;
+            let add_context = |context: &mut ErrorContext| {
+                context.message_type = Some(message_type);
+                context.message_position = Some(message_position);
+            };
             #[cfg(feature = "trace")]
             let _span =
                 span!(Level::DEBUG, "reading message", message_type = ?message_type, tick = ?tick)
                     .entered();
 
+            let message_data = packet_data.clone();
+            observer.start_message(message_type);
             if state.should_parse_message(message_type) && message_type != MessageType::Empty {
                 #[cfg(feature = "trace")]
                 event!(Level::TRACE, "parsing message");
-                messages.push(Message::from_type(message_type, &mut packet_data, state)?);
+                let message = match message_type {
+                    MessageType::PacketEntities => {
+                        PacketEntitiesMessage::parse_observed(&mut packet_data, state, observer)
+                            .map(Message::PacketEntities)
+                    }
+                    _ => Message::from_type(message_type, &mut packet_data, state),
+                }
+                .map_err(|e| e.with_context(add_context))?;
+                let bits = packet_data.pos() - message_position;
+                observer.end_message(message_type, Some(&message), &message_data, bits);
+                messages.push(message);
             } else {
                 #[cfg(feature = "trace")]
                 event!(Level::TRACE, "skipping message");
-                Message::skip_type(message_type, &mut packet_data, state)?;
+                Message::skip_type(message_type, &mut packet_data, state)
+                    .map_err(|e| e.with_context(add_context))?;
+                let bits = packet_data.pos() - message_position;
+                observer.end_message(message_type, None, &message_data, bits);
+            }
+            if message_type != MessageType::Empty {
+                messages_end = packet_data.pos();
             }
         }
 
+        // the bits after the messages that are more than the padding to a whole byte, which can
+        // include the start of a message type that doesn't fit in the packet
+        let data_remaining = packet_data
+            .bit_len()
+            .saturating_sub((messages_end + 6).next_multiple_of(8));
+
+        packet_data.set_pos(messages_end)?;
+        let padding_bits = packet_data.bits_left();
+        let padding = if (1..=64).contains(&padding_bits) {
+            PacketPadding {
+                bits: padding_bits as u8,
+                value: packet_data.read_sized(padding_bits)?,
+            }
+        } else {
+            PacketPadding::default()
+        };
+
         let packet = MessagePacket {
             tick,
             messages,
             meta,
+            padding,
+            data_remaining,
         };
         Ok(packet)
     }
@@ -99,11 +176,18 @@ impl Encode for MessagePacket<'_> {
         self.tick.write(stream)?;
         self.meta.write(stream)?;
         stream.reserve_byte_length(32, |stream| {
+            let start = stream.bit_len();
             for message in self.messages.iter() {
                 message.get_message_type().write(stream)?;
                 message.encode(stream, state)?;
             }
 
+            // the padding can only be re-used if it still ends on a whole byte
+            let padding = self.padding;
+            if padding.bits > 0 && (stream.bit_len() - start + padding.bits as usize) % 8 == 0 {
+                padding.value.write_sized(stream, padding.bits as usize)?;
+            }
+
             Ok(())
         })
     }
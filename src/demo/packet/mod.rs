@@ -10,7 +10,7 @@ use self::stringtable::StringTablePacket;
 use self::synctick::SyncTickPacket;
 use self::usercmd::UserCmdPacket;
 use crate::demo::data::DemoTick;
-use crate::demo::parser::Encode;
+use crate::demo::parser::{Encode, ParseObserver};
 use serde::{Deserialize, Serialize};
 #[cfg(feature = "trace")]
 use tracing::{event, span, Level};
@@ -98,19 +98,34 @@ impl Packet<'_> {
 
 impl<'a> Parse<'a> for Packet<'a> {
     fn parse(stream: &mut Stream<'a>, state: &ParserState) -> Result<Self> {
+        Self::parse_observed(stream, state, &mut ())
+    }
+}
+
+impl<'a> Packet<'a> {
+    /// Parse a packet, passing the messages and entity updates that are read to the observer
+    pub fn parse_observed<O: ParseObserver>(
+        stream: &mut Stream<'a>,
+        state: &ParserState,
+        observer: &mut O,
+    ) -> Result<Self> {
         let packet_type = PacketType::read(stream)?;
         #[cfg(feature = "trace")]
         {
-            let tick: u32 = stream.read()?;
-            stream.set_pos(stream.pos() - 32)?;
+            // the stop packet is shorter than 32 bits, so the tick can't always be read ahead
+            let tick = stream.clone().read::<u32>().ok();
             let _span =
-                span!(Level::INFO, "reading packet", packet_type = ?packet_type, tick = tick)
+                span!(Level::INFO, "reading packet", packet_type = ?packet_type, tick = ?tick)
                     .entered();
             event!(Level::DEBUG, "parsing packet");
         }
         Ok(match packet_type {
-            PacketType::Signon => Packet::Signon(MessagePacket::parse(stream, state)?),
-            PacketType::Message => Packet::Message(MessagePacket::parse(stream, state)?),
+            PacketType::Signon => {
+                Packet::Signon(MessagePacket::parse_observed(stream, state, observer)?)
+            }
+            PacketType::Message => {
+                Packet::Message(MessagePacket::parse_observed(stream, state, observer)?)
+            }
             PacketType::SyncTick => Packet::SyncTick(SyncTickPacket::parse(stream, state)?),
             PacketType::ConsoleCmd => Packet::ConsoleCmd(ConsoleCmdPacket::parse(stream, state)?),
             PacketType::UserCmd => Packet::UserCmd(UserCmdPacket::parse(stream, state)?),
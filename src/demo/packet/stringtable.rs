@@ -28,20 +28,71 @@ pub struct StringTable<'a> {
     pub fixed_user_data_size: Option<FixedUserDataSize>,
     pub client_entries: Option<Vec<StringTableEntry<'a>>>,
     pub compressed: bool,
+    /// The compression method of a compressed table, tables that are compressed without a method
+    /// set are encoded with lzss
+    #[serde(default)]
+    pub compression: Option<StringTableCompression>,
 }
 
 impl PartialEq for StringTable<'_> {
     fn eq(&self, other: &Self) -> bool {
-        // ignore `compresses` until we encode compressed
+        // ignore the padding bits from the compression, they don't carry any data
         self.name.eq(&other.name)
             && (self.entries.eq(&other.entries))
             && (self.max_entries.eq(&other.max_entries))
             && (self.fixed_user_data_size.eq(&other.fixed_user_data_size))
             && (self.client_entries.eq(&other.client_entries))
+            && (self
+                .effective_compression()
+                .map(|compression| compression.method)
+                == other
+                    .effective_compression()
+                    .map(|compression| compression.method))
+    }
+}
+
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressionMethod {
+    Lzss,
+    Snappy,
+}
+
+impl CompressionMethod {
+    pub fn magic(&self) -> &'static str {
+        match self {
+            CompressionMethod::Lzss => "LZSS",
+            CompressionMethod::Snappy => "SNAP",
+        }
+    }
+}
+
+/// How the data of a string table was compressed
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringTableCompression {
+    pub method: CompressionMethod,
+    /// The bits after the table data in the last byte of the decompressed data
+    #[serde(default)]
+    pub padding: u8,
+}
+
+impl From<CompressionMethod> for StringTableCompression {
+    fn from(method: CompressionMethod) -> Self {
+        StringTableCompression { method, padding: 0 }
     }
 }
 
 impl StringTable<'_> {
+    /// The compression used when encoding the table, `None` if the table isn't compressed
+    pub fn effective_compression(&self) -> Option<StringTableCompression> {
+        match (self.compressed, self.compression) {
+            (false, _) => None,
+            (true, Some(compression)) => Some(compression),
+            (true, None) => Some(CompressionMethod::Lzss.into()),
+        }
+    }
+
     pub fn get_table_meta(&self) -> StringTableMeta {
         StringTableMeta {
             fixed_userdata_size: self.fixed_user_data_size,
@@ -74,6 +125,7 @@ impl<'a> BitRead<'a, LittleEndian> for StringTable<'a> {
             fixed_user_data_size: None,
             client_entries,
             compressed: false,
+            compression: None,
         })
     }
 }
@@ -105,6 +157,7 @@ fn test_string_table_roundtrip() {
         fixed_user_data_size: None,
         client_entries: None,
         compressed: false,
+        compression: None,
     });
     crate::test_roundtrip_write(StringTable {
         name: "foo".into(),
@@ -119,6 +172,7 @@ fn test_string_table_roundtrip() {
         fixed_user_data_size: None,
         client_entries: None,
         compressed: false,
+        compression: None,
     });
     crate::test_roundtrip_write(StringTable {
         name: "foo".into(),
@@ -145,6 +199,7 @@ fn test_string_table_roundtrip() {
             extra_data: None,
         }]),
         compressed: false,
+        compression: None,
     });
 }
 
@@ -290,6 +345,7 @@ fn test_string_table_packet_roundtrip() {
                 fixed_user_data_size: None,
                 client_entries: None,
                 compressed: false,
+                compression: None,
             }],
         },
         &state,
@@ -311,6 +367,7 @@ fn test_string_table_packet_roundtrip() {
                     fixed_user_data_size: None,
                     client_entries: None,
                     compressed: false,
+                    compression: None,
                 },
                 StringTable {
                     name: "table2".into(),
@@ -337,6 +394,7 @@ fn test_string_table_packet_roundtrip() {
                         extra_data: None,
                     }]),
                     compressed: false,
+                    compression: None,
                 },
             ],
         },
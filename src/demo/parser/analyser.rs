@@ -376,14 +376,14 @@ pub struct World {
     pub boundary_max: Vector,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Analyser {
     state: MatchState,
     pause_start: Option<DemoTick>,
     user_id_map: HashMap<EntityId, UserId>,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct Pause {
     from: DemoTick,
     to: DemoTick,
@@ -405,10 +405,8 @@ impl MessageHandler for Analyser {
 
     fn handle_message(&mut self, message: &Message, tick: DemoTick, _parser_state: &ParserState) {
         match message {
-            Message::NetTick(msg) => {
-                if self.state.start_tick == 0 {
-                    self.state.start_tick = msg.tick;
-                }
+            Message::NetTick(msg) if self.state.start_tick == 0 => {
+                self.state.start_tick = msg.tick;
             }
             Message::ServerInfo(message) => {
                 self.state.interval_per_tick = message.interval_per_tick
@@ -475,12 +473,12 @@ impl Analyser {
                         .push(ChatMessage::from_message(text_message, tick));
                 }
             }
-            UserMessage::Text(text_message) => {
-                if text_message.location == HudTextLocation::PrintTalk {
-                    self.state
-                        .chat
-                        .push(ChatMessage::from_text(text_message, tick));
-                }
+            UserMessage::Text(text_message)
+                if text_message.location == HudTextLocation::PrintTalk =>
+            {
+                self.state
+                    .chat
+                    .push(ChatMessage::from_text(text_message, tick));
             }
             _ => {}
         }
@@ -504,10 +502,8 @@ impl Analyser {
                     user_state.team = spawn.team;
                 }
             }
-            GameEvent::TeamPlayRoundWin(event) => {
-                if event.win_reason != WIN_REASON_TIME_LIMIT {
-                    self.state.rounds.push(Round::from_event(event, tick))
-                }
+            GameEvent::TeamPlayRoundWin(event) if event.win_reason != WIN_REASON_TIME_LIMIT => {
+                self.state.rounds.push(Round::from_event(event, tick))
             }
             _ => {}
         }
@@ -535,7 +531,7 @@ impl Analyser {
     }
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct MatchState {
     pub chat: Vec<ChatMessage>,
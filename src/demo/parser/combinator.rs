@@ -0,0 +1,493 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::packetentities::PacketEntity;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
+use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::packet::stringtable::StringTableEntry;
+use crate::demo::parser::entities::{PropChange, PropSubscription};
+use crate::demo::parser::handler::{BorrowMessageHandler, MessageHandler};
+use crate::ParserState;
+use std::any::Any;
+
+/// Borrow the outputs of multiple handlers that are combined into a tuple
+///
+/// [`BorrowMessageHandler::borrow_output`] returns a reference to the `Output` of the handler, for
+/// a tuple of handlers that would be a reference to a tuple of outputs. The outputs are stored in
+/// the individual handlers instead of together in a tuple, so there is nothing for that reference
+/// to point to and a tuple of handlers can't implement [`BorrowMessageHandler`]. Instead this
+/// provides a tuple of references to the individual outputs, which is what
+/// [`DemoTicker::states`](crate::demo::parser::DemoTicker::states) returns.
+pub trait BorrowMessageHandlers: MessageHandler {
+    type Borrowed<'s>
+    where
+        Self: 's;
+
+    fn borrow_outputs<'s>(&'s self, state: &'s ParserState) -> Self::Borrowed<'s>;
+}
+
+macro_rules! impl_tuple_handler {
+    ($($name:ident => $index:tt),+) => {
+        /// Run multiple handlers in a single pass, every callback is forwarded to all handlers
+        impl<$($name: MessageHandler),+> MessageHandler for ($($name,)+) {
+            type Output = ($($name::Output,)+);
+
+            fn does_handle(message_type: MessageType) -> bool {
+                $($name::does_handle(message_type))||+
+            }
+
+            fn handle_header(&mut self, header: &Header) {
+                $(self.$index.handle_header(header);)+
+            }
+
+            fn handle_message(
+                &mut self,
+                message: &Message,
+                tick: DemoTick,
+                parser_state: &ParserState,
+            ) {
+                let message_type = message.get_message_type();
+                $(
+                    if $name::does_handle(message_type) {
+                        self.$index.handle_message(message, tick, parser_state);
+                    }
+                )+
+            }
+
+            fn handle_string_entry(
+                &mut self,
+                table: &str,
+                index: usize,
+                entry: &StringTableEntry,
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_string_entry(table, index, entry, parser_state);)+
+            }
+
+            fn handle_data_tables(
+                &mut self,
+                tables: &[ParseSendTable],
+                server_classes: &[ServerClass],
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_data_tables(tables, server_classes, parser_state);)+
+            }
+
+            fn handle_packet_meta(
+                &mut self,
+                tick: DemoTick,
+                meta: &MessagePacketMeta,
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_packet_meta(tick, meta, parser_state);)+
+            }
+
+            fn handle_entity_enter(
+                &mut self,
+                entity: &PacketEntity,
+                created: bool,
+                tick: DemoTick,
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_entity_enter(entity, created, tick, parser_state);)+
+            }
+
+            fn handle_entity_leave(
+                &mut self,
+                entity: &PacketEntity,
+                tick: DemoTick,
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_entity_leave(entity, tick, parser_state);)+
+            }
+
+            fn handle_entity_delete(
+                &mut self,
+                entity: &PacketEntity,
+                tick: DemoTick,
+                parser_state: &ParserState,
+            ) {
+                $(self.$index.handle_entity_delete(entity, tick, parser_state);)+
+            }
+
+            /// All classes are decoded if any handler doesn't filter the classes
+            fn entity_classes(&self) -> Option<Vec<String>> {
+                let mut classes = Vec::new();
+                $(classes.extend(self.$index.entity_classes()?);)+
+                Some(classes)
+            }
+
+            fn handled_message_types(&self) -> Option<Vec<MessageType>> {
+                if $(self.$index.handled_message_types().is_none())&&+ {
+                    return None;
+                }
+                let mut message_types = Vec::new();
+                $(message_types.extend(handled_message_types(&self.$index));)+
+                Some(message_types)
+            }
+
+            fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+                let mut subscriptions = Vec::new();
+                $(subscriptions.extend(
+                    self.$index
+                        .prop_subscriptions()
+                        .into_iter()
+                        .map(|subscription| subscription.through_handler($index)),
+                );)+
+                subscriptions
+            }
+
+            /// Prop changes are only forwarded to the handler that subscribed to the prop
+            fn handle_prop_change(&mut self, change: &PropChange, parser_state: &ParserState) {
+                match change.next_handler() {
+                    $(Some($index) => self.$index.handle_prop_change(change, parser_state),)+
+                    _ => {}
+                }
+            }
+
+            /// Parsing only stops once all handlers are done
+            fn is_done(&self) -> bool {
+                $(self.$index.is_done())&&+
+            }
+
+            fn into_output(self, state: &ParserState) -> Self::Output {
+                ($(self.$index.into_output(state),)+)
+            }
+        }
+
+        impl<$($name: BorrowMessageHandler),+> BorrowMessageHandlers for ($($name,)+) {
+            type Borrowed<'s> = ($(&'s $name::Output,)+) where Self: 's;
+
+            fn borrow_outputs<'s>(&'s self, state: &'s ParserState) -> Self::Borrowed<'s> {
+                ($(self.$index.borrow_output(state),)+)
+            }
+        }
+    };
+}
+
+/// The message types handled by the handler instance
+fn handled_message_types<T: MessageHandler>(handler: &T) -> Vec<MessageType> {
+    handler.handled_message_types().unwrap_or_else(|| {
+        MessageType::ALL
+            .into_iter()
+            .filter(|message_type| T::does_handle(*message_type))
+            .collect()
+    })
+}
+
+impl_tuple_handler!(A => 0, B => 1);
+impl_tuple_handler!(A => 0, B => 1, C => 2);
+impl_tuple_handler!(A => 0, B => 1, C => 2, D => 3);
+impl_tuple_handler!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_tuple_handler!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
+/// Object safe version of [`MessageHandler`], allowing a dynamic list of handlers to be combined
+///
+/// This is implemented for every [`MessageHandler`] with a `'static` output, the output is returned
+/// as `Box<dyn Any>` and can be downcast back into the concrete output type.
+pub trait DynMessageHandler {
+    fn does_handle(&self, message_type: MessageType) -> bool;
+
+    fn handle_header(&mut self, header: &Header);
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState);
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    );
+
+    fn handle_data_tables(
+        &mut self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    );
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    );
+
+    fn handle_entity_enter(
+        &mut self,
+        entity: &PacketEntity,
+        created: bool,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    );
+
+    fn handle_entity_leave(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    );
+
+    fn handle_entity_delete(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    );
+
+    fn entity_classes(&self) -> Option<Vec<String>>;
+
+    fn handled_message_types(&self) -> Vec<MessageType>;
+
+    fn prop_subscriptions(&self) -> Vec<PropSubscription>;
+
+    fn handle_prop_change(&mut self, change: &PropChange, parser_state: &ParserState);
+
+    fn is_done(&self) -> bool;
+
+    fn into_output(self: Box<Self>, state: &ParserState) -> Box<dyn Any>;
+}
+
+impl<T: MessageHandler> DynMessageHandler for T
+where
+    T::Output: 'static,
+{
+    fn does_handle(&self, message_type: MessageType) -> bool {
+        T::does_handle(message_type)
+    }
+
+    fn handle_header(&mut self, header: &Header) {
+        MessageHandler::handle_header(self, header)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        MessageHandler::handle_message(self, message, tick, parser_state)
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_string_entry(self, table, index, entry, parser_state)
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_data_tables(self, tables, server_classes, parser_state)
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_packet_meta(self, tick, meta, parser_state)
+    }
+
+    fn handle_entity_enter(
+        &mut self,
+        entity: &PacketEntity,
+        created: bool,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_entity_enter(self, entity, created, tick, parser_state)
+    }
+
+    fn handle_entity_leave(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_entity_leave(self, entity, tick, parser_state)
+    }
+
+    fn handle_entity_delete(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        MessageHandler::handle_entity_delete(self, entity, tick, parser_state)
+    }
+
+    fn entity_classes(&self) -> Option<Vec<String>> {
+        MessageHandler::entity_classes(self)
+    }
+
+    fn handled_message_types(&self) -> Vec<MessageType> {
+        handled_message_types(self)
+    }
+
+    fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+        MessageHandler::prop_subscriptions(self)
+    }
+
+    fn handle_prop_change(&mut self, change: &PropChange, parser_state: &ParserState) {
+        MessageHandler::handle_prop_change(self, change, parser_state)
+    }
+
+    fn is_done(&self) -> bool {
+        MessageHandler::is_done(self)
+    }
+
+    fn into_output(self: Box<Self>, state: &ParserState) -> Box<dyn Any> {
+        Box::new(MessageHandler::into_output(*self, state))
+    }
+}
+
+/// Run a dynamic list of handlers in a single pass
+///
+/// Because the list of handlers is only known at runtime, the static [`MessageHandler::does_handle`]
+/// can't know which messages are handled and accepts everything. The parser instead uses
+/// [`MessageHandler::handled_message_types`], so only the messages handled by one of the boxed handlers
+/// are parsed and each handler only receives the message types it handles.
+///
+/// When the list is nested in a tuple of handlers the tuple does the same, other wrappers that only
+/// look at `does_handle` will parse every message.
+impl MessageHandler for Vec<Box<dyn DynMessageHandler>> {
+    type Output = Vec<Box<dyn Any>>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        true
+    }
+
+    fn handled_message_types(&self) -> Option<Vec<MessageType>> {
+        Some(
+            self.iter()
+                .flat_map(|handler| handler.handled_message_types())
+                .collect(),
+        )
+    }
+
+    fn handle_header(&mut self, header: &Header) {
+        for handler in self.iter_mut() {
+            handler.handle_header(header);
+        }
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        let message_type = message.get_message_type();
+        for handler in self.iter_mut() {
+            if handler.does_handle(message_type) {
+                handler.handle_message(message, tick, parser_state);
+            }
+        }
+    }
+
+    fn handle_string_entry(
+        &mut self,
+        table: &str,
+        index: usize,
+        entry: &StringTableEntry,
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_string_entry(table, index, entry, parser_state);
+        }
+    }
+
+    fn handle_data_tables(
+        &mut self,
+        tables: &[ParseSendTable],
+        server_classes: &[ServerClass],
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_data_tables(tables, server_classes, parser_state);
+        }
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        meta: &MessagePacketMeta,
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_packet_meta(tick, meta, parser_state);
+        }
+    }
+
+    fn handle_entity_enter(
+        &mut self,
+        entity: &PacketEntity,
+        created: bool,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_entity_enter(entity, created, tick, parser_state);
+        }
+    }
+
+    fn handle_entity_leave(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_entity_leave(entity, tick, parser_state);
+        }
+    }
+
+    fn handle_entity_delete(
+        &mut self,
+        entity: &PacketEntity,
+        tick: DemoTick,
+        parser_state: &ParserState,
+    ) {
+        for handler in self.iter_mut() {
+            handler.handle_entity_delete(entity, tick, parser_state);
+        }
+    }
+
+    /// All classes are decoded if any handler doesn't filter the classes
+    fn entity_classes(&self) -> Option<Vec<String>> {
+        let mut classes = Vec::new();
+        for handler in self.iter() {
+            classes.extend(handler.entity_classes()?);
+        }
+        Some(classes)
+    }
+
+    fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+        self.iter()
+            .enumerate()
+            .flat_map(|(index, handler)| {
+                handler
+                    .prop_subscriptions()
+                    .into_iter()
+                    .map(move |subscription| subscription.through_handler(index))
+            })
+            .collect()
+    }
+
+    /// Prop changes are only forwarded to the handler that subscribed to the prop
+    fn handle_prop_change(&mut self, change: &PropChange, parser_state: &ParserState) {
+        if let Some(handler) = change.next_handler().and_then(|index| self.get_mut(index)) {
+            handler.handle_prop_change(change, parser_state);
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        !self.is_empty() && self.iter().all(|handler| handler.is_done())
+    }
+
+    fn into_output(self, state: &ParserState) -> Self::Output {
+        self.into_iter()
+            .map(|handler| handler.into_output(state))
+            .collect()
+    }
+}
@@ -0,0 +1,225 @@
+use crate::demo::message::MessageType;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Protocol version used until the demo header has been read
+pub const DEFAULT_PROTOCOL_VERSION: u32 = 24;
+
+/// Configuration for a [`DemoParser`](crate::DemoParser)
+///
+/// ```
+/// use tf_demo_parser::demo::parser::ParserConfig;
+/// use tf_demo_parser::MessageType;
+///
+/// let config = ParserConfig::new()
+///     .parse_all(true)
+///     .decode_entities(false)
+///     .skip_messages([MessageType::VoiceData, MessageType::TempEntities])
+///     .lenient(true);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ParserConfig {
+    parse_all: bool,
+    decode_entities: Option<bool>,
+    lenient: bool,
+    limits: ParserLimits,
+    parse_messages: MessageTypeSet,
+    skip_messages: MessageTypeSet,
+    track_entities: bool,
+    lazy_props: bool,
+    protocol_version: Option<u32>,
+    cancel_token: Option<CancelToken>,
+}
+
+impl ParserConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse all messages, not just the ones required by the analyser
+    pub fn parse_all(mut self, parse_all: bool) -> Self {
+        self.parse_all = parse_all;
+        self
+    }
+
+    /// Enable or disable decoding of entities
+    ///
+    /// By default entities are only decoded if the analyser handles `PacketEntities` messages or
+    /// all messages are parsed. Since entity decoding is by far the most expensive part of parsing
+    /// a demo, disabling it can greatly speed up parsing when no entity data is needed.
+    pub fn decode_entities(mut self, decode_entities: bool) -> Self {
+        self.decode_entities = Some(decode_entities);
+        self
+    }
+
+    /// Skip damaged packets instead of stopping at the first error
+    ///
+    /// See [`DemoTicker::set_lenient`](crate::demo::parser::DemoTicker::set_lenient)
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Set the resource limits used while parsing
+    pub fn limits(mut self, limits: ParserLimits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Always parse the specified message types, even if the analyser doesn't handle them
+    pub fn parse_messages(mut self, message_types: impl IntoIterator<Item = MessageType>) -> Self {
+        self.parse_messages.extend(message_types);
+        self
+    }
+
+    /// Never parse the specified message types, even if the analyser handles them or all messages are parsed
+    ///
+    /// Messages required by the parser itself, like string table updates, are always parsed.
+    pub fn skip_messages(mut self, message_types: impl IntoIterator<Item = MessageType>) -> Self {
+        self.skip_messages.extend(message_types);
+        self
+    }
+
+    /// Keep the full current state of every entity in the [`EntityStore`](crate::demo::parser::entities::EntityStore)
+    ///
+    /// Enabling this also enables entity decoding, unless it's explicitly disabled.
+    pub fn track_entities(mut self, track_entities: bool) -> Self {
+        self.track_entities = track_entities;
+        self
+    }
+
+    /// Only decode the values of entity props when they are requested
+    ///
+    /// Entity updates keep the undecoded prop data in [`PacketEntity::lazy_props`](crate::demo::message::packetentities::PacketEntity::lazy_props),
+    /// which is decoded by [`PacketEntity::try_props`](crate::demo::message::packetentities::PacketEntity::try_props)
+    /// and [`PacketEntity::get_prop_by_identifier`](crate::demo::message::packetentities::PacketEntity::get_prop_by_identifier).
+    /// This speeds up analysers that only look at a few props, at the cost of decoding props
+    /// multiple times if they are requested repeatedly.
+    ///
+    /// Lazy decoding is disabled when entity tracking is enabled, since that needs all props anyway.
+    pub fn lazy_props(mut self, lazy_props: bool) -> Self {
+        self.lazy_props = lazy_props;
+        self
+    }
+
+    /// Use the specified protocol version instead of the one from the demo header
+    pub fn protocol_version(mut self, protocol_version: u32) -> Self {
+        self.protocol_version = Some(protocol_version);
+        self
+    }
+
+    /// Stop parsing once the token is cancelled
+    pub fn cancel_token(mut self, token: CancelToken) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
+    pub fn is_parse_all(&self) -> bool {
+        self.parse_all
+    }
+
+    /// Whether entities should be decoded, `None` if it depends on the analyser
+    pub fn should_decode_entities(&self) -> Option<bool> {
+        self.decode_entities
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        self.lenient
+    }
+
+    pub fn get_limits(&self) -> &ParserLimits {
+        &self.limits
+    }
+
+    pub fn is_parsed(&self, message_type: MessageType) -> bool {
+        self.parse_messages.contains(message_type)
+    }
+
+    pub fn is_skipped(&self, message_type: MessageType) -> bool {
+        self.skip_messages.contains(message_type)
+    }
+
+    pub fn is_tracking_entities(&self) -> bool {
+        self.track_entities
+    }
+
+    pub fn is_lazy_props(&self) -> bool {
+        self.lazy_props
+    }
+
+    /// The protocol version overwriting the version from the demo header, if set
+    pub fn get_protocol_version(&self) -> Option<u32> {
+        self.protocol_version
+    }
+
+    pub fn get_cancel_token(&self) -> Option<&CancelToken> {
+        self.cancel_token.as_ref()
+    }
+}
+
+/// Token to stop a running parse from another thread
+///
+/// Once cancelled, the parser stops before the next packet and the analyser output is produced
+/// from the packets handled so far.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Limits to protect against malformed or malicious demos
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParserLimits {
+    /// Maximum size in bytes of the data of a single packet
+    pub max_packet_size: Option<u32>,
+    /// Maximum size in bytes of compressed string table data
+    pub max_compressed_size: u32,
+    /// Maximum size in bytes of string table data after decompression
+    pub max_decompressed_size: u32,
+}
+
+impl Default for ParserLimits {
+    fn default() -> Self {
+        ParserLimits {
+            max_packet_size: None,
+            max_compressed_size: 10 * 1024 * 1024,
+            max_decompressed_size: 100 * 1024 * 1024,
+        }
+    }
+}
+
+/// Set of message types, stored as a bitmask of the 6 bit message type ids
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct MessageTypeSet(u64);
+
+impl MessageTypeSet {
+    pub(crate) fn contains(&self, message_type: MessageType) -> bool {
+        self.0 & (1 << message_type as u8) != 0
+    }
+
+    pub(crate) fn extend(&mut self, message_types: impl IntoIterator<Item = MessageType>) {
+        for message_type in message_types {
+            self.0 |= 1 << message_type as u8;
+        }
+    }
+}
+
+#[test]
+fn test_message_type_set() {
+    let mut set = MessageTypeSet::default();
+    set.extend([MessageType::Empty, MessageType::CmdKeyValues]);
+    assert!(set.contains(MessageType::Empty));
+    assert!(set.contains(MessageType::CmdKeyValues));
+    assert!(!set.contains(MessageType::PacketEntities));
+}
@@ -0,0 +1,305 @@
+use crate::demo::data::DemoTick;
+use crate::demo::message::packetentities::{
+    EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use crate::demo::packet::datatable::{ClassId, ServerClass};
+use crate::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use crate::nullhasher::NullHasherBuilder;
+use crate::ParserState;
+use std::cell::Cell;
+use std::collections::HashMap;
+#[cfg(feature = "trace")]
+use tracing::warn;
+
+/// The complete current state of every entity in the demo
+///
+/// Unlike the entities in a [`PacketEntitiesMessage`], which only contain the changed props, the
+/// stored entities contain the full set of props with the baseline and all updates applied.
+///
+/// Entity tracking is opt-in through [`ParserConfig::track_entities`](crate::demo::parser::ParserConfig::track_entities),
+/// the store is updated before the `PacketEntities` message is passed to the analyser.
+#[derive(Debug, Clone, Default)]
+pub struct EntityStore {
+    entities: HashMap<EntityId, PacketEntity, NullHasherBuilder>,
+    subscriptions: Vec<PropSubscription>,
+    /// Subscribed props and the handlers subscribed to them by the class id of the subscribed
+    /// server classes
+    subscribed: HashMap<ClassId, Vec<(SendPropIdentifier, HandlerRoute)>, NullHasherBuilder>,
+    changes: Vec<PropChange>,
+}
+
+/// Interest in changes of a prop for all entities of a server class
+///
+/// See [`MessageHandler::prop_subscriptions`](crate::demo::parser::MessageHandler::prop_subscriptions)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PropSubscription {
+    pub class_name: String,
+    pub identifier: SendPropIdentifier,
+    pub(crate) route: HandlerRoute,
+}
+
+impl PropSubscription {
+    pub fn new(class_name: impl Into<String>, identifier: SendPropIdentifier) -> Self {
+        PropSubscription {
+            class_name: class_name.into(),
+            identifier,
+            route: HandlerRoute::default(),
+        }
+    }
+
+    /// Mark the subscription as coming from the handler at `index` of a combined handler
+    pub(crate) fn through_handler(mut self, index: usize) -> Self {
+        self.route = self.route.enter(index);
+        self
+    }
+}
+
+/// The indexes of the handlers leading through nested combined handlers to the handler that made
+/// a subscription
+///
+/// The route is resolved once when the parser is created, so combined handlers can forward a
+/// change without asking their handlers for their subscriptions again.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct HandlerRoute {
+    /// The index at every level of combined handlers, 16 bits per level with the outermost level
+    /// in the lowest bits
+    indexes: u128,
+    depth: u32,
+}
+
+impl HandlerRoute {
+    const BITS: u32 = 16;
+
+    /// Add a level of combined handlers around the route
+    fn enter(self, index: usize) -> Self {
+        debug_assert!(index < 1 << Self::BITS && (self.depth + 1) * Self::BITS <= u128::BITS);
+        HandlerRoute {
+            indexes: (self.indexes << Self::BITS) | index as u128,
+            depth: self.depth + 1,
+        }
+    }
+
+    /// Split of the index for the outermost level of combined handlers
+    fn next(self) -> Option<(usize, Self)> {
+        let index = (self.indexes & ((1 << Self::BITS) - 1)) as usize;
+        let depth = self.depth.checked_sub(1)?;
+        Some((
+            index,
+            HandlerRoute {
+                indexes: self.indexes >> Self::BITS,
+                depth,
+            },
+        ))
+    }
+}
+
+/// A change in the value of a subscribed prop
+#[derive(Debug, Clone)]
+pub struct PropChange {
+    pub entity_id: EntityId,
+    pub server_class: ClassId,
+    pub tick: DemoTick,
+    pub identifier: SendPropIdentifier,
+    /// The previous value, `None` if the prop wasn't set before, for example for newly created entities
+    pub old: Option<SendPropValue>,
+    pub new: SendPropValue,
+    /// The remaining route to the handler that subscribed to the prop
+    route: Cell<HandlerRoute>,
+}
+
+impl PropChange {
+    /// Take the index of the handler to forward the change to from a combined handler
+    pub(crate) fn next_handler(&self) -> Option<usize> {
+        let (index, route) = self.route.get().next()?;
+        self.route.set(route);
+        Some(index)
+    }
+}
+
+impl PartialEq for PropChange {
+    fn eq(&self, other: &Self) -> bool {
+        self.entity_id == other.entity_id
+            && self.server_class == other.server_class
+            && self.tick == other.tick
+            && self.identifier == other.identifier
+            && self.old == other.old
+            && self.new == other.new
+    }
+}
+
+impl EntityStore {
+    pub fn get(&self, entity_id: EntityId) -> Option<&PacketEntity> {
+        self.entities.get(&entity_id)
+    }
+
+    pub fn contains(&self, entity_id: EntityId) -> bool {
+        self.entities.contains_key(&entity_id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PacketEntity> {
+        self.entities.values()
+    }
+
+    /// Get all entities of a server class
+    pub fn by_class(&self, class_id: ClassId) -> impl Iterator<Item = &PacketEntity> {
+        self.entities
+            .values()
+            .filter(move |entity| entity.server_class == class_id)
+    }
+
+    pub(crate) fn subscribe(&mut self, subscriptions: impl IntoIterator<Item = PropSubscription>) {
+        self.subscriptions.extend(subscriptions);
+    }
+
+    /// Map the subscribed class names to the class ids of the demo
+    pub(crate) fn resolve_subscriptions(&mut self, server_classes: &[ServerClass]) {
+        self.subscribed.clear();
+        for subscription in self.subscriptions.iter() {
+            if let Some(class) = server_classes
+                .iter()
+                .find(|class| class.name.as_str() == subscription.class_name)
+            {
+                let subscribed = self.subscribed.entry(class.id).or_default();
+                let subscriber = (subscription.identifier, subscription.route);
+                if !subscribed.contains(&subscriber) {
+                    subscribed.push(subscriber);
+                }
+            }
+        }
+    }
+
+    /// Take the changes to subscribed props from the last update
+    pub(crate) fn take_changes(&mut self) -> Vec<PropChange> {
+        std::mem::take(&mut self.changes)
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        message: &PacketEntitiesMessage,
+        tick: DemoTick,
+        state: &ParserState,
+    ) {
+        // a full update replaces all existing entities
+        if message.delta.is_none() {
+            self.entities.clear();
+        }
+
+        for removed in message.removed_entities.iter() {
+            self.entities.remove(removed);
+        }
+
+        for entity in message.entities.iter() {
+            match entity.update_type {
+                UpdateType::Enter => {
+                    let mut stored = entity.clone();
+                    // lazy decoding is disabled while tracking entities, so this can only fail
+                    // for entities that were not created by the parser
+                    let Ok(props) = entity.try_props(state) else {
+                        #[cfg(feature = "trace")]
+                        warn!(
+                            entity = display(entity.entity_index),
+                            "failed to decode the props of a tracked entity"
+                        );
+                        continue;
+                    };
+                    stored.props = props.collect();
+                    stored.in_pvs = true;
+                    if let Some(subscribed) = self.subscribed.get(&entity.server_class) {
+                        // entities re-entering the pvs keep their previous state, a different
+                        // serial number means the index was reused for a new entity
+                        let previous = self.entities.get(&entity.entity_index).filter(|previous| {
+                            previous.server_class == entity.server_class
+                                && previous.serial_number == entity.serial_number
+                        });
+                        record_changes(
+                            &mut self.changes,
+                            subscribed,
+                            previous,
+                            &stored,
+                            &stored.props,
+                            tick,
+                        );
+                    }
+                    self.entities.insert(entity.entity_index, stored);
+                }
+                UpdateType::Preserve => match self.entities.get_mut(&entity.entity_index) {
+                    Some(stored) => {
+                        if let Some(subscribed) = self.subscribed.get(&stored.server_class) {
+                            record_changes(
+                                &mut self.changes,
+                                subscribed,
+                                Some(stored),
+                                entity,
+                                &entity.props,
+                                tick,
+                            );
+                        }
+                        stored.apply_update(&entity.props);
+                        stored.in_pvs = true;
+                        stored.update_type = UpdateType::Preserve;
+                    }
+                    None => {
+                        self.entities.insert(entity.entity_index, entity.clone());
+                    }
+                },
+                UpdateType::Leave => {
+                    if let Some(stored) = self.entities.get_mut(&entity.entity_index) {
+                        stored.in_pvs = false;
+                        stored.update_type = UpdateType::Leave;
+                    }
+                }
+                UpdateType::Delete => {
+                    self.entities.remove(&entity.entity_index);
+                }
+            }
+        }
+    }
+}
+
+/// Record a change for every handler subscribed to a changed prop
+fn record_changes(
+    changes: &mut Vec<PropChange>,
+    subscribed: &[(SendPropIdentifier, HandlerRoute)],
+    previous: Option<&PacketEntity>,
+    entity: &PacketEntity,
+    props: &[SendProp],
+    tick: DemoTick,
+) {
+    for prop in props.iter() {
+        let mut routes = subscribed
+            .iter()
+            .filter(|(identifier, _)| *identifier == prop.identifier)
+            .map(|(_, route)| *route)
+            .peekable();
+        if routes.peek().is_none() {
+            continue;
+        }
+        let old = previous
+            .and_then(|previous| {
+                previous
+                    .props
+                    .iter()
+                    .find(|old| old.identifier == prop.identifier)
+            })
+            .map(|old| &old.value);
+        if old != Some(&prop.value) {
+            changes.extend(routes.map(|route| PropChange {
+                entity_id: entity.entity_index,
+                server_class: entity.server_class,
+                tick,
+                identifier: prop.identifier,
+                old: old.cloned(),
+                new: prop.value.clone(),
+                route: Cell::new(route),
+            }));
+        }
+    }
+}
@@ -1,14 +1,31 @@
+use crate::demo::data::DemoTick;
 use crate::demo::gamevent::GameEventValueType;
 use crate::demo::message::gameevent::GameEventTypeId;
 use crate::demo::message::packetentities::EntityId;
+use crate::demo::message::MessageType;
 use crate::demo::packet::datatable::{ClassId, SendTableName};
+use crate::demo::packet::PacketType;
 use crate::demo::sendprop::{SendPropIdentifier, SendPropValue};
 use bitbuffer::BitError;
+use std::fmt::{Display, Formatter};
 use std::str::Utf8Error;
 use std::string::FromUtf8Error;
 use thiserror::Error;
 
 /// Errors that can occur during parsing
+///
+/// Errors returned while parsing a demo are usually wrapped in [`ParseError::WithContext`] to add
+/// the location of the error, so matching directly on the variants of a returned error will not
+/// find the original error. Use [`ParseError::inner`] or [`ParseError::into_inner`] to match on the
+/// error and [`ParseError::context`] to get the location.
+///
+/// ```
+/// # use tf_demo_parser::ParseError;
+/// # use bitbuffer::BitError;
+/// fn is_truncated(err: &ParseError) -> bool {
+///     matches!(err.inner(), ParseError::ReadError(BitError::NotEnoughData { .. }))
+/// }
+/// ```
 #[non_exhaustive]
 #[derive(Debug, Error)]
 pub enum ParseError {
@@ -71,6 +88,110 @@ pub enum ParseError {
     UnknownEntity(EntityId),
     #[error("No sendprop definition found for property")]
     UnknownDefinition(SendPropIdentifier),
+    #[error("Packet size of {size} bytes exceeds the limit of {limit} bytes")]
+    PacketTooLarge { size: u32, limit: u32 },
+    #[error("A packet was written after the stop packet")]
+    PacketAfterStop,
+    #[error("Error while reading demo data: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid json: {0}")]
+    Json(#[from] serde_json::Error),
+    /// Another error with the location in the demo where it occurred
+    ///
+    /// The context is always added to the innermost error, so `source` is never a `WithContext` itself.
+    #[error("{source} ({context})")]
+    WithContext {
+        context: ErrorContext,
+        source: Box<ParseError>,
+    },
+}
+
+/// Location in the demo where an error occurred
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ErrorContext {
+    /// Tick of the packet being parsed
+    pub tick: Option<DemoTick>,
+    /// Index of the packet being parsed
+    pub packet_index: Option<usize>,
+    pub packet_type: Option<PacketType>,
+    /// Type of the message being parsed, if the error occurred while parsing a message
+    pub message_type: Option<MessageType>,
+    /// Bit position of the start of the packet in the demo stream
+    pub position: Option<usize>,
+    /// Bit position of the start of the message, relative to the start of the message data of the packet
+    pub message_position: Option<usize>,
+}
+
+impl Display for ErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mut parts = Vec::with_capacity(6);
+        if let Some(tick) = self.tick {
+            parts.push(format!("tick {}", tick));
+        }
+        if let Some(packet_index) = self.packet_index {
+            parts.push(format!("packet {}", packet_index));
+        }
+        if let Some(packet_type) = self.packet_type {
+            parts.push(format!("packet type {}", packet_type.as_str()));
+        }
+        if let Some(position) = self.position {
+            parts.push(format!("bit {}", position));
+        }
+        if let Some(message_type) = self.message_type {
+            parts.push(format!("message type {:?}", message_type));
+        }
+        if let Some(message_position) = self.message_position {
+            parts.push(format!("message bit {}", message_position));
+        }
+        write!(f, "at {}", parts.join(", "))
+    }
+}
+
+impl ParseError {
+    /// Attach information about the location of the error, merging it with any existing context
+    pub fn with_context(self, add_context: impl FnOnce(&mut ErrorContext)) -> Self {
+        match self {
+            ParseError::WithContext {
+                mut context,
+                source,
+            } => {
+                add_context(&mut context);
+                ParseError::WithContext { context, source }
+            }
+            err => {
+                let mut context = ErrorContext::default();
+                add_context(&mut context);
+                ParseError::WithContext {
+                    context,
+                    source: Box::new(err),
+                }
+            }
+        }
+    }
+
+    /// Get the location where the error occurred, if known
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            ParseError::WithContext { context, .. } => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Get the underlying error without any location information
+    pub fn inner(&self) -> &ParseError {
+        match self {
+            ParseError::WithContext { source, .. } => source.inner(),
+            err => err,
+        }
+    }
+
+    /// Take the underlying error without any location information
+    pub fn into_inner(self) -> ParseError {
+        match self {
+            ParseError::WithContext { source, .. } => source.into_inner(),
+            err => err,
+        }
+    }
 }
 
 #[non_exhaustive]
@@ -122,3 +243,33 @@ impl From<FromUtf8Error> for ParseError {
 }
 
 pub type Result<T> = std::result::Result<T, ParseError>;
+
+#[test]
+fn test_error_context_merge() {
+    let err = ParseError::InvalidMessageType(99).with_context(|context| {
+        context.message_type = Some(MessageType::GameEvent);
+    });
+    let err = err.with_context(|context| {
+        context.tick = Some(DemoTick::from(12));
+        context.packet_index = Some(3);
+    });
+
+    assert!(matches!(err.inner(), ParseError::InvalidMessageType(99)));
+    assert_eq!(
+        Some(&ErrorContext {
+            tick: Some(DemoTick::from(12)),
+            packet_index: Some(3),
+            message_type: Some(MessageType::GameEvent),
+            ..ErrorContext::default()
+        }),
+        err.context()
+    );
+    assert_eq!(
+        "Message identifier is invalid: 99 (at tick 12, packet 3, message type GameEvent)",
+        err.to_string()
+    );
+    assert!(matches!(
+        err.into_inner(),
+        ParseError::InvalidMessageType(99)
+    ));
+}
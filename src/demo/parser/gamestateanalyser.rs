@@ -19,10 +19,12 @@ use crate::demo::vector::{Vector, VectorXY};
 use crate::{MessageType, ParserState, ReadResult, Stream};
 use std::convert::TryFrom;
 use std::str::FromStr;
+#[cfg(feature = "trace")]
+use tracing::warn;
 
 pub struct CachedEntities {}
 
-#[derive(Default, Debug)]
+#[derive(Default, Debug, Clone)]
 pub struct GameStateAnalyser {
     pub state: GameState,
     tick: DemoTick,
@@ -117,7 +119,7 @@ impl MessageHandler for GameStateAnalyser {
     }
 
     fn into_output(mut self, state: &ParserState) -> Self::Output {
-        self.state.server_classes = state.server_classes.clone();
+        self.state.server_classes = state.server_classes.to_vec();
         self.state
     }
 }
@@ -128,6 +130,26 @@ impl BorrowMessageHandler for GameStateAnalyser {
     }
 }
 
+/// The props of the entity, nothing if the lazily decoded props of the entity fail to decode
+fn entity_props<'a>(
+    entity: &'a PacketEntity,
+    parser_state: &'a ParserState,
+) -> impl Iterator<Item = SendProp> + 'a {
+    entity
+        .try_props(parser_state)
+        .map_err(|_e| {
+            #[cfg(feature = "trace")]
+            warn!(
+                entity = display(entity.entity_index),
+                error = display(_e),
+                "failed to decode entity props"
+            );
+        })
+        .ok()
+        .into_iter()
+        .flatten()
+}
+
 impl GameStateAnalyser {
     pub fn new() -> Self {
         Self::default()
@@ -141,7 +163,20 @@ impl GameStateAnalyser {
             return;
         };
 
-        for prop in &entity.props {
+        // only the props of the update, with the lazily decoded ones when lazy decoding is enabled
+        let lazy_props = match entity.decode_lazy_props(parser_state) {
+            Ok(lazy_props) => lazy_props,
+            Err(_e) => {
+                #[cfg(feature = "trace")]
+                warn!(
+                    entity = display(entity.entity_index),
+                    error = display(_e),
+                    "failed to decode entity props, ignoring update"
+                );
+                return;
+            }
+        };
+        for prop in entity.props.iter().chain(lazy_props.iter()) {
             if prop.identifier == OUTER {
                 let outer = i64::try_from(&prop.value).unwrap_or_default();
                 self.state
@@ -167,7 +202,7 @@ impl GameStateAnalyser {
     }
 
     pub fn handle_player_resource(&mut self, entity: &PacketEntity, parser_state: &ParserState) {
-        for prop in entity.props(parser_state) {
+        for prop in entity_props(entity, parser_state) {
             if let Some((table_name, prop_name)) = prop.identifier.names() {
                 if let Ok(player_id) = u32::from_str(prop_name.as_str()) {
                     let entity_id = EntityId::from(player_id);
@@ -242,7 +277,7 @@ impl GameStateAnalyser {
 
         player.in_pvs = entity.in_pvs;
 
-        for prop in entity.props(parser_state) {
+        for prop in entity_props(entity, parser_state) {
             match prop.identifier {
                 HEALTH_PROP => {
                     player.health = i64::try_from(&prop.value).unwrap_or_default() as u16
@@ -338,7 +373,7 @@ impl GameStateAnalyser {
             .get_or_create_building(entity.entity_index, BuildingClass::Sentry);
 
         if let Building::Sentry(sentry) = building {
-            for prop in entity.props(parser_state) {
+            for prop in entity_props(entity, parser_state) {
                 match prop.identifier {
                     ANGLE => sentry.angle = f32::try_from(&prop.value).unwrap_or_default(),
                     MINI => sentry.is_mini = i64::try_from(&prop.value).unwrap_or_default() > 0,
@@ -386,7 +421,7 @@ impl GameStateAnalyser {
             .get_or_create_building(entity.entity_index, BuildingClass::Teleporter);
 
         if let Building::Teleporter(teleporter) = building {
-            for prop in entity.props(parser_state) {
+            for prop in entity_props(entity, parser_state) {
                 match prop.identifier {
                     RECHARGE_TIME => {
                         teleporter.recharge_time = f32::try_from(&prop.value).unwrap_or_default()
@@ -433,7 +468,7 @@ impl GameStateAnalyser {
             .get_or_create_building(entity.entity_index, BuildingClass::Dispenser);
 
         if let Building::Dispenser(dispenser) = building {
-            for prop in entity.props(parser_state) {
+            for prop in entity_props(entity, parser_state) {
                 match prop.identifier {
                     AMMO => dispenser.metal = i64::try_from(&prop.value).unwrap_or_default() as u16,
                     HEALING => {
@@ -514,7 +549,7 @@ impl GameStateAnalyser {
                 health,
                 ..
             }) => {
-                for prop in entity.props(parser_state) {
+                for prop in entity_props(entity, parser_state) {
                     match prop.identifier {
                         LOCAL_ORIGIN => {
                             *position = Vector::try_from(&prop.value).unwrap_or_default()
@@ -576,7 +611,7 @@ impl GameStateAnalyser {
 
         // todo: bounds for grenades
 
-        for prop in entity.props(parser_state) {
+        for prop in entity_props(entity, parser_state) {
             match prop.identifier {
                 ROCKET_ORIGIN | GRENADE_ORIGIN => {
                     let pos = Vector::try_from(&prop.value).unwrap_or_default();
@@ -1,12 +1,18 @@
+use crate::demo::message::packetentities::{
+    BaselineIndex, EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
 use crate::demo::message::{Message, MessageType};
 use crate::demo::packet::datatable::{ParseSendTable, ServerClass};
 use crate::demo::packet::stringtable::{StringTable, StringTableEntry};
-use crate::demo::packet::Packet;
+use crate::demo::packet::{Packet, PacketType};
 use crate::Result;
 
 use crate::demo::data::{DemoTick, ServerTick};
 use crate::demo::header::Header;
 use crate::demo::packet::message::MessagePacketMeta;
+use crate::demo::parser::combinator::BorrowMessageHandlers;
+use crate::demo::parser::config::ParserConfig;
+use crate::demo::parser::entities::{PropChange, PropSubscription};
 use crate::ParserState;
 use std::borrow::Cow;
 
@@ -45,6 +51,79 @@ pub trait MessageHandler {
     ) {
     }
 
+    /// Called when an entity is created or re-enters the pvs
+    ///
+    /// `created` is `false` when an existing entity with the same serial number re-enters the pvs.
+    /// Like all entity callbacks this requires entity decoding to be enabled, either by handling
+    /// `PacketEntities` messages or through the parser config.
+    fn handle_entity_enter(
+        &mut self,
+        _entity: &PacketEntity,
+        _created: bool,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Called when an entity leaves the pvs, the entity contains no props
+    fn handle_entity_leave(
+        &mut self,
+        _entity: &PacketEntity,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Called when an entity is deleted, the entity contains no props
+    ///
+    /// This includes entities that are explicitly removed, entities replaced by a new entity with
+    /// the same index and all existing entities when a full update is received.
+    fn handle_entity_delete(
+        &mut self,
+        _entity: &PacketEntity,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+    }
+
+    /// Names of the server classes to decode entity props for, `None` to decode all classes
+    ///
+    /// This is called once when the parser is created. Entities of other classes are still
+    /// tracked and passed to the handler, but their props are skipped while parsing.
+    fn entity_classes(&self) -> Option<Vec<String>> {
+        None
+    }
+
+    /// The message types handled by this instance, `None` to use [`does_handle`](Self::does_handle)
+    ///
+    /// This is called once when the parser is created, for handlers that only know which messages
+    /// they handle at runtime, like a list of boxed [`DynMessageHandler`](crate::demo::parser::DynMessageHandler)s.
+    fn handled_message_types(&self) -> Option<Vec<MessageType>> {
+        None
+    }
+
+    /// Props to receive [`handle_prop_change`](Self::handle_prop_change) callbacks for
+    ///
+    /// This is called once when the parser is created, subscribing to any props enables
+    /// entity tracking.
+    fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+        Vec::new()
+    }
+
+    /// Called when the value of a subscribed prop changes
+    ///
+    /// The parser state already contains the updated entity.
+    fn handle_prop_change(&mut self, _change: &PropChange, _parser_state: &ParserState) {}
+
+    /// Whether the handler has all the information it needs
+    ///
+    /// Parsing stops once this returns `true`, after which [`into_output`](Self::into_output) is
+    /// called as normal. This is checked before every packet, so analysers that only need part
+    /// of the demo can skip parsing the rest of it.
+    fn is_done(&self) -> bool {
+        false
+    }
+
     fn into_output(self, state: &ParserState) -> Self::Output;
 }
 
@@ -69,6 +148,7 @@ pub struct DemoHandler<'a, T: MessageHandler> {
     pub server_tick: ServerTick,
     pub demo_tick: DemoTick,
     pub string_table_names: Vec<Cow<'a, str>>,
+    packet_index: usize,
     analyser: T,
     pub state_handler: ParserState,
 }
@@ -87,37 +167,69 @@ impl Default for DemoHandler<'_, NullHandler> {
 
 impl<'a, T: MessageHandler> DemoHandler<'a, T> {
     pub fn with_analyser(analyser: T) -> Self {
-        let state_handler = ParserState::new(24, T::does_handle, false);
-
-        DemoHandler {
-            server_tick: ServerTick::default(),
-            demo_tick: DemoTick::default(),
-            string_table_names: Vec::new(),
-            analyser,
-            state_handler,
-        }
+        Self::with_config(analyser, ParserConfig::new())
     }
+
     pub fn parse_all_with_analyser(analyser: T) -> Self {
-        let state_handler = ParserState::new(24, T::does_handle, true);
+        Self::with_config(analyser, ParserConfig::new().parse_all(true))
+    }
+
+    pub fn with_config(analyser: T, config: ParserConfig) -> Self {
+        let subscriptions = analyser.prop_subscriptions();
+        let config = if subscriptions.is_empty() {
+            config
+        } else {
+            config.track_entities(true)
+        };
+        // subscribed props need to be decoded
+        let entity_classes = analyser.entity_classes().map(|mut classes| {
+            classes.extend(
+                subscriptions
+                    .iter()
+                    .map(|subscription| subscription.class_name.clone()),
+            );
+            classes
+        });
+        let mut state_handler = ParserState::with_config(T::does_handle, config);
+        state_handler.entities.subscribe(subscriptions);
+        state_handler.set_entity_class_filter(entity_classes);
+        state_handler.set_analyser_message_types(analyser.handled_message_types());
 
         DemoHandler {
             server_tick: ServerTick::default(),
             demo_tick: DemoTick::default(),
             string_table_names: Vec::new(),
+            packet_index: 0,
             analyser,
             state_handler,
         }
     }
 
     pub fn handle_header(&mut self, header: &Header) {
-        self.state_handler.protocol_version = header.protocol;
+        if self.state_handler.config().get_protocol_version().is_none() {
+            self.state_handler.protocol_version = header.protocol;
+        }
         self.analyser.handle_header(header);
     }
 
     pub fn handle_packet(&mut self, packet: Packet<'a>) -> Result<()> {
+        let tick = packet.tick();
+        // signon packets carry the server tick from before the recording started
+        if !matches!(packet, Packet::Signon(_) | Packet::DataTables(_)) {
+            self.demo_tick = tick;
+        }
+        let packet_index = self.packet_index;
+        self.packet_index += 1;
         match packet {
             Packet::DataTables(packet) => {
-                self.handle_data_table(packet.tables, packet.server_classes)?;
+                self.handle_data_table(packet.tables, packet.server_classes)
+                    .map_err(|e| {
+                        e.with_context(|context| {
+                            context.tick = Some(tick);
+                            context.packet_index = Some(packet_index);
+                            context.packet_type = Some(PacketType::DataTables);
+                        })
+                    })?;
             }
             Packet::StringTables(packet) => {
                 for table in packet.tables.into_iter() {
@@ -193,14 +305,102 @@ impl<'a, T: MessageHandler> DemoHandler<'a, T> {
     }
 
     pub fn handle_message(&mut self, message: Message<'a>, tick: DemoTick) {
+        if let Message::PacketEntities(message) = &message {
+            self.state_handler.update_entities(message, tick);
+            for change in self.state_handler.take_prop_changes() {
+                self.analyser
+                    .handle_prop_change(&change, &self.state_handler);
+            }
+            self.handle_entity_lifecycle(message, tick);
+        }
         let message_type = message.get_message_type();
-        if T::does_handle(message_type) {
+        if self.state_handler.analyser_handles(message_type) {
             self.analyser
                 .handle_message(&message, tick, &self.state_handler);
         }
         self.state_handler.handle_message(message, tick);
     }
 
+    fn handle_entity_lifecycle(&mut self, message: &PacketEntitiesMessage, tick: DemoTick) {
+        let state = &self.state_handler;
+
+        if message.delta.is_none() {
+            for (&entity_index, &serial_number) in state.entity_serials.iter() {
+                if let Some(entity) =
+                    lifecycle_entity(state, entity_index, serial_number, UpdateType::Delete)
+                {
+                    self.analyser.handle_entity_delete(&entity, tick, state);
+                }
+            }
+        }
+
+        for entity in message.entities.iter() {
+            // all existing entities have already been deleted for a full update
+            let existing = state
+                .entity_serials
+                .get(&entity.entity_index)
+                .filter(|_| message.delta.is_some())
+                .and_then(|&serial_number| {
+                    lifecycle_entity(
+                        state,
+                        entity.entity_index,
+                        serial_number,
+                        UpdateType::Delete,
+                    )
+                });
+            match entity.update_type {
+                UpdateType::Enter => {
+                    let created = match existing {
+                        Some(existing)
+                            if existing.serial_number == entity.serial_number
+                                && existing.server_class == entity.server_class =>
+                        {
+                            false
+                        }
+                        Some(existing) => {
+                            // the entity index is reused for a new entity
+                            self.analyser.handle_entity_delete(&existing, tick, state);
+                            true
+                        }
+                        None => true,
+                    };
+                    self.analyser
+                        .handle_entity_enter(entity, created, tick, state);
+                }
+                UpdateType::Leave => {
+                    if let Some(mut existing) = existing {
+                        existing.update_type = UpdateType::Leave;
+                        self.analyser.handle_entity_leave(&existing, tick, state);
+                    }
+                }
+                UpdateType::Delete => {
+                    if let Some(existing) = existing {
+                        self.analyser.handle_entity_delete(&existing, tick, state);
+                    }
+                }
+                UpdateType::Preserve => {}
+            }
+        }
+
+        for &entity_index in message.removed_entities.iter() {
+            if let Some(entity) =
+                state
+                    .entity_serials
+                    .get(&entity_index)
+                    .and_then(|&serial_number| {
+                        lifecycle_entity(state, entity_index, serial_number, UpdateType::Delete)
+                    })
+            {
+                self.analyser.handle_entity_delete(&entity, tick, state);
+            }
+        }
+    }
+
+    /// Whether the analyser has signaled that it doesn't need any more data
+    pub fn is_done(&self) -> bool {
+        self.analyser.is_done()
+    }
+
     pub fn into_output(self) -> T::Output {
         self.analyser.into_output(&self.state_handler)
     }
@@ -215,3 +415,31 @@ impl<T: MessageHandler + BorrowMessageHandler> DemoHandler<'_, T> {
         self.analyser.borrow_output(&self.state_handler)
     }
 }
+
+impl<T: BorrowMessageHandlers> DemoHandler<'_, T> {
+    pub fn borrow_outputs(&self) -> T::Borrowed<'_> {
+        self.analyser.borrow_outputs(&self.state_handler)
+    }
+}
+
+/// Entity without props passed to the leave and delete callbacks
+fn lifecycle_entity(
+    state: &ParserState,
+    entity_index: EntityId,
+    serial_number: u32,
+    update_type: UpdateType,
+) -> Option<PacketEntity> {
+    let server_class = *state.entity_classes.get(&entity_index)?;
+    Some(PacketEntity {
+        server_class,
+        entity_index,
+        props: Vec::new(),
+        in_pvs: false,
+        update_type,
+        serial_number,
+        delay: None,
+        delta: None,
+        baseline_index: BaselineIndex::First,
+        lazy_props: None,
+    })
+}
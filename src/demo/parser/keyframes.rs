@@ -0,0 +1,130 @@
+use crate::demo::data::DemoTick;
+use crate::demo::parser::{
+    DemoHandler, DemoTicker, MessageHandler, PacketPosition, SeekablePacketSource,
+};
+use crate::Result;
+
+/// A snapshot of the parser and analyser state at a specific point in the demo
+///
+/// Only the position of the next packet in the demo and the state of the handler are stored,
+/// the data tables and other parts of the [`ParserState`](crate::ParserState) that don't change
+/// while parsing are shared with the ticker.
+#[derive(Clone)]
+pub struct Keyframe<'a, A: MessageHandler> {
+    pub tick: DemoTick,
+    /// The position of the first packet after the keyframe
+    pub position: PacketPosition,
+    handler: DemoHandler<'a, A>,
+    skipped: usize,
+}
+
+/// An index of keyframes, allowing a [`DemoTicker`] to seek to any tick in the demo
+/// without having to re-parse everything before it
+#[derive(Clone)]
+pub struct KeyframeIndex<'a, A: MessageHandler> {
+    keyframes: Vec<Keyframe<'a, A>>,
+}
+
+impl<A: MessageHandler> Default for KeyframeIndex<'_, A> {
+    fn default() -> Self {
+        KeyframeIndex {
+            keyframes: Vec::new(),
+        }
+    }
+}
+
+impl<'a, A: MessageHandler> KeyframeIndex<'a, A> {
+    pub fn len(&self) -> usize {
+        self.keyframes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keyframes.is_empty()
+    }
+
+    pub fn keyframes(&self) -> &[Keyframe<'a, A>] {
+        &self.keyframes
+    }
+
+    /// Get the last keyframe at or before the tick
+    pub fn keyframe_for(&self, tick: DemoTick) -> Option<&Keyframe<'a, A>> {
+        let index = self
+            .keyframes
+            .partition_point(|keyframe| keyframe.tick <= tick);
+        self.keyframes.get(index.checked_sub(1)?)
+    }
+}
+
+impl<'a, A: MessageHandler, P: SeekablePacketSource<'a>> DemoTicker<'a, A, P> {
+    /// The keyframes used by [`seek`](Self::seek), empty until [`build_index`](Self::build_index) is called
+    pub fn keyframe_index(&self) -> &KeyframeIndex<'a, A> {
+        &self.keyframes
+    }
+}
+
+impl<'a, A, P> DemoTicker<'a, A, P>
+where
+    A: MessageHandler + Clone,
+    P: SeekablePacketSource<'a>,
+{
+    /// Build a keyframe index for the remainder of the demo in a single pass,
+    /// storing a keyframe every `interval` ticks.
+    ///
+    /// The ticker processes the rest of the demo to build the index and is moved back to its
+    /// current position afterwards.
+    pub fn build_index(&mut self, interval: u32) -> Result<()> {
+        let start = self.keyframe();
+        let mut keyframes = vec![start.clone()];
+        let mut next_keyframe = self.current_tick() + interval.max(1);
+
+        while self.tick()? {
+            if self.current_tick() >= next_keyframe {
+                keyframes.push(self.keyframe());
+                next_keyframe = self.current_tick() + interval.max(1);
+            }
+        }
+
+        self.restore(start)?;
+        self.keyframes = KeyframeIndex { keyframes };
+        Ok(())
+    }
+
+    /// Move the ticker to the first packet at or after the tick
+    ///
+    /// The nearest keyframe before the tick is restored and packets are processed from there,
+    /// unless the ticker is already closer to the target tick.
+    ///
+    /// Returns whether or not the tick was reached before the end of the demo
+    pub fn seek(&mut self, tick: DemoTick) -> Result<bool> {
+        if let Some(keyframe) = self.keyframes.keyframe_for(tick) {
+            let current = self.current_tick();
+            if current > tick || current < keyframe.tick {
+                let keyframe = keyframe.clone();
+                self.restore(keyframe)?;
+            }
+        }
+
+        while self.current_tick() < tick {
+            if !self.tick()? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    fn keyframe(&self) -> Keyframe<'a, A> {
+        Keyframe {
+            tick: self.current_tick(),
+            position: self.packets.packet_position(),
+            handler: self.handler.clone(),
+            skipped: self.skipped.len(),
+        }
+    }
+
+    fn restore(&mut self, keyframe: Keyframe<'a, A>) -> Result<()> {
+        self.packets.seek(keyframe.position)?;
+        self.handler = keyframe.handler;
+        self.skipped.truncate(keyframe.skipped);
+        Ok(())
+    }
+}
@@ -5,7 +5,7 @@ use crate::demo::parser::handler::MessageHandler;
 
 use crate::ParserState;
 
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct MessageTypeAnalyser {
     packet_types: Vec<MessageType>,
 }
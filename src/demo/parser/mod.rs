@@ -5,23 +5,39 @@ pub use self::messagetypeanalyser::MessageTypeAnalyser;
 
 use crate::demo::header::Header;
 
-use crate::demo::packet::Packet;
+use crate::demo::packet::{Packet, PacketType};
 use crate::demo::parser::analyser::Analyser;
 pub use crate::demo::parser::analyser::MatchState;
+pub use crate::demo::parser::combinator::{BorrowMessageHandlers, DynMessageHandler};
+pub use crate::demo::parser::config::{CancelToken, ParserConfig, ParserLimits};
+pub use crate::demo::parser::entities::{EntityStore, PropChange, PropSubscription};
 pub use crate::demo::parser::handler::{DemoHandler, MessageHandler, NullHandler};
+pub use crate::demo::parser::keyframes::{Keyframe, KeyframeIndex};
+pub use crate::demo::parser::observer::ParseObserver;
+pub use crate::demo::parser::progress::Progress;
 pub use crate::demo::parser::state::ParserState;
 use crate::Stream;
+use std::sync::Arc;
 
 pub mod analyser;
+pub mod combinator;
+pub mod config;
+pub mod entities;
 pub mod error;
 pub mod gamestateanalyser;
 pub mod handler;
+pub mod keyframes;
 pub mod messagetypeanalyser;
+pub mod observer;
 pub mod player_summary_analyzer;
+pub mod progress;
+pub mod reader;
 pub mod state;
 
 pub use self::error::*;
 use crate::demo::parser::handler::BorrowMessageHandler;
+pub use crate::demo::parser::reader::ReaderPacketStream;
+use std::io::Read;
 
 pub trait Parse<'a>: Sized {
     fn parse(stream: &mut Stream<'a>, state: &ParserState) -> Result<Self>;
@@ -57,9 +73,9 @@ impl<'a, T: BitRead<'a, LittleEndian>> ParseBitSkip<'a> for T {
     }
 }
 
-pub struct DemoParser<'a, A: MessageHandler> {
+pub struct DemoParser<'a, A: MessageHandler, P: PacketSource<'a> = RawPacketStream<'a>> {
     handler: DemoHandler<'a, A>,
-    stream: Stream<'a>,
+    packets: P,
 }
 
 impl<'a> DemoParser<'a, Analyser> {
@@ -74,16 +90,65 @@ impl<'a> DemoParser<'a, Analyser> {
 
 impl<'a, A: MessageHandler> DemoParser<'a, A> {
     pub fn new_with_analyser(stream: Stream<'a>, analyser: A) -> Self {
+        DemoParser::with_config(stream, analyser, ParserConfig::new())
+    }
+
+    pub fn new_all_with_analyser(stream: Stream<'a>, analyser: A) -> Self {
+        DemoParser::with_config(stream, analyser, ParserConfig::new().parse_all(true))
+    }
+
+    /// Create a parser with the provided configuration
+    ///
+    /// ```no_run
+    /// # use tf_demo_parser::{Demo, DemoParser};
+    /// # use tf_demo_parser::demo::parser::analyser::Analyser;
+    /// # use tf_demo_parser::demo::parser::ParserConfig;
+    /// # let file = std::fs::read("demo.dem").unwrap();
+    /// let demo = Demo::new(&file);
+    /// let config = ParserConfig::new().decode_entities(false).lenient(true);
+    /// let parser = DemoParser::with_config(demo.get_stream(), Analyser::new(), config);
+    /// let (header, state) = parser.parse().unwrap();
+    /// ```
+    pub fn with_config(stream: Stream<'a>, analyser: A, config: ParserConfig) -> Self {
         DemoParser {
-            handler: DemoHandler::with_analyser(analyser),
-            stream,
+            handler: DemoHandler::with_config(analyser, config),
+            packets: RawPacketStream::new(stream),
         }
     }
+}
 
-    pub fn new_all_with_analyser(stream: Stream<'a>, analyser: A) -> Self {
+impl<R: Read> DemoParser<'static, Analyser, ReaderPacketStream<R>> {
+    /// Create a parser that incrementally reads the demo from a [`Read`] source
+    /// instead of requiring the entire demo to be loaded in memory
+    pub fn from_reader(reader: R) -> Self {
+        DemoParser::from_reader_with_analyser(reader, Analyser::new())
+    }
+}
+
+impl<A: MessageHandler, R: Read> DemoParser<'static, A, ReaderPacketStream<R>> {
+    pub fn from_reader_with_analyser(reader: R, analyser: A) -> Self {
+        DemoParser::from_reader_with_config(reader, analyser, ParserConfig::new())
+    }
+
+    pub fn from_reader_all_with_analyser(reader: R, analyser: A) -> Self {
+        DemoParser::from_reader_with_config(reader, analyser, ParserConfig::new().parse_all(true))
+    }
+
+    /// Create a parser that incrementally reads the demo from a [`Read`] source with the provided configuration
+    pub fn from_reader_with_config(reader: R, analyser: A, config: ParserConfig) -> Self {
         DemoParser {
-            handler: DemoHandler::parse_all_with_analyser(analyser),
-            stream,
+            handler: DemoHandler::with_config(analyser, config),
+            packets: ReaderPacketStream::new(reader),
+        }
+    }
+}
+
+impl<'a, A: MessageHandler, P: PacketSource<'a>> DemoParser<'a, A, P> {
+    /// Create a parser reading packets from any packet source
+    pub fn from_source(packets: P, analyser: A, config: ParserConfig) -> Self {
+        DemoParser {
+            handler: DemoHandler::with_config(analyser, config),
+            packets,
         }
     }
 
@@ -96,22 +161,124 @@ impl<'a, A: MessageHandler> DemoParser<'a, A> {
         Ok((header, ticker.into_state()))
     }
 
-    /// A Ticker provides a way to step trough the demo packet by packet
+    /// Parse the demo, skipping over damaged packets instead of stopping at the first error
+    ///
+    /// The skipped packets are returned alongside the analyser output
+    pub fn parse_lenient(self) -> Result<(Header, A::Output, Vec<SkippedPacket>)> {
+        let (header, mut ticker) = self.ticker()?;
+        ticker.set_lenient(true);
+        while ticker.tick()? {
+            // noop
+        }
+        let skipped = ticker.take_skipped();
+        Ok((header, ticker.into_state(), skipped))
+    }
+
+    /// A Ticker provides a way to step through the demo packet by packet
     /// while allowing to see the intermediate states
-    pub fn ticker(mut self) -> Result<(Header, DemoTicker<'a, A>)> {
-        let header = Header::read(&mut self.stream)?;
+    pub fn ticker(mut self) -> Result<(Header, DemoTicker<'a, A, P>)> {
+        let header = self.packets.read_header()?;
         self.handler.handle_header(&header);
+        let config = self.handler.get_parser_state().config();
+        let lenient = config.is_lenient();
+        let cancel_token = config.get_cancel_token().cloned();
         let ticker = DemoTicker {
             handler: self.handler,
-            packets: RawPacketStream::new(self.stream),
+            packets: self.packets,
+            keyframes: KeyframeIndex::default(),
+            total_ticks: header.ticks,
+            lenient,
+            skipped: Vec::new(),
+            cancel_token,
         };
         Ok((header, ticker))
     }
 }
 
-#[derive(Clone)]
+/// A source of demo packets
+pub trait PacketSource<'a> {
+    /// Read the demo header, this should be called before reading any packets
+    fn read_header(&mut self) -> Result<Header>;
+
+    fn next(&mut self, state: &ParserState) -> Result<Option<Packet<'a>>>;
+
+    /// Get the bit position in the source
+    fn pos(&self) -> usize;
+
+    /// Get the total length of the source in bits, if known
+    fn total_len(&self) -> Option<usize> {
+        None
+    }
+
+    /// Skip over the packet that caused the last error returned from [`next`](Self::next),
+    /// so parsing can continue with the following packet
+    ///
+    /// Returns `false` if the source can't find the start of the next packet
+    fn recover(&mut self) -> bool;
+}
+
+/// The position of a packet in a [`SeekablePacketSource`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PacketPosition {
+    /// Bit position of the start of the packet
+    pub pos: usize,
+    /// Index of the packet in the demo
+    pub packet_index: usize,
+}
+
+/// A source of demo packets that can go back to a packet it has already read
+pub trait SeekablePacketSource<'a>: PacketSource<'a> {
+    /// The position of the next packet
+    fn packet_position(&self) -> PacketPosition;
+
+    /// Continue reading packets from a position returned by [`packet_position`](Self::packet_position)
+    fn seek(&mut self, position: PacketPosition) -> Result<()>;
+}
+
+/// Size in bytes of the fixed part of a packet following the packet type,
+/// and whether it's followed by length prefixed data
+pub(crate) fn packet_frame(packet_type: PacketType) -> (usize, bool) {
+    match packet_type {
+        PacketType::Signon | PacketType::Message => (4 + MESSAGE_META_SIZE, true),
+        PacketType::SyncTick => (4, false),
+        PacketType::ConsoleCmd | PacketType::DataTables | PacketType::StringTables => (4, true),
+        PacketType::UserCmd => (8, true),
+        PacketType::Stop => (3, false),
+    }
+}
+
+/// Size of the packet meta of signon and message packets in bytes
+const MESSAGE_META_SIZE: usize = 84;
+
+/// Read the packet type and the fixed size part of a packet,
+/// returning the length of the data that follows if the packet is length prefixed
+fn read_packet_length(stream: &mut Stream) -> Result<Option<u32>> {
+    let packet_type = PacketType::read(stream)?;
+    let (fixed_size, has_length) = packet_frame(packet_type);
+    stream.skip_bits(fixed_size * 8)?;
+    Ok(if has_length {
+        Some(stream.read()?)
+    } else {
+        None
+    })
+}
+
+/// A packet that was skipped while parsing in lenient mode
+#[derive(Debug, Clone)]
+pub struct SkippedPacket {
+    /// Bit position of the start of the skipped packet
+    pub start: usize,
+    /// Bit position where parsing resumed, `None` if the rest of the demo had to be skipped
+    pub end: Option<usize>,
+    pub error: Arc<ParseError>,
+}
+
 pub struct RawPacketStream<'a> {
     stream: Stream<'a>,
+    /// Position of the start of `stream` in the demo, cloning a stream moves its start to the current position
+    offset: usize,
+    packet_index: usize,
+    error_start: Option<usize>,
     pub ended: bool,
     pub incomplete: bool,
 }
@@ -120,92 +287,354 @@ impl<'a> RawPacketStream<'a> {
     pub fn new(stream: Stream<'a>) -> Self {
         RawPacketStream {
             stream,
+            offset: 0,
+            packet_index: 0,
+            error_start: None,
             ended: false,
             incomplete: false,
         }
     }
 
+    /// Get the bit position in the demo
     pub fn pos(&self) -> usize {
-        self.stream.pos()
+        self.offset + self.stream.pos()
+    }
+
+    pub fn read_header(&mut self) -> Result<Header> {
+        Ok(Header::read(&mut self.stream)?)
     }
 
     pub fn next(&mut self, state: &ParserState) -> Result<Option<Packet<'a>>> {
+        self.next_observed(state, &mut ())
+    }
+
+    /// Read the next packet, passing the messages and entity updates that are read to the observer
+    pub fn next_observed<O: ParseObserver>(
+        &mut self,
+        state: &ParserState,
+        observer: &mut O,
+    ) -> Result<Option<Packet<'a>>> {
         if self.ended {
-            Ok(None)
-        } else {
-            match Packet::parse(&mut self.stream, state) {
-                Ok(packet @ Packet::Stop(_)) => {
-                    self.ended = true;
-                    Ok(Some(packet))
-                }
-                Ok(packet) => Ok(Some(packet)),
-                Err(ParseError::ReadError(BitError::NotEnoughData { .. })) => {
-                    self.ended = true;
-                    self.incomplete = true;
-                    Ok(None)
-                }
-                Err(e) => {
+            return Ok(None);
+        }
+
+        let start = self.stream.pos();
+        // cloning a stream makes the current position the start of the new stream
+        let packet_stream = self.stream.clone();
+        if let Some(limit) = state.config().get_limits().max_packet_size {
+            if let Ok(Some(size)) = read_packet_length(&mut packet_stream.clone()) {
+                if size > limit {
                     self.ended = true;
-                    Err(e)
+                    self.error_start = Some(start);
+                    let err = ParseError::PacketTooLarge { size, limit };
+                    return Err(packet_error(
+                        err,
+                        &packet_stream,
+                        self.offset + start,
+                        self.packet_index,
+                    ));
                 }
             }
         }
+
+        match Packet::parse_observed(&mut self.stream, state, observer) {
+            Ok(packet @ Packet::Stop(_)) => {
+                self.ended = true;
+                Ok(Some(packet))
+            }
+            Ok(packet) => {
+                self.packet_index += 1;
+                Ok(Some(packet))
+            }
+            // only the end of the demo counts as truncation, a packet that reads past its own
+            // length is damaged and can be skipped
+            Err(_) if packet_exceeds_stream(&packet_stream) => {
+                self.ended = true;
+                self.incomplete = true;
+                Ok(None)
+            }
+            Err(e) => {
+                self.ended = true;
+                self.error_start = Some(start);
+                Err(packet_error(
+                    e,
+                    &packet_stream,
+                    self.offset + start,
+                    self.packet_index,
+                ))
+            }
+        }
+    }
+
+    /// Skip over the packet that caused the last error, using the length prefix of the packet
+    ///
+    /// Returns `false` if the packet type is invalid, as the size of the packet is unknown in that case,
+    /// or if the packet runs past the end of the demo
+    pub fn recover(&mut self) -> bool {
+        let Some(start) = self.error_start.take() else {
+            return false;
+        };
+        if self.stream.set_pos(start).is_err() {
+            return false;
+        }
+        let skipped = read_packet_length(&mut self.stream).and_then(|length| match length {
+            Some(length) => Ok(self.stream.skip_bits(length as usize * 8)?),
+            None => Ok(()),
+        });
+        match skipped {
+            Ok(()) => {
+                self.packet_index += 1;
+                self.ended = false;
+                true
+            }
+            Err(e) => {
+                self.incomplete = is_truncated(&e);
+                false
+            }
+        }
+    }
+}
+
+/// Whether the error was caused by the demo ending unexpectedly
+pub(crate) fn is_truncated(err: &ParseError) -> bool {
+    matches!(
+        err.inner(),
+        ParseError::ReadError(BitError::NotEnoughData { .. })
+    )
+}
+
+/// Whether the packet at the start of the stream is cut off by the end of the demo
+pub(crate) fn packet_exceeds_stream(packet: &Stream) -> bool {
+    let mut stream = packet.clone();
+    match read_packet_length(&mut stream) {
+        Ok(Some(length)) => stream.bits_left() < length as usize * 8,
+        Ok(None) => false,
+        Err(e) => is_truncated(&e),
+    }
+}
+
+/// Add the location of the packet at the start of `packet` to an error,
+/// `start` is the position of the packet in the demo
+pub(crate) fn packet_error(
+    err: ParseError,
+    packet: &Stream,
+    start: usize,
+    packet_index: usize,
+) -> ParseError {
+    let mut stream = packet.clone();
+    let packet_type = PacketType::read(&mut stream).ok();
+    let tick = DemoTick::read(&mut stream).ok();
+    err.with_context(|context| {
+        context.packet_index = Some(packet_index);
+        context.position = Some(start);
+        context.packet_type = packet_type;
+        context.tick = tick;
+    })
+}
+
+impl Clone for RawPacketStream<'_> {
+    fn clone(&self) -> Self {
+        // the cloned stream starts at the current position, which is rebased to keep positions
+        // relative to the start of the demo
+        let stream = self.stream.clone();
+        let rebase = self.stream.pos();
+        RawPacketStream {
+            stream,
+            offset: self.offset + rebase,
+            packet_index: self.packet_index,
+            error_start: self.error_start.and_then(|start| start.checked_sub(rebase)),
+            ended: self.ended,
+            incomplete: self.incomplete,
+        }
+    }
+}
+
+impl<'a> PacketSource<'a> for RawPacketStream<'a> {
+    fn read_header(&mut self) -> Result<Header> {
+        RawPacketStream::read_header(self)
+    }
+
+    fn next(&mut self, state: &ParserState) -> Result<Option<Packet<'a>>> {
+        RawPacketStream::next(self, state)
+    }
+
+    fn pos(&self) -> usize {
+        RawPacketStream::pos(self)
+    }
+
+    fn total_len(&self) -> Option<usize> {
+        Some(self.offset + self.stream.bit_len())
+    }
+
+    fn recover(&mut self) -> bool {
+        RawPacketStream::recover(self)
+    }
+}
+
+impl<'a> SeekablePacketSource<'a> for RawPacketStream<'a> {
+    fn packet_position(&self) -> PacketPosition {
+        PacketPosition {
+            pos: self.pos(),
+            packet_index: self.packet_index,
+        }
+    }
+
+    fn seek(&mut self, position: PacketPosition) -> Result<()> {
+        let pos = position
+            .pos
+            .checked_sub(self.offset)
+            .ok_or(ParseError::InvalidDemo(
+                "seek before the start of the packet stream",
+            ))?;
+        self.stream.set_pos(pos)?;
+        self.packet_index = position.packet_index;
+        self.error_start = None;
+        self.ended = false;
+        self.incomplete = false;
+        Ok(())
     }
 }
 
 #[derive(Clone)]
-pub struct DemoTicker<'a, A: MessageHandler> {
+pub struct DemoTicker<'a, A: MessageHandler, P: PacketSource<'a> = RawPacketStream<'a>> {
     handler: DemoHandler<'a, A>,
-    packets: RawPacketStream<'a>,
+    packets: P,
+    keyframes: KeyframeIndex<'a, A>,
+    total_ticks: u32,
+    lenient: bool,
+    skipped: Vec<SkippedPacket>,
+    cancel_token: Option<CancelToken>,
 }
 
-impl<A: MessageHandler> DemoTicker<'_, A> {
+impl<'a, A: MessageHandler, P: PacketSource<'a>> DemoTicker<'a, A, P> {
     /// Process the next packet
     ///
-    /// returns whether or not there are still packets left in the demo
+    /// returns whether or not there are still packets left in the demo,
+    /// or `false` if the analyser is done or parsing has been cancelled
     pub fn tick(&mut self) -> Result<bool> {
-        Ok(
-            if let Some(packet) = self.packets.next(&self.handler.state_handler)? {
-                self.handler.handle_packet(packet)?;
+        Ok(if let Some((start, packet)) = self.next_packet()? {
+            self.handle_packet(start, packet)?;
 
-                true
-            } else {
-                false
-            },
-        )
+            true
+        } else {
+            false
+        })
+    }
+
+    /// Stop processing packets once the token is cancelled
+    pub fn set_cancel_token(&mut self, token: CancelToken) {
+        self.cancel_token = Some(token);
+    }
+
+    /// Whether parsing was stopped early, either because the analyser is done or parsing was cancelled
+    pub fn is_stopped(&self) -> bool {
+        self.handler.is_done()
+            || self
+                .cancel_token
+                .as_ref()
+                .is_some_and(|token| token.is_cancelled())
+    }
+
+    /// Skip damaged packets instead of returning an error
+    ///
+    /// Errors while reading or handling a packet are recorded and the packet is skipped using the
+    /// length prefix of the packet, the skipped packets can be retrieved with [`skipped`](Self::skipped)
+    pub fn set_lenient(&mut self, lenient: bool) {
+        self.lenient = lenient;
+    }
+
+    /// The packets that were skipped because of errors in lenient mode
+    pub fn skipped(&self) -> &[SkippedPacket] {
+        &self.skipped
+    }
+
+    pub fn take_skipped(&mut self) -> Vec<SkippedPacket> {
+        std::mem::take(&mut self.skipped)
+    }
+
+    /// Read the next packet and the bit position it starts at
+    fn next_packet(&mut self) -> Result<Option<(usize, Packet<'a>)>> {
+        if self.is_stopped() {
+            return Ok(None);
+        }
+        loop {
+            let start = self.packets.pos();
+            match self.packets.next(&self.handler.state_handler) {
+                Err(e) if self.lenient => {
+                    let recovered = self.packets.recover();
+                    self.skipped.push(SkippedPacket {
+                        start,
+                        end: recovered.then(|| self.packets.pos()),
+                        error: Arc::new(e),
+                    });
+                    if !recovered {
+                        return Ok(None);
+                    }
+                }
+                result => return result.map(|packet| packet.map(|packet| (start, packet))),
+            }
+        }
+    }
+
+    fn handle_packet(&mut self, start: usize, packet: Packet<'a>) -> Result<()> {
+        match self.handler.handle_packet(packet) {
+            Err(e) if self.lenient => {
+                self.skipped.push(SkippedPacket {
+                    start,
+                    end: Some(self.packets.pos()),
+                    error: Arc::new(e),
+                });
+                Ok(())
+            }
+            result => result,
+        }
+    }
+
+    /// The tick of the last processed packet
+    pub fn current_tick(&self) -> DemoTick {
+        self.handler.demo_tick
     }
 
     pub fn into_state(self) -> A::Output {
         self.handler.into_output()
     }
+
+    pub fn parser_state(&self) -> &ParserState {
+        self.handler.get_parser_state()
+    }
 }
 
-impl<A: MessageHandler + BorrowMessageHandler> DemoTicker<'_, A> {
+impl<'a, A: MessageHandler + BorrowMessageHandler, P: PacketSource<'a>> DemoTicker<'a, A, P> {
     pub fn state(&self) -> &A::Output {
         self.handler.borrow_output()
     }
 
-    pub fn parser_state(&self) -> &ParserState {
-        self.handler.get_parser_state()
-    }
-
     /// Process the next packet
     #[allow(clippy::should_implement_trait)]
-    pub fn next(&mut self) -> Result<Option<Tick<A::Output>>> {
-        Ok(
-            if let Some(packet) = self.packets.next(&self.handler.state_handler)? {
-                let tick = packet.tick();
-                self.handler.handle_packet(packet)?;
-
-                Some(Tick {
-                    state: self.handler.borrow_output(),
-                    parser_state: self.handler.get_parser_state(),
-                    tick,
-                })
-            } else {
-                None
-            },
-        )
+    pub fn next(&mut self) -> Result<Option<Tick<'_, A::Output>>> {
+        Ok(if let Some((start, packet)) = self.next_packet()? {
+            let tick = packet.tick();
+            self.handle_packet(start, packet)?;
+
+            Some(Tick {
+                state: self.handler.borrow_output(),
+                parser_state: self.handler.get_parser_state(),
+                tick,
+            })
+        } else {
+            None
+        })
+    }
+}
+
+impl<'a, A: BorrowMessageHandlers, P: PacketSource<'a>> DemoTicker<'a, A, P> {
+    /// Get references to the outputs of all combined handlers
+    ///
+    /// Combined handlers don't implement [`BorrowMessageHandler`], so [`state`](Self::state) and
+    /// [`next`](Self::next) aren't available, step through the demo with [`tick`](Self::tick) and
+    /// use this and [`parser_state`](Self::parser_state) to inspect the state in between.
+    pub fn states(&self) -> A::Borrowed<'_> {
+        self.handler.borrow_outputs()
     }
 }
 
@@ -0,0 +1,34 @@
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::ClassId;
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::Stream;
+
+/// Callbacks for following along while the parser reads the messages and entity updates of a packet,
+/// see [`RawPacketStream::next_observed`](crate::demo::parser::RawPacketStream::next_observed)
+///
+/// All methods do nothing by default, `()` can be used when nothing needs to be observed.
+pub trait ParseObserver {
+    /// Called before a message is read from a signon or message packet
+    fn start_message(&mut self, _message_type: MessageType) {}
+
+    /// Called after a message is read, `message` is `None` for messages that are skipped.
+    ///
+    /// `data` starts after the message type, `bits` is the size of the message including the message type
+    fn end_message(
+        &mut self,
+        _message_type: MessageType,
+        _message: Option<&Message>,
+        _data: &Stream,
+        _bits: usize,
+    ) {
+    }
+
+    /// Called after an update for an entity of a known server class is read from a `PacketEntities` message,
+    /// `bits` includes the entity index and update type
+    fn entity_update(&mut self, _class: ClassId, _bits: usize) {}
+
+    /// Called after a prop of an entity update is decoded, `bits` includes the prop index
+    fn prop(&mut self, _identifier: SendPropIdentifier, _bits: usize) {}
+}
+
+impl ParseObserver for () {}
@@ -18,13 +18,13 @@ use std::collections::{BTreeMap, HashMap};
  * scoreboard for every player if they took a snapshot at the time the demo finishes (such as the end
  * of a match or round).
  */
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct PlayerSummaryAnalyzer {
     state: PlayerSummaryState,
     user_id_map: HashMap<EntityId, UserId>,
 }
 
-#[derive(Debug, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Serialize, Deserialize, PartialEq, Default, Clone)]
 pub struct PlayerSummary {
     pub points: u32,
     pub kills: u32,
@@ -45,7 +45,7 @@ pub struct PlayerSummary {
     pub damage_dealt: u32,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize, PartialEq)]
+#[derive(Default, Debug, Serialize, Deserialize, PartialEq, Clone)]
 pub struct PlayerSummaryState {
     pub player_summaries: HashMap<UserId, PlayerSummary>,
     pub users: BTreeMap<UserId, UserInfo>,
@@ -66,6 +66,10 @@ impl MessageHandler for PlayerSummaryAnalyzer {
         }
     }
 
+    fn entity_classes(&self) -> Option<Vec<String>> {
+        Some(vec!["CTFPlayer".into(), "CTFPlayerResource".into()])
+    }
+
     fn into_output(self, _parser_state: &ParserState) -> <Self as MessageHandler>::Output {
         self.state
     }
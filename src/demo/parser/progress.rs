@@ -0,0 +1,59 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::parser::{DemoParser, DemoTicker, MessageHandler, PacketSource};
+use crate::Result;
+
+/// How far the parser has progressed through the demo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Progress {
+    /// Number of bytes read from the demo, including the header
+    pub bytes: usize,
+    /// Size of the demo in bytes, if known
+    pub total_bytes: Option<usize>,
+    /// Tick of the last processed packet
+    pub tick: DemoTick,
+    /// Number of ticks in the demo according to the header
+    pub total_ticks: u32,
+}
+
+impl Progress {
+    /// Progress as a fraction between 0 and 1
+    ///
+    /// Based on the number of bytes read if the size of the demo is known, falling back to the
+    /// number of ticks from the header. Returns `None` if neither is known, which can be the case
+    /// for demos that weren't properly finished.
+    pub fn fraction(&self) -> Option<f32> {
+        let fraction = match self.total_bytes {
+            Some(total_bytes) if total_bytes > 0 => self.bytes as f32 / total_bytes as f32,
+            _ if self.total_ticks > 0 => u32::from(self.tick) as f32 / self.total_ticks as f32,
+            _ => return None,
+        };
+        Some(fraction.clamp(0.0, 1.0))
+    }
+}
+
+impl<'a, A: MessageHandler, P: PacketSource<'a>> DemoTicker<'a, A, P> {
+    /// Get the progress through the demo
+    pub fn progress(&self) -> Progress {
+        Progress {
+            bytes: self.packets.pos() / 8,
+            total_bytes: self.packets.total_len().map(|len| len / 8),
+            tick: self.current_tick(),
+            total_ticks: self.total_ticks,
+        }
+    }
+}
+
+impl<'a, A: MessageHandler, P: PacketSource<'a>> DemoParser<'a, A, P> {
+    /// Parse the demo, calling `callback` with the progress after every packet
+    pub fn parse_with_progress(
+        self,
+        mut callback: impl FnMut(Progress),
+    ) -> Result<(Header, A::Output)> {
+        let (header, mut ticker) = self.ticker()?;
+        while ticker.tick()? {
+            callback(ticker.progress());
+        }
+        Ok((header, ticker.into_state()))
+    }
+}
@@ -0,0 +1,223 @@
+use crate::demo::header::Header;
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{
+    is_truncated, packet_error, packet_frame, PacketPosition, PacketSource, SeekablePacketSource,
+};
+use crate::{Parse, ParseError, ParserState, Result};
+use bitbuffer::{BitError, BitRead, BitReadBuffer, BitReadStream, LittleEndian};
+use std::io::{Read, Seek, SeekFrom};
+
+/// Size of the demo header in bytes
+const HEADER_SIZE: usize = 1072;
+
+/// A packet stream that incrementally reads packets from any [`Read`] source
+///
+/// Only a single packet is kept in memory at a time, so memory usage is bound by the largest
+/// packet in the demo instead of the size of the demo file.
+pub struct ReaderPacketStream<R: Read> {
+    reader: R,
+    buffer: Vec<u8>,
+    pos: usize,
+    total_size: Option<usize>,
+    packet_index: usize,
+    recoverable: bool,
+    pub ended: bool,
+    pub incomplete: bool,
+}
+
+impl<R: Read> ReaderPacketStream<R> {
+    pub fn new(reader: R) -> Self {
+        ReaderPacketStream {
+            reader,
+            buffer: Vec::new(),
+            pos: 0,
+            total_size: None,
+            packet_index: 0,
+            recoverable: false,
+            ended: false,
+            incomplete: false,
+        }
+    }
+
+    /// Set the size of the demo in bytes, used for progress reporting
+    pub fn with_total_size(mut self, bytes: usize) -> Self {
+        self.total_size = Some(bytes);
+        self
+    }
+
+    /// Get the bit position in the source
+    pub fn pos(&self) -> usize {
+        self.pos * 8
+    }
+
+    /// Read the demo header, this should be called before reading any packets
+    pub fn read_header(&mut self) -> Result<Header> {
+        self.buffer.clear();
+        self.read_bytes(HEADER_SIZE)?;
+        let data = std::mem::take(&mut self.buffer);
+        let mut stream = BitReadStream::new(BitReadBuffer::new_owned(data, LittleEndian));
+        Ok(Header::read(&mut stream)?)
+    }
+
+    pub fn next(&mut self, state: &ParserState) -> Result<Option<Packet<'static>>> {
+        if self.ended {
+            return Ok(None);
+        }
+
+        let start = self.pos();
+        match self.read_packet_data(state.config().get_limits().max_packet_size) {
+            Ok(()) => {}
+            Err(e) if is_truncated(&e) => {
+                self.ended = true;
+                self.incomplete = true;
+                return Ok(None);
+            }
+            Err(e) => {
+                self.ended = true;
+                return Err(e.with_context(|context| {
+                    context.packet_index = Some(self.packet_index);
+                    context.position = Some(start);
+                }));
+            }
+        }
+
+        let data = std::mem::take(&mut self.buffer);
+        let packet_stream = BitReadStream::new(BitReadBuffer::new_owned(data, LittleEndian));
+        match Packet::parse(&mut packet_stream.clone(), state) {
+            Ok(packet @ Packet::Stop(_)) => {
+                self.ended = true;
+                Ok(Some(packet))
+            }
+            Ok(packet) => {
+                self.packet_index += 1;
+                Ok(Some(packet))
+            }
+            Err(e) => {
+                self.ended = true;
+                // the full packet has already been read, so we can continue with the next packet
+                self.recoverable = true;
+                Err(packet_error(e, &packet_stream, start, self.packet_index))
+            }
+        }
+    }
+
+    /// Read the raw bytes for the next packet into the buffer
+    fn read_packet_data(&mut self, max_packet_size: Option<u32>) -> Result<()> {
+        self.buffer.clear();
+        self.read_bytes(1)?;
+        let packet_type = self
+            .buffer
+            .first()
+            .copied()
+            .ok_or(ParseError::InvalidDemo("empty packet"))?;
+        let packet_type = PacketType::read(&mut BitReadStream::new(BitReadBuffer::new(
+            &[packet_type],
+            LittleEndian,
+        )))
+        .map_err(|_| ParseError::InvalidPacketType(packet_type))?;
+
+        let (fixed_size, has_length) = packet_frame(packet_type);
+        self.read_bytes(fixed_size)?;
+        if has_length {
+            self.read_length_prefixed(max_packet_size)?;
+        }
+        Ok(())
+    }
+
+    /// Continue with the packet following the packet that caused the last error
+    ///
+    /// Returns `false` if the packet type is invalid, as the size of the packet is unknown in that case
+    pub fn recover(&mut self) -> bool {
+        if std::mem::take(&mut self.recoverable) {
+            self.packet_index += 1;
+            self.ended = false;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn read_length_prefixed(&mut self, max_packet_size: Option<u32>) -> Result<()> {
+        let length_start = self.buffer.len();
+        self.read_bytes(4)?;
+        let length_bytes: [u8; 4] = self
+            .buffer
+            .get(length_start..)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(ParseError::InvalidDemo("missing packet length"))?;
+        let length = u32::from_le_bytes(length_bytes);
+        match max_packet_size {
+            Some(limit) if length > limit => {
+                // skip over the packet without buffering it, so parsing can continue after it
+                let skipped = std::io::copy(
+                    &mut (&mut self.reader).take(length as u64),
+                    &mut std::io::sink(),
+                )?;
+                self.pos += skipped as usize;
+                self.recoverable = skipped == length as u64;
+                Err(ParseError::PacketTooLarge {
+                    size: length,
+                    limit,
+                })
+            }
+            _ => self.read_bytes(length as usize),
+        }
+    }
+
+    fn read_bytes(&mut self, count: usize) -> Result<()> {
+        // read through `take` so a malformed length doesn't cause a huge allocation up front
+        let read = (&mut self.reader)
+            .take(count as u64)
+            .read_to_end(&mut self.buffer)?;
+        self.pos += read;
+        if read < count {
+            return Err(ParseError::ReadError(BitError::NotEnoughData {
+                requested: count * 8,
+                bits_left: read * 8,
+            }));
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> PacketSource<'static> for ReaderPacketStream<R> {
+    fn read_header(&mut self) -> Result<Header> {
+        ReaderPacketStream::read_header(self)
+    }
+
+    fn next(&mut self, state: &ParserState) -> Result<Option<Packet<'static>>> {
+        ReaderPacketStream::next(self, state)
+    }
+
+    fn pos(&self) -> usize {
+        ReaderPacketStream::pos(self)
+    }
+
+    fn total_len(&self) -> Option<usize> {
+        self.total_size.map(|bytes| bytes * 8)
+    }
+
+    fn recover(&mut self) -> bool {
+        ReaderPacketStream::recover(self)
+    }
+}
+
+impl<R: Read + Seek> SeekablePacketSource<'static> for ReaderPacketStream<R> {
+    fn packet_position(&self) -> PacketPosition {
+        PacketPosition {
+            pos: self.pos(),
+            packet_index: self.packet_index,
+        }
+    }
+
+    fn seek(&mut self, position: PacketPosition) -> Result<()> {
+        let pos = position.pos / 8;
+        self.reader.seek(SeekFrom::Start(pos as u64))?;
+        self.pos = pos;
+        self.packet_index = position.packet_index;
+        self.recoverable = false;
+        self.ended = false;
+        self.incomplete = false;
+        Ok(())
+    }
+}
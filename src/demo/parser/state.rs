@@ -10,16 +10,19 @@ use crate::demo::message::packetentities::{
 use crate::demo::message::stringtable::StringTableMeta;
 use crate::demo::message::{Message, MessageType};
 use crate::demo::packet::datatable::{
-    ClassId, ParseSendTable, SendTable, SendTableName, ServerClass,
+    ClassId, ParseSendTable, PropLocation, SendTable, SendTableName, ServerClass,
 };
 use crate::demo::packet::stringtable::StringTableEntry;
 
 use crate::demo::data::DemoTick;
-use crate::demo::sendprop::{SendProp, SendPropIdentifier};
+use crate::demo::parser::config::{MessageTypeSet, ParserConfig, DEFAULT_PROTOCOL_VERSION};
+use crate::demo::parser::entities::{EntityStore, PropChange};
+use crate::demo::sendprop::{SendProp, SendPropDefinition, SendPropIdentifier};
 use crate::nullhasher::NullHasherBuilder;
 use crate::{Result, Stream};
 use serde::{Deserialize, Serialize};
-use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::Arc;
 #[cfg(feature = "trace")]
 use tracing::warn;
 
@@ -30,24 +33,62 @@ pub struct DemoMeta {
     pub interval_per_tick: f32,
 }
 
+/// The state needed to parse the packets of a demo
+///
+/// The data tables, server classes, game event definitions and static baselines rarely change
+/// after the start of the demo, they are shared between clones of the state so the state can be
+/// cloned cheaply, for example for the keyframes of a [`DemoTicker`](crate::demo::parser::DemoTicker).
 #[derive(Clone)]
 pub struct ParserState {
-    pub static_baselines: HashMap<ClassId, StaticBaseline, NullHasherBuilder>,
-    pub parsed_static_baselines: RefCell<HashMap<ClassId, Vec<SendProp>, NullHasherBuilder>>,
-    pub event_definitions: Vec<GameEventDefinition>,
+    /// The raw baselines borrow from reference counted buffers, so they are shared with an `Rc` instead of an `Arc`
+    pub static_baselines: Rc<HashMap<ClassId, StaticBaseline, NullHasherBuilder>>,
+    pub event_definitions: Arc<Vec<GameEventDefinition>>,
     pub string_tables: Vec<StringTableMeta>,
     pub entity_classes: HashMap<EntityId, ClassId, NullHasherBuilder>,
+    /// Serial numbers of all entities that currently exist
+    pub entity_serials: HashMap<EntityId, u32, NullHasherBuilder>,
     // indexed by ClassId
-    pub send_tables: Vec<SendTable>,
-    pub server_classes: Vec<ServerClass>,
+    pub send_tables: Arc<Vec<SendTable>>,
+    /// Locations of the props that are included multiple times in the flattened props, indexed by ClassId
+    prop_locations: Arc<Vec<FnvHashMap<u32, PropLocation>>>,
+    pub server_classes: Arc<Vec<ServerClass>>,
     pub instance_baselines: [Baseline; 2],
     pub demo_meta: DemoMeta,
+    /// Full state of all entities, only maintained when entity tracking is enabled
+    pub entities: EntityStore,
     analyser_handles: fn(message_type: MessageType) -> bool,
+    /// Message types handled by the analyser instance, overrides `analyser_handles` when set
+    analyser_message_types: Option<MessageTypeSet>,
     handle_entities: bool,
-    parse_all: bool,
+    /// Names of the server classes to decode entity props for, all classes if not set
+    entity_class_filter: Option<Vec<String>>,
+    /// Whether to decode the props of each server class, indexed by ClassId
+    decoded_classes: Vec<bool>,
+    config: ParserConfig,
     pub protocol_version: u32,
 }
 
+fn should_handle_entities(config: &ParserConfig, analyser_handles: bool) -> bool {
+    config.should_decode_entities().unwrap_or_else(|| {
+        analyser_handles || config.is_parse_all() || config.is_tracking_entities()
+    })
+}
+
+/// The locations of the props that share their identifier with another prop, by prop index
+fn repeated_prop_locations(
+    flat: &[(SendPropDefinition, PropLocation)],
+) -> FnvHashMap<u32, PropLocation> {
+    let mut counts: FnvHashMap<SendPropIdentifier, usize> = FnvHashMap::default();
+    for (prop, _) in flat.iter() {
+        *counts.entry(prop.identifier).or_default() += 1;
+    }
+    flat.iter()
+        .enumerate()
+        .filter(|(_, (prop, _))| counts.get(&prop.identifier).is_some_and(|count| *count > 1))
+        .map(|(index, (_, location))| (index as u32, *location))
+        .collect()
+}
+
 #[derive(Clone)]
 pub struct StaticBaseline {
     pub class_id: ClassId,
@@ -77,23 +118,46 @@ impl ParserState {
         analyser_handles: fn(message_type: MessageType) -> bool,
         parse_all: bool,
     ) -> Self {
+        let mut state =
+            Self::with_config(analyser_handles, ParserConfig::new().parse_all(parse_all));
+        state.protocol_version = protocol_version;
+        state
+    }
+
+    pub fn with_config(
+        analyser_handles: fn(message_type: MessageType) -> bool,
+        config: ParserConfig,
+    ) -> Self {
+        let handle_entities =
+            should_handle_entities(&config, analyser_handles(MessageType::PacketEntities));
         ParserState {
-            static_baselines: HashMap::with_hasher(NullHasherBuilder),
-            parsed_static_baselines: RefCell::new(HashMap::with_hasher(NullHasherBuilder)),
-            event_definitions: Vec::new(),
+            static_baselines: Rc::new(HashMap::with_hasher(NullHasherBuilder)),
+            event_definitions: Arc::default(),
             string_tables: Vec::new(),
             entity_classes: HashMap::with_hasher(NullHasherBuilder),
-            send_tables: Vec::new(),
-            server_classes: Vec::new(),
+            entity_serials: HashMap::with_hasher(NullHasherBuilder),
+            send_tables: Arc::default(),
+            prop_locations: Arc::default(),
+            server_classes: Arc::default(),
             instance_baselines: [Baseline::default(), Baseline::default()],
             demo_meta: DemoMeta::default(),
+            entities: EntityStore::default(),
             analyser_handles,
-            handle_entities: analyser_handles(MessageType::PacketEntities) || parse_all,
-            parse_all,
-            protocol_version,
+            analyser_message_types: None,
+            handle_entities,
+            entity_class_filter: None,
+            decoded_classes: Vec::new(),
+            protocol_version: config
+                .get_protocol_version()
+                .unwrap_or(DEFAULT_PROTOCOL_VERSION),
+            config,
         }
     }
 
+    pub fn config(&self) -> &ParserConfig {
+        &self.config
+    }
+
     pub fn get_static_baseline(
         &self,
         class_id: ClassId,
@@ -110,24 +174,18 @@ impl ParserState {
                 Ok(Vec::new())
             }
         }
-        // let mut cached = self.parsed_static_baselines.borrow_mut();
-        // Ok(match cached.entry(class_id) {
-        //     Entry::Occupied(entry) => entry.get().as_slice(),
-        //     Entry::Vacant(entry) => match self.static_baselines.get(&class_id) {
-        //         Some(static_baseline) => {
-        //             let props = static_baseline.parse(send_table)?;
-        //             entry.insert(props).as_slice()
-        //         }
-        //         None => {
-        //             #[cfg(feature = "trace")]
-        //             warn!(
-        //                 class_id = display(class_id),
-        //                 "class without static baseline"
-        //             );
-        //             &[]
-        //         }
-        //     },
-        // })
+    }
+
+    /// Get the location of a prop of a class, for props that are included multiple times
+    ///
+    /// Sub tables that are included more than once, like the elements of `m_AnimOverlay`, share
+    /// the same prop identifiers, the location tells them apart. Props that are only included
+    /// once don't have a location.
+    pub fn prop_location(&self, class_id: ClassId, prop_index: u32) -> Option<PropLocation> {
+        self.prop_locations
+            .get(usize::from(class_id))?
+            .get(&prop_index)
+            .copied()
     }
 
     fn get_instance_baseline(&self, index: BaselineIndex) -> &Baseline {
@@ -151,24 +209,12 @@ impl ParserState {
         class_id: ClassId,
         send_table: &SendTable,
         is_delta: bool,
-    ) -> Result<Cow<[SendProp]>> {
+    ) -> Result<Cow<'_, [SendProp]>> {
         match self.get_instance_baseline(baseline_index).get(entity_index) {
             Some(baseline) if baseline.server_class == class_id && is_delta => {
                 Ok(Cow::Borrowed(&baseline.props))
             }
-            _ => match self.static_baselines.get(&class_id) {
-                Some(_static_baseline) => {
-                    Ok(Cow::Owned(self.get_static_baseline(class_id, send_table)?))
-                }
-                None => {
-                    #[cfg(feature = "trace")]
-                    warn!(
-                        class_id = display(class_id),
-                        "class without static baseline"
-                    );
-                    Ok(Cow::Owned(Vec::new()))
-                }
-            },
+            _ => Ok(Cow::Owned(self.get_static_baseline(class_id, send_table)?)),
         }
     }
 
@@ -178,28 +224,47 @@ impl ParserState {
         server_classes: Vec<ServerClass>,
     ) -> Result<()> {
         if self.handle_entities {
-            let mut send_tables: FnvHashMap<SendTableName, SendTable> = parse_tables
+            let mut send_tables: FnvHashMap<
+                SendTableName,
+                (SendTable, FnvHashMap<u32, PropLocation>),
+            > = parse_tables
                 .iter()
                 .map(|parse_table| {
-                    let flat = parse_table.flatten_props(parse_tables);
+                    let flat = parse_table.flatten_props_with_locations(parse_tables)?;
+                    let locations = repeated_prop_locations(&flat);
                     Ok((
                         parse_table.name.clone(),
-                        SendTable {
-                            name: parse_table.name.clone(),
-                            needs_decoder: parse_table.needs_decoder,
-                            flattened_props: flat?,
-                        },
+                        (
+                            SendTable {
+                                name: parse_table.name.clone(),
+                                needs_decoder: parse_table.needs_decoder,
+                                flattened_props: flat.into_iter().map(|(prop, _)| prop).collect(),
+                            },
+                            locations,
+                        ),
                     ))
                 })
                 .collect::<Result<_>>()?;
 
-            self.server_classes = server_classes;
+            self.server_classes = Arc::new(server_classes);
+            self.entities.resolve_subscriptions(&self.server_classes);
+            if let Some(filter) = &self.entity_class_filter {
+                self.decoded_classes = self
+                    .server_classes
+                    .iter()
+                    .map(|class| filter.iter().any(|name| name == class.name.as_str()))
+                    .collect();
+            }
 
-            self.send_tables.reserve(self.server_classes.len());
+            let tables = Arc::make_mut(&mut self.send_tables);
+            let prop_locations = Arc::make_mut(&mut self.prop_locations);
+            tables.reserve(self.server_classes.len());
+            prop_locations.reserve(self.server_classes.len());
 
             for class in self.server_classes.iter() {
-                if let Some(table) = send_tables.remove(&class.data_table) {
-                    self.send_tables.push(table);
+                if let Some((table, locations)) = send_tables.remove(&class.data_table) {
+                    tables.push(table);
+                    prop_locations.push(locations);
                 } else {
                     #[cfg(feature = "trace")]
                     warn!(class = debug(class), "class without table");
@@ -215,12 +280,38 @@ impl ParserState {
     }
 
     pub fn should_parse_message(&self, message_type: MessageType) -> bool {
-        self.parse_all
-            || if message_type == MessageType::PacketEntities {
-                self.handle_entities
-            } else {
-                Self::does_handle(message_type) || (self.analyser_handles)(message_type)
-            }
+        if message_type == MessageType::PacketEntities {
+            self.handle_entities
+        } else if Self::does_handle(message_type) {
+            true
+        } else if self.config.is_skipped(message_type) {
+            false
+        } else {
+            self.config.is_parse_all()
+                || self.analyser_handles(message_type)
+                || self.config.is_parsed(message_type)
+        }
+    }
+
+    /// Whether the analyser handles the message type
+    pub fn analyser_handles(&self, message_type: MessageType) -> bool {
+        match &self.analyser_message_types {
+            Some(message_types) => message_types.contains(message_type),
+            None => (self.analyser_handles)(message_type),
+        }
+    }
+
+    /// Set the message types handled by the analyser instance, `None` to use the `does_handle` of the analyser
+    pub fn set_analyser_message_types(&mut self, message_types: Option<Vec<MessageType>>) {
+        self.analyser_message_types = message_types.map(|message_types| {
+            let mut set = MessageTypeSet::default();
+            set.extend(message_types);
+            set
+        });
+        self.handle_entities = should_handle_entities(
+            &self.config,
+            self.analyser_handles(MessageType::PacketEntities),
+        );
     }
 
     pub fn does_handle(message_type: MessageType) -> bool {
@@ -243,16 +334,30 @@ impl ParserState {
                 self.demo_meta.interval_per_tick = message.interval_per_tick;
             }
             Message::GameEventList(message) => {
-                self.event_definitions = message.event_list;
+                self.event_definitions = Arc::new(message.event_list);
             }
             Message::PacketEntities(ent_message) => {
+                // a full update deletes all existing entities
+                if ent_message.delta.is_none() {
+                    self.entity_serials.clear();
+                }
+
                 for removed in ent_message.removed_entities.iter() {
                     self.entity_classes.remove(removed);
+                    self.entity_serials.remove(removed);
                 }
 
                 for entity in ent_message.entities.iter() {
-                    if entity.update_type == UpdateType::Delete {
-                        self.entity_classes.remove(&entity.entity_index);
+                    match entity.update_type {
+                        UpdateType::Delete => {
+                            self.entity_classes.remove(&entity.entity_index);
+                            self.entity_serials.remove(&entity.entity_index);
+                        }
+                        UpdateType::Enter => {
+                            self.entity_serials
+                                .insert(entity.entity_index, entity.serial_number);
+                        }
+                        _ => {}
                     }
                     self.entity_classes
                         .insert(entity.entity_index, entity.server_class);
@@ -294,12 +399,62 @@ impl ParserState {
         }
     }
 
+    /// Only decode the props of entities of the specified server classes
+    ///
+    /// Entities of other classes are still tracked, but their props are skipped while parsing.
+    pub fn set_entity_class_filter(&mut self, class_names: Option<Vec<String>>) {
+        self.entity_class_filter = class_names;
+        self.decoded_classes.clear();
+    }
+
+    /// Whether the props of entities of the server class are decoded
+    pub fn should_decode_class(&self, class_id: ClassId) -> bool {
+        self.entity_class_filter.is_none()
+            || self
+                .decoded_classes
+                .get(usize::from(class_id))
+                .copied()
+                .unwrap_or_default()
+    }
+
+    /// Whether entity props should be decoded on demand instead of while parsing
+    pub fn should_decode_lazily(&self) -> bool {
+        self.config.is_lazy_props() && !self.config.is_tracking_entities()
+    }
+
+    /// Apply the entity updates to the entity store, if entity tracking is enabled
+    pub fn update_entities(&mut self, message: &PacketEntitiesMessage, tick: DemoTick) {
+        if self.config.is_tracking_entities() {
+            let mut entities = std::mem::take(&mut self.entities);
+            entities.update(message, tick, self);
+            self.entities = entities;
+        }
+    }
+
+    /// Take the changes to subscribed props caused by the last entity update
+    pub fn take_prop_changes(&mut self) -> Vec<PropChange> {
+        self.entities.take_changes()
+    }
+
+    /// Get the full state of all entities of the server class with the provided name
+    ///
+    /// Requires entity tracking to be enabled
+    pub fn entities_by_class_name(&self, name: &str) -> impl Iterator<Item = &PacketEntity> {
+        let class_id = self
+            .server_classes
+            .iter()
+            .find(|class| class.name.as_str() == name)
+            .map(|class| class.id);
+        class_id
+            .into_iter()
+            .flat_map(|class_id| self.entities.by_class(class_id))
+    }
+
     pub fn handle_string_entry(&mut self, table: &str, _index: usize, entry: &StringTableEntry) {
         if table == "instancebaseline" {
             if let (Some(extra), Ok(class_id)) = (&entry.extra_data, entry.text().parse()) {
                 let baseline = StaticBaseline::new(class_id, extra.data.to_owned());
-                self.static_baselines.insert(class_id, baseline);
-                self.parsed_static_baselines.borrow_mut().remove(&class_id);
+                Rc::make_mut(&mut self.static_baselines).insert(class_id, baseline);
             }
         }
     }
@@ -415,6 +570,7 @@ impl From<BaselineEntity> for PacketEntity {
             delay: None,
             delta: None,
             baseline_index: BaselineIndex::First,
+            lazy_props: None,
         }
     }
 }
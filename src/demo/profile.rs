@@ -0,0 +1,215 @@
+use crate::demo::header::Header;
+use crate::demo::message::usermessage::UserMessageType;
+use crate::demo::message::{Message, MessageType};
+use crate::demo::packet::datatable::ClassId;
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, NullHandler, ParseObserver, ParserConfig, RawPacketStream};
+use crate::demo::sendprop::SendPropIdentifier;
+use crate::{Demo, ParserState, Result, Stream};
+use bitbuffer::BitRead;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::time::{Duration, Instant};
+
+/// The number of occurrences and total size of one kind of data
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SizeEntry {
+    pub count: usize,
+    pub bits: usize,
+}
+
+impl SizeEntry {
+    pub fn bytes(&self) -> usize {
+        self.bits / 8
+    }
+
+    fn add(&mut self, bits: usize) {
+        self.count += 1;
+        self.bits += bits;
+    }
+}
+
+/// The size of a demo broken down by the kind of data, and the time spent parsing it
+///
+/// Each breakdown is a more detailed look into a part of the one before it, the size of the game
+/// events for example is also counted in the size of the `GameEvent` messages and the `Message` packets.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SizeProfile {
+    /// The size of the demo, including the header
+    pub total_bits: usize,
+    /// The size of each packet type, including the packet headers
+    pub packets: BTreeMap<String, SizeEntry>,
+    /// The size of each message type, including the message type
+    pub messages: BTreeMap<String, SizeEntry>,
+    /// The size of the user messages of each type
+    pub user_messages: BTreeMap<String, SizeEntry>,
+    /// The size of the game events of each type
+    pub game_events: BTreeMap<String, SizeEntry>,
+    /// The size of the entity updates for each server class, including the entity index
+    pub server_classes: BTreeMap<String, SizeEntry>,
+    /// The size of each sendprop in the entity updates, including the prop index
+    pub props: BTreeMap<String, SizeEntry>,
+    /// The time spent parsing the packets that don't contain messages
+    pub packet_time: BTreeMap<String, Duration>,
+    /// The time spent parsing each message type
+    pub message_time: BTreeMap<String, Duration>,
+}
+
+impl SizeProfile {
+    /// The entries of a breakdown, largest first
+    pub fn largest(entries: &BTreeMap<String, SizeEntry>) -> Vec<(&str, SizeEntry)> {
+        let mut sorted: Vec<_> = entries
+            .iter()
+            .map(|(name, entry)| (name.as_str(), *entry))
+            .collect();
+        sorted.sort_by_key(|(_, entry)| std::cmp::Reverse(entry.bits));
+        sorted
+    }
+}
+
+/// Collects the sizes and parse times while the parser reads the demo
+#[derive(Default)]
+struct Profiler {
+    profile: SizeProfile,
+    message_start: Option<Instant>,
+    /// Entity update sizes by class, the names of the classes are only known after the data tables
+    server_classes: HashMap<ClassId, SizeEntry>,
+    props: HashMap<SendPropIdentifier, SizeEntry>,
+}
+
+impl ParseObserver for Profiler {
+    fn start_message(&mut self, _message_type: MessageType) {
+        self.message_start = Some(Instant::now());
+    }
+
+    fn end_message(
+        &mut self,
+        message_type: MessageType,
+        message: Option<&Message>,
+        data: &Stream,
+        bits: usize,
+    ) {
+        let name = format!("{:?}", message_type);
+        if let Some(start) = self.message_start.take() {
+            *self.profile.message_time.entry(name.clone()).or_default() += start.elapsed();
+        }
+        self.profile.messages.entry(name).or_default().add(bits);
+
+        match message {
+            Some(Message::UserMessage(user_message)) => {
+                let name = match data.clone().read::<UserMessageType>() {
+                    Ok(message_type) => format!("{:?}", message_type),
+                    Err(_) => user_message.message_type().to_string(),
+                };
+                self.profile
+                    .user_messages
+                    .entry(name)
+                    .or_default()
+                    .add(bits)
+            }
+            Some(Message::GameEvent(event)) => self
+                .profile
+                .game_events
+                .entry(event.event_type.as_str().into())
+                .or_default()
+                .add(bits),
+            _ => {}
+        }
+    }
+
+    fn entity_update(&mut self, class: ClassId, bits: usize) {
+        self.server_classes.entry(class).or_default().add(bits);
+    }
+
+    fn prop(&mut self, identifier: SendPropIdentifier, bits: usize) {
+        self.props.entry(identifier).or_default().add(bits);
+    }
+}
+
+impl Profiler {
+    fn finish(
+        mut self,
+        state: &ParserState,
+        prop_names: &HashMap<SendPropIdentifier, String>,
+    ) -> SizeProfile {
+        for (class, size) in self.server_classes {
+            if let Some(class) = state.server_classes.get(usize::from(class)) {
+                self.profile
+                    .server_classes
+                    .insert(class.name.to_string(), size);
+            }
+        }
+        for (identifier, size) in self.props {
+            let name = match prop_names.get(&identifier) {
+                Some(name) => name.clone(),
+                None => identifier.to_string(),
+            };
+            let entry = self.profile.props.entry(name).or_default();
+            entry.count += size.count;
+            entry.bits += size.bits;
+        }
+        self.profile
+    }
+}
+
+/// Measure how much each packet type, message type, user message, game event, server class and
+/// sendprop contributes to the size of a demo
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::profile::{profile_demo, SizeProfile};
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// let file = std::fs::read("in.dem")?;
+/// let profile = profile_demo(&Demo::new(&file))?;
+/// for (class, size) in SizeProfile::largest(&profile.server_classes).iter().take(10) {
+///     println!("{class}: {} bytes", size.bytes());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn profile_demo(demo: &Demo) -> Result<SizeProfile> {
+    let mut stream = demo.get_stream();
+    let mut profiler = Profiler::default();
+    profiler.profile.total_bits = stream.bit_len();
+    Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut prop_names = HashMap::new();
+
+    loop {
+        let start = packets.pos();
+        let parse_start = Instant::now();
+        // the profile covers the complete packets of a truncated demo
+        let Some(packet) = packets.next_observed(&handler.state_handler, &mut profiler)? else {
+            break;
+        };
+        let parse_time = parse_start.elapsed();
+
+        let packet_type = packet.packet_type();
+        let profile = &mut profiler.profile;
+        profile
+            .packets
+            .entry(packet_type.as_str().into())
+            .or_default()
+            .add(packets.pos() - start);
+        // the parse time of message packets is counted per message
+        if !matches!(packet_type, PacketType::Signon | PacketType::Message) {
+            *profile
+                .packet_time
+                .entry(packet_type.as_str().into())
+                .or_default() += parse_time;
+        }
+
+        if let Packet::DataTables(tables) = &packet {
+            for table in tables.tables.iter() {
+                for prop in table.props.iter() {
+                    prop_names.insert(prop.identifier, format!("{}.{}", table.name, prop.name));
+                }
+            }
+        }
+
+        handler.handle_packet(packet)?;
+    }
+
+    Ok(profiler.finish(&handler.state_handler, &prop_names))
+}
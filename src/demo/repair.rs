@@ -0,0 +1,122 @@
+use crate::demo::header::Header;
+use crate::demo::packet::PacketType;
+use crate::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use crate::demo::writer::DemoWriter;
+use crate::{Demo, Result};
+use bitbuffer::BitRead;
+use serde::{Deserialize, Serialize};
+use std::io::{Seek, Write};
+
+/// Diagnostics of a repaired demo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RepairReport {
+    /// The header as found in the input demo
+    pub original_header: Header,
+    /// The header of the repaired demo
+    pub header: Header,
+    /// The input ended in the middle of a packet
+    pub truncated: bool,
+    /// The packet that couldn't be parsed, the demo is cut off before this packet
+    pub error: Option<String>,
+    /// Damaged packets in the middle of the demo that were left out of the repaired demo
+    pub skipped: Vec<String>,
+    /// The input didn't end with a stop packet, a stop packet has been added
+    pub missing_stop: bool,
+    /// The number of packets copied from the input demo
+    pub packets: usize,
+    /// The number of bytes after the last complete packet that were dropped
+    pub dropped_bytes: usize,
+    /// The tick interval used to calculate the duration
+    pub interval_per_tick: f32,
+}
+
+impl RepairReport {
+    /// Whether anything in the input demo needed to be fixed
+    pub fn is_damaged(&self) -> bool {
+        self.truncated
+            || self.error.is_some()
+            || !self.skipped.is_empty()
+            || self.missing_stop
+            || self.dropped_bytes > 0
+            || self.original_header.ticks != self.header.ticks
+            || self.original_header.frames != self.header.frames
+    }
+}
+
+/// Repair a demo that wasn't closed properly, for example because the game crashed while recording
+///
+/// The demo is cut off after the last packet that can be fully parsed, a stop packet is added and
+/// the tick count, frame count, duration and signon size in the header are recalculated.
+/// Damaged packets in the middle of the demo are left out if their length can still be read,
+/// the rest of the demo is kept.
+/// The duration is based on the tick interval from the server info, or the `sv_minupdaterate`
+/// convar when there is no server info.
+///
+/// ```no_run
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::repair::repair_demo;
+/// # use std::fs::File;
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// let file = std::fs::read("crashed.dem")?;
+/// let demo = Demo::new(&file);
+/// let (_, report) = repair_demo(&demo, File::create("repaired.dem")?)?;
+/// if report.truncated {
+///     println!("dropped {} bytes of incomplete data", report.dropped_bytes);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn repair_demo<W: Write + Seek>(demo: &Demo, out: W) -> Result<(W, RepairReport)> {
+    let mut stream = demo.get_stream();
+    let total_bits = stream.bit_len();
+    let original_header = Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut writer = DemoWriter::new(out, original_header.clone())?.update_header(true);
+
+    let mut error = None;
+    let mut skipped = Vec::new();
+    let mut missing_stop = true;
+    let mut packet_count = 0;
+    let mut end = packets.pos();
+
+    loop {
+        let packet = match packets.next(&handler.state_handler) {
+            Ok(Some(packet)) => packet,
+            Ok(None) => break,
+            Err(e) if packets.recover() => {
+                skipped.push(e.to_string());
+                continue;
+            }
+            Err(e) => {
+                error = Some(e.to_string());
+                break;
+            }
+        };
+        if packet.packet_type() == PacketType::Stop {
+            missing_stop = false;
+        }
+        // only packets that are fully handled end up in the repaired demo
+        if let Err(e) = handler.handle_packet(packet.clone()) {
+            error = Some(e.to_string());
+            break;
+        }
+        writer.write_packet(packet)?;
+        packet_count += 1;
+        end = packets.pos();
+    }
+
+    let report = RepairReport {
+        original_header,
+        header: writer.header(),
+        truncated: packets.incomplete,
+        error,
+        skipped,
+        missing_stop,
+        packets: packet_count,
+        dropped_bytes: total_bits.saturating_sub(end) / 8,
+        interval_per_tick: writer.interval_per_tick(),
+    };
+
+    Ok((writer.finish()?, report))
+}
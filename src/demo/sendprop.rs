@@ -705,6 +705,55 @@ impl SendPropValue {
             }
         }
     }
+
+    /// Skip over a value without decoding it
+    pub fn skip(stream: &mut Stream, definition: &SendPropParseDefinition) -> Result<()> {
+        match definition {
+            SendPropParseDefinition::NormalVarInt { .. } => {
+                crate::demo::message::stringtable::read_var_int(stream)?;
+            }
+            SendPropParseDefinition::UnsignedInt { bit_count, .. }
+            | SendPropParseDefinition::Int { bit_count, .. } => {
+                stream.skip_bits(*bit_count as usize)?;
+            }
+            SendPropParseDefinition::Float {
+                definition: float_definition,
+                ..
+            } => Self::skip_float(stream, float_definition)?,
+            SendPropParseDefinition::String { .. } => {
+                let length: usize = stream.read_int(9)?;
+                stream.skip_bits(length * 8)?;
+            }
+            SendPropParseDefinition::Vector {
+                definition: float_definition,
+                ..
+            } => {
+                for _ in 0..3 {
+                    Self::skip_float(stream, float_definition)?;
+                }
+            }
+            SendPropParseDefinition::VectorXY {
+                definition: float_definition,
+                ..
+            } => {
+                for _ in 0..2 {
+                    Self::skip_float(stream, float_definition)?;
+                }
+            }
+            SendPropParseDefinition::Array {
+                count_bit_count,
+                inner_definition,
+                ..
+            } => {
+                let count: usize = stream.read_int(*count_bit_count as usize)?;
+                for _ in 0..count {
+                    Self::skip(stream, inner_definition)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub fn encode(
         &self,
         stream: &mut BitWriteStream<LittleEndian>,
@@ -802,6 +851,18 @@ impl SendPropValue {
         }
     }
 
+    fn skip_float(stream: &mut Stream, definition: &FloatDefinition) -> Result<()> {
+        match definition {
+            FloatDefinition::FloatNoScale => stream.skip_bits(32)?,
+            FloatDefinition::Scaled { bit_count, .. } => stream.skip_bits(*bit_count as usize)?,
+            // variable length encodings
+            _ => {
+                Self::read_float(stream, definition)?;
+            }
+        }
+        Ok(())
+    }
+
     fn write_float(
         val: f32,
         stream: &mut BitWriteStream<LittleEndian>,
@@ -1169,9 +1230,9 @@ impl<'de> Deserialize<'de> for SendPropIdentifier {
     {
         #[derive(Deserialize)]
         #[serde(untagged)]
-        enum Options<'a> {
+        enum Options {
             Num(u64),
-            Str(&'a str),
+            Str(String),
         }
 
         let raw = Options::deserialize(deserializer)?;
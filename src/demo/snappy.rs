@@ -0,0 +1,173 @@
+//! Snappy compression matching the output of the snappy version used by the source engine
+//!
+//! Newer snappy versions (including the `snap` crate) skip ahead faster when no matches are found,
+//! which results in different, but equally valid, compressed data.
+
+const BLOCK_SIZE: usize = 1 << 16;
+const MAX_HASH_TABLE_SIZE: usize = 1 << 14;
+const INPUT_MARGIN: usize = 15;
+
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(32 + input.len() + input.len() / 6);
+    write_var_int(&mut output, input.len());
+
+    let mut table = vec![0u16; MAX_HASH_TABLE_SIZE];
+    for block in input.chunks(BLOCK_SIZE) {
+        let start = output.len();
+        if compress_block(block, &mut table, &mut output).is_none() {
+            // can't happen, but emitting the block as literal is always valid
+            output.truncate(start);
+            emit_literal(&mut output, block);
+        }
+    }
+    output
+}
+
+fn write_var_int(output: &mut Vec<u8>, mut int: usize) {
+    while int >= 0x80 {
+        output.push(int as u8 | 0x80);
+        int >>= 7;
+    }
+    output.push(int as u8);
+}
+
+fn load_u32(input: &[u8], pos: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        input.get(pos..pos + 4)?.try_into().ok()?,
+    ))
+}
+
+fn hash(bytes: u32, shift: u32) -> usize {
+    (bytes.wrapping_mul(0x1e35a7bd) >> shift) as usize
+}
+
+fn compress_block(input: &[u8], table: &mut [u16], output: &mut Vec<u8>) -> Option<()> {
+    let mut table_size = 256;
+    let mut shift = 32 - 8;
+    while table_size < MAX_HASH_TABLE_SIZE && table_size < input.len() {
+        table_size *= 2;
+        shift -= 1;
+    }
+    let table = table.get_mut(..table_size)?;
+    table.fill(0);
+
+    let mut next_emit = 0;
+    if input.len() >= INPUT_MARGIN {
+        let limit = input.len() - INPUT_MARGIN;
+        let mut pos = 1;
+        let mut next_hash = hash(load_u32(input, pos)?, shift);
+        'outer: loop {
+            let mut skip = 32;
+            let mut next_pos = pos;
+            let mut candidate;
+            loop {
+                pos = next_pos;
+                let current_hash = next_hash;
+                next_pos = pos + (skip >> 5);
+                skip += 1;
+                if next_pos > limit {
+                    break 'outer;
+                }
+                next_hash = hash(load_u32(input, next_pos)?, shift);
+                let slot = table.get_mut(current_hash)?;
+                candidate = *slot as usize;
+                *slot = pos as u16;
+                if load_u32(input, pos)? == load_u32(input, candidate)? {
+                    break;
+                }
+            }
+
+            emit_literal(output, input.get(next_emit..pos)?);
+
+            loop {
+                let base = pos;
+                let matched = 4 + match_length(input, candidate + 4, pos + 4);
+                pos += matched;
+                emit_copy(output, base - candidate, matched);
+                next_emit = pos;
+                if pos >= limit {
+                    break 'outer;
+                }
+
+                let previous_hash = hash(load_u32(input, pos - 1)?, shift);
+                *table.get_mut(previous_hash)? = (pos - 1) as u16;
+                let current = load_u32(input, pos)?;
+                let slot = table.get_mut(hash(current, shift))?;
+                candidate = *slot as usize;
+                *slot = pos as u16;
+                if current != load_u32(input, candidate)? {
+                    break;
+                }
+            }
+            next_hash = hash(load_u32(input, pos + 1)?, shift);
+            pos += 1;
+        }
+    }
+
+    if next_emit < input.len() {
+        emit_literal(output, input.get(next_emit..)?);
+    }
+    Some(())
+}
+
+fn match_length(input: &[u8], candidate: usize, pos: usize) -> usize {
+    input
+        .iter()
+        .skip(pos)
+        .zip(input.iter().skip(candidate))
+        .take_while(|(a, b)| a == b)
+        .count()
+}
+
+fn emit_literal(output: &mut Vec<u8>, literal: &[u8]) {
+    let n = literal.len() - 1;
+    if n < 60 {
+        output.push((n as u8) << 2);
+    } else if n < 256 {
+        output.push(60 << 2);
+        output.push(n as u8);
+    } else {
+        output.push(61 << 2);
+        output.extend_from_slice(&(n as u16).to_le_bytes());
+    }
+    output.extend_from_slice(literal);
+}
+
+fn emit_copy(output: &mut Vec<u8>, offset: usize, mut length: usize) {
+    while length >= 68 {
+        emit_copy_long(output, offset, 64);
+        length -= 64;
+    }
+    if length > 64 {
+        emit_copy_long(output, offset, 60);
+        length -= 60;
+    }
+    if length < 12 && offset < 2048 {
+        output.push((((offset >> 8) as u8) << 5) | (((length - 4) as u8) << 2) | 0b01);
+        output.push(offset as u8);
+    } else {
+        emit_copy_long(output, offset, length);
+    }
+}
+
+fn emit_copy_long(output: &mut Vec<u8>, offset: usize, length: usize) {
+    output.push((((length - 1) as u8) << 2) | 0b10);
+    output.extend_from_slice(&(offset as u16).to_le_bytes());
+}
+
+#[test]
+fn test_snappy_roundtrip() {
+    let inputs: [&[u8]; 4] = [
+        b"",
+        b"short",
+        b"a longer input with some repetition, some repetition, some repetition",
+        &[7; 200_000],
+    ];
+    for input in inputs {
+        let compressed = compress(input);
+        let decompressed = snap::raw::Decoder::new()
+            .decompress_vec(&compressed)
+            .unwrap();
+        assert_eq!(input, decompressed.as_slice());
+    }
+}
@@ -0,0 +1,539 @@
+use crate::demo::data::{MaybeUtf8String, UserInfo};
+use crate::demo::gameevent_gen::GameEvent;
+use crate::demo::header::Header;
+use crate::demo::message::usermessage::{ChatMessageKind, UserMessage};
+use crate::demo::message::Message;
+use crate::demo::packet::stringtable::{ExtraData, StringTable, StringTableEntry};
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, Encode, NullHandler, ParserConfig, RawPacketStream};
+use crate::demo::writer::DemoWriter;
+use crate::{Demo, ParserState, Result, Stream};
+use bitbuffer::{BitRead, BitReadBuffer, BitWrite, BitWriteStream, LittleEndian};
+use std::collections::HashMap;
+use std::io::{Seek, Write};
+
+/// A rewrite step applied to every packet of a demo before it's written
+///
+/// Transforms can be combined by putting them in a tuple or a [`TransformPipeline`], the packet
+/// returned by one transform is passed to the next.
+pub trait PacketTransform {
+    /// Transform a packet, returning `None` drops the packet from the demo
+    ///
+    /// The parser state is the state of the input demo from before the packet.
+    fn transform<'a>(&mut self, packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>>;
+
+    /// Transform the demo header, called once before any packet
+    fn transform_header(&mut self, _header: &mut Header) {}
+}
+
+impl<T: PacketTransform + ?Sized> PacketTransform for Box<T> {
+    fn transform<'a>(&mut self, packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>> {
+        T::transform(self, packet, state)
+    }
+
+    fn transform_header(&mut self, header: &mut Header) {
+        T::transform_header(self, header)
+    }
+}
+
+impl<T: PacketTransform + ?Sized> PacketTransform for &mut T {
+    fn transform<'a>(&mut self, packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>> {
+        T::transform(self, packet, state)
+    }
+
+    fn transform_header(&mut self, header: &mut Header) {
+        T::transform_header(self, header)
+    }
+}
+
+macro_rules! impl_tuple_transform {
+    ($($name:ident => $i:tt),+) => {
+        impl<$($name: PacketTransform),+> PacketTransform for ($($name,)+) {
+            fn transform<'a>(&mut self, packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>> {
+                $(let packet = self.$i.transform(packet, state)?;)+
+                Some(packet)
+            }
+
+            fn transform_header(&mut self, header: &mut Header) {
+                $(self.$i.transform_header(header);)+
+            }
+        }
+    };
+}
+
+impl_tuple_transform!(A => 0, B => 1);
+impl_tuple_transform!(A => 0, B => 1, C => 2);
+impl_tuple_transform!(A => 0, B => 1, C => 2, D => 3);
+impl_tuple_transform!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_tuple_transform!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+
+/// A list of transforms that are applied in order, for when the stages are only known at runtime
+///
+/// Stages can be borrowed to inspect them after the rewrite.
+///
+/// ```
+/// # use tf_demo_parser::demo::packet::PacketType;
+/// # use tf_demo_parser::demo::transform::{DropPackets, PovToStv, StripVoice, TransformPipeline};
+/// let mut strip_voice = StripVoice::new();
+/// let pipeline = TransformPipeline::new()
+///     .with(PovToStv)
+///     .with(&mut strip_voice)
+///     .with(DropPackets::new([PacketType::ConsoleCmd]));
+/// ```
+#[derive(Default)]
+pub struct TransformPipeline<'a> {
+    stages: Vec<Box<dyn PacketTransform + 'a>>,
+}
+
+impl<'a> TransformPipeline<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a stage to the end of the pipeline
+    pub fn with(mut self, stage: impl PacketTransform + 'a) -> Self {
+        self.push(stage);
+        self
+    }
+
+    /// Add a stage to the end of the pipeline
+    pub fn push(&mut self, stage: impl PacketTransform + 'a) {
+        self.stages.push(Box::new(stage));
+    }
+
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+
+impl PacketTransform for TransformPipeline<'_> {
+    fn transform<'a>(&mut self, packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>> {
+        self.stages
+            .iter_mut()
+            .try_fold(packet, |packet, stage| stage.transform(packet, state))
+    }
+
+    fn transform_header(&mut self, header: &mut Header) {
+        for stage in self.stages.iter_mut() {
+            stage.transform_header(header);
+        }
+    }
+}
+
+/// Re-encode a demo with a transform applied to every packet
+///
+/// All messages of the input are parsed, so messages not touched by the transform are kept.
+/// If the transform changes or drops any packet, the tick count, frame count, duration and signon
+/// size in the header are recalculated.
+pub fn rewrite_demo<W: Write + Seek, T: PacketTransform>(
+    demo: &Demo,
+    out: W,
+    transform: &mut T,
+) -> Result<W> {
+    let mut stream = demo.get_stream();
+    let mut header = Header::read(&mut stream)?;
+    transform.transform_header(&mut header);
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut writer = DemoWriter::new(out, header)?;
+
+    let mut changed = false;
+    while let Some(packet) = packets.next(&handler.state_handler)? {
+        match transform.transform(packet.clone(), &handler.state_handler) {
+            Some(transformed) => {
+                changed |= transformed != packet;
+                writer.write_packet(transformed)?;
+            }
+            None => changed = true,
+        }
+        handler.handle_packet(packet)?;
+    }
+
+    // adds the stop packet for truncated demos, the length in the header is only recalculated
+    // when the packets were changed, keeping any changes made by `transform_header` otherwise
+    writer.update_header(changed).finish()
+}
+
+/// Convert a pov demo into an stv demo by removing the view angles and marking the server as stv
+pub struct PovToStv;
+
+impl PacketTransform for PovToStv {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &mut packet {
+            message_packet.meta.view_angles = Default::default();
+            for message in message_packet.messages.iter_mut() {
+                if let Message::ServerInfo(info) = message {
+                    info.stv = true;
+                }
+            }
+        }
+        Some(packet)
+    }
+}
+
+/// Remove all packets of the given types
+pub struct DropPackets {
+    types: Vec<PacketType>,
+}
+
+impl DropPackets {
+    pub fn new(types: impl IntoIterator<Item = PacketType>) -> Self {
+        DropPackets {
+            types: types.into_iter().collect(),
+        }
+    }
+}
+
+impl PacketTransform for DropPackets {
+    fn transform<'a>(&mut self, packet: Packet<'a>, _state: &ParserState) -> Option<Packet<'a>> {
+        (!self.types.contains(&packet.packet_type())).then_some(packet)
+    }
+}
+
+/// Change the element count of array props in the data tables
+///
+/// Prop names in the data tables are quoted, e.g. `"player_array"`, the names given here are without quotes.
+#[derive(Default)]
+pub struct PropArraySizes {
+    sizes: Vec<(String, String, u16)>,
+}
+
+impl PropArraySizes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The array sizes needed for servers with up to 100 players
+    pub fn hundred_players() -> Self {
+        Self::new()
+            .set("DT_ObjectDispenser", "healing_array", 101)
+            .set("DT_Team", "player_array", 101)
+            .set("DT_TFTeam", "team_object_array", 606)
+    }
+
+    pub fn set(mut self, table: impl Into<String>, prop: impl Into<String>, count: u16) -> Self {
+        self.sizes
+            .push((table.into(), format!("\"{}\"", prop.into()), count));
+        self
+    }
+}
+
+impl PacketTransform for PropArraySizes {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        if let Packet::DataTables(tables_packet) = &mut packet {
+            for table in tables_packet.tables.iter_mut() {
+                for prop in table.props.iter_mut() {
+                    if let Some((_, _, count)) =
+                        self.sizes.iter().find(|(table_name, prop_name, _)| {
+                            table.name.as_str() == table_name && prop.name.as_str() == prop_name
+                        })
+                    {
+                        prop.element_count = Some(*count);
+                    }
+                }
+            }
+        }
+        Some(packet)
+    }
+}
+
+/// Remove voice data from the demo
+///
+/// By default the voice of all clients is removed, together with the voice init message.
+/// The number of removed messages and the size they took up in the encoded packets is tracked to
+/// report the savings.
+#[derive(Debug, Default)]
+pub struct StripVoice {
+    clients: Option<Vec<u8>>,
+    removed_messages: usize,
+    removed_bytes: usize,
+}
+
+impl StripVoice {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only remove the voice data of the given client indexes, the voice init message is kept
+    pub fn clients(clients: impl IntoIterator<Item = u8>) -> Self {
+        StripVoice {
+            clients: Some(clients.into_iter().collect()),
+            ..Self::default()
+        }
+    }
+
+    /// The number of removed voice messages
+    pub fn removed_messages(&self) -> usize {
+        self.removed_messages
+    }
+
+    /// The number of bytes the packets with removed voice messages shrunk by when encoded
+    pub fn removed_bytes(&self) -> usize {
+        self.removed_bytes
+    }
+
+    fn strips(&self, message: &Message) -> bool {
+        match (message, &self.clients) {
+            (Message::VoiceInit(_), None) | (Message::VoiceData(_), None) => true,
+            (Message::VoiceData(voice), Some(clients)) => clients.contains(&voice.client),
+            _ => false,
+        }
+    }
+}
+
+impl PacketTransform for StripVoice {
+    fn transform<'a>(&mut self, mut packet: Packet<'a>, state: &ParserState) -> Option<Packet<'a>> {
+        let stripped = match &packet {
+            Packet::Signon(message_packet) | Packet::Message(message_packet) => message_packet
+                .messages
+                .iter()
+                .filter(|message| self.strips(message))
+                .count(),
+            _ => 0,
+        };
+        if stripped == 0 {
+            return Some(packet);
+        }
+
+        let before = encoded_len(&packet, state);
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &mut packet {
+            message_packet
+                .messages
+                .retain(|message| !self.strips(message));
+        }
+        // a packet that can't be encoded fails the rewrite once it's written
+        if let (Some(before), Some(after)) = (before, encoded_len(&packet, state)) {
+            self.removed_bytes += before.saturating_sub(after);
+        }
+        self.removed_messages += stripped;
+        Some(packet)
+    }
+}
+
+/// The encoded size of a packet in bytes
+fn encoded_len(packet: &Packet, state: &ParserState) -> Option<usize> {
+    let mut data = Vec::new();
+    let mut stream = BitWriteStream::new(&mut data, LittleEndian);
+    packet.encode(&mut stream, state).ok()?;
+    Some(stream.byte_len())
+}
+
+/// Replace the names and steam ids of all players
+///
+/// Players are numbered in the order they are first seen and renamed to `Player <n>` with steam id
+/// `[U:1:<n>]`. The same player keeps the same replacement for the entire demo, including after a
+/// name change. Bots and the SourceTV client are left as is.
+///
+/// This rewrites the `userinfo` string table, chat messages, name change messages, the player
+/// connection events and the nickname in the demo header.
+#[derive(Debug, Default)]
+pub struct Anonymizer {
+    by_steam_id: HashMap<String, u32>,
+    by_name: HashMap<String, u32>,
+    player_count: u32,
+    table_count: u8,
+    userinfo_table: Option<u8>,
+}
+
+impl Anonymizer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The number of players that have been anonymized
+    pub fn player_count(&self) -> usize {
+        self.player_count as usize
+    }
+
+    fn next_player(&mut self) -> u32 {
+        self.player_count += 1;
+        self.player_count
+    }
+
+    /// Get the number of a player, assigning a new one for unknown players
+    fn player(&mut self, name: &str, steam_id: Option<&str>) -> u32 {
+        let known = steam_id
+            .and_then(|steam_id| self.by_steam_id.get(steam_id))
+            .or_else(|| self.by_name.get(name))
+            .copied();
+        let player = known.unwrap_or_else(|| self.next_player());
+        if let Some(steam_id) = steam_id {
+            self.by_steam_id.insert(steam_id.to_string(), player);
+        }
+        if !name.is_empty() {
+            self.by_name.insert(name.to_string(), player);
+        }
+        player
+    }
+
+    fn name(&mut self, name: &MaybeUtf8String) -> MaybeUtf8String {
+        match name.as_ref() {
+            "" => name.clone(),
+            name => MaybeUtf8String::Valid(anonymous_name(self.player(name, None))),
+        }
+    }
+
+    /// Anonymize the name and steam id from an event, leaving bots untouched
+    fn player_event(&mut self, name: &mut MaybeUtf8String, network_id: &mut MaybeUtf8String) {
+        if is_real_steam_id(network_id.as_ref()) {
+            let player = self.player(name.as_ref(), Some(network_id.as_ref()));
+            *name = MaybeUtf8String::Valid(anonymous_name(player));
+            *network_id = MaybeUtf8String::Valid(anonymous_steam_id(player));
+        }
+    }
+
+    /// Rename the old and new name of a name change to the same player
+    fn name_change(&mut self, old: &mut MaybeUtf8String, new: &mut MaybeUtf8String) {
+        let player = self.player(old.as_ref(), None);
+        if !new.as_ref().is_empty() {
+            self.by_name.insert(new.as_ref().to_string(), player);
+        }
+        *old = MaybeUtf8String::Valid(anonymous_name(player));
+        *new = MaybeUtf8String::Valid(anonymous_name(player));
+    }
+
+    fn user_info(&mut self, index: u16, entry: &mut StringTableEntry) {
+        let Some(extra_data) = &entry.extra_data else {
+            return;
+        };
+        let mut user_info = match UserInfo::parse_from_string_table(
+            index,
+            entry.text.as_deref(),
+            Some(extra_data.data.clone()),
+        ) {
+            Ok(Some(user_info)) => user_info,
+            _ => return,
+        };
+        let info = &mut user_info.player_info;
+        if info.is_fake_player != 0 || info.is_hl_tv != 0 || !is_real_steam_id(&info.steam_id) {
+            return;
+        }
+
+        let player = self.player(&info.name, Some(&info.steam_id));
+        info.name = anonymous_name(player);
+        info.steam_id = anonymous_steam_id(player);
+        info.friends_id = player;
+
+        let mut original = extra_data.data.clone();
+        let Ok(original) = original.read_bytes(extra_data.byte_len as usize) else {
+            return;
+        };
+        let mut data = Vec::with_capacity(original.len());
+        {
+            let mut stream = BitWriteStream::new(&mut data, LittleEndian);
+            if info.write(&mut stream).is_err() {
+                return;
+            }
+        }
+        // keep any trailing data we don't know about
+        data.extend_from_slice(original.get(data.len()..).unwrap_or_default());
+
+        entry.extra_data = Some(ExtraData::new(Stream::new(BitReadBuffer::new_owned(
+            data,
+            LittleEndian,
+        ))));
+    }
+
+    /// Tables get their id in the order they are created, either from a `StringTables` packet
+    /// or a `CreateStringTable` message, the same way as the parser assigns them.
+    ///
+    /// Demos usually contain both for the same tables, in which case the first one sets the id.
+    fn string_table(&mut self, table: &mut StringTable) {
+        if table.name == "userinfo" {
+            self.userinfo_table.get_or_insert(self.table_count);
+            for (index, entry) in table.entries.iter_mut() {
+                self.user_info(*index, entry);
+            }
+        }
+        self.table_count = self.table_count.saturating_add(1);
+    }
+
+    fn message(&mut self, message: &mut Message) {
+        match message {
+            Message::CreateStringTable(create) => self.string_table(&mut create.table),
+            Message::UpdateStringTable(update) if Some(update.table_id) == self.userinfo_table => {
+                for (index, entry) in update.entries.iter_mut() {
+                    self.user_info(*index, entry);
+                }
+            }
+            Message::UserMessage(UserMessage::SayText2(chat)) => match (&chat.kind, &mut chat.from)
+            {
+                (ChatMessageKind::NameChange, Some(from)) => self.name_change(from, &mut chat.text),
+                (_, Some(from)) => *from = self.name(from),
+                _ => {}
+            },
+            Message::GameEvent(event) => match &mut event.event {
+                GameEvent::PlayerChangeName(change) => {
+                    self.name_change(&mut change.old_name, &mut change.new_name)
+                }
+                GameEvent::PlayerConnect(connect) => {
+                    self.player_event(&mut connect.name, &mut connect.network_id)
+                }
+                GameEvent::PlayerConnectClient(connect) => {
+                    self.player_event(&mut connect.name, &mut connect.network_id)
+                }
+                GameEvent::PlayerInfo(info) => {
+                    self.player_event(&mut info.name, &mut info.network_id)
+                }
+                GameEvent::PlayerDisconnect(disconnect) => {
+                    self.player_event(&mut disconnect.name, &mut disconnect.network_id)
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+}
+
+fn anonymous_name(player: u32) -> String {
+    format!("Player {player}")
+}
+
+fn anonymous_steam_id(player: u32) -> String {
+    format!("[U:1:{player}]")
+}
+
+fn is_real_steam_id(steam_id: &str) -> bool {
+    !steam_id.is_empty() && steam_id != "BOT"
+}
+
+impl PacketTransform for Anonymizer {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        match &mut packet {
+            Packet::StringTables(tables_packet) => {
+                for table in tables_packet.tables.iter_mut() {
+                    self.string_table(table);
+                }
+            }
+            Packet::Signon(message_packet) | Packet::Message(message_packet) => {
+                for message in message_packet.messages.iter_mut() {
+                    self.message(message);
+                }
+            }
+            _ => {}
+        }
+        Some(packet)
+    }
+
+    fn transform_header(&mut self, header: &mut Header) {
+        if !header.nick.is_empty() {
+            header.nick = anonymous_name(self.player(&header.nick, None));
+        }
+    }
+}
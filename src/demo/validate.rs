@@ -0,0 +1,241 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::packet::datatable::ClassId;
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use crate::{Demo, ParseError, Result};
+use bitbuffer::BitRead;
+use serde::{Deserialize, Serialize};
+
+/// The structural problems found in a demo
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    pub header: Header,
+    /// The number of packets that could be read
+    pub packets: usize,
+    /// The tick of the last packet
+    pub ticks: u32,
+    /// The number of message packets
+    pub frames: u32,
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+}
+
+/// A problem in the demo and the location where it was found
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Issue {
+    /// Index of the packet containing the problem
+    pub packet_index: Option<usize>,
+    pub tick: Option<DemoTick>,
+    /// Bit position of the start of the packet in the demo
+    pub position: Option<usize>,
+    #[serde(flatten)]
+    pub problem: Problem,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Problem {
+    /// The tick count in the header doesn't match the tick of the last packet
+    HeaderTicks { header: u32, actual: u32 },
+    /// The frame count in the header doesn't match the number of message packets
+    HeaderFrames { header: u32, actual: u32 },
+    /// The demo doesn't end with a stop packet
+    MissingStop,
+    /// The demo ends in the middle of a packet
+    Truncated,
+    /// The tick of a packet is lower than the tick of the packet before it
+    NonMonotonicTick { previous: DemoTick, tick: DemoTick },
+    /// A string table update for a table that wasn't created
+    UnknownStringTable { table_id: u8 },
+    /// An entity or temp entity with a server class that isn't in the data tables
+    UnknownServerClass { class: ClassId },
+    /// Data left over after parsing a packet
+    DataRemaining { bits: usize },
+    /// A game event that doesn't match its definition
+    GameEventMismatch { error: String },
+    /// Any other error that caused the packet to be skipped
+    InvalidPacket { error: String },
+}
+
+impl From<&ParseError> for Problem {
+    fn from(err: &ParseError) -> Self {
+        match err.inner() {
+            ParseError::StringTableNotFound(table_id) => Problem::UnknownStringTable {
+                table_id: *table_id,
+            },
+            ParseError::UnknownServerClass(class) => Problem::UnknownServerClass { class: *class },
+            ParseError::DataRemaining(bits) => Problem::DataRemaining { bits: *bits },
+            err @ (ParseError::MalformedGameEvent(_)
+            | ParseError::InvalidGameEvent { .. }
+            | ParseError::MissingGameEventValue { .. }) => Problem::GameEventMismatch {
+                error: err.to_string(),
+            },
+            err => Problem::InvalidPacket {
+                error: err.to_string(),
+            },
+        }
+    }
+}
+
+impl Issue {
+    fn new(packet_index: usize, tick: DemoTick, position: usize, problem: Problem) -> Self {
+        Issue {
+            packet_index: Some(packet_index),
+            tick: Some(tick),
+            position: Some(position),
+            problem,
+        }
+    }
+
+    fn demo(problem: Problem) -> Self {
+        Issue {
+            packet_index: None,
+            tick: None,
+            position: None,
+            problem,
+        }
+    }
+
+    fn from_error(err: &ParseError) -> Self {
+        let context = err.context().cloned().unwrap_or_default();
+        Issue {
+            packet_index: context.packet_index,
+            tick: context.tick,
+            position: context.position,
+            problem: err.into(),
+        }
+    }
+}
+
+/// Walk through all packets of a demo and report any structural problems
+///
+/// Damaged packets are skipped using their length prefix, so one broken packet doesn't hide the
+/// problems in the rest of the demo. Only an unreadable header results in an error.
+pub fn validate_demo(demo: &Demo) -> Result<ValidationReport> {
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream)?;
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+
+    let mut issues = Vec::new();
+    let mut packet_count = 0;
+    let mut frames = 0;
+    let mut last_tick = DemoTick::default();
+    let mut previous_tick: Option<DemoTick> = None;
+    let mut stopped = false;
+
+    loop {
+        let start = packets.pos();
+        let packet = match packets.next(&handler.state_handler) {
+            Ok(Some(packet)) => packet,
+            Ok(None) => break,
+            Err(e) => {
+                issues.push(Issue::from_error(&e));
+                if packets.recover() {
+                    packet_count += 1;
+                    continue;
+                } else {
+                    break;
+                }
+            }
+        };
+        let index = packet_count;
+        let tick = packet.tick();
+        packet_count += 1;
+        last_tick = tick;
+
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &packet {
+            let bits = message_packet.data_remaining;
+            if bits > 0 {
+                issues.push(Issue::new(
+                    index,
+                    tick,
+                    start,
+                    Problem::DataRemaining { bits },
+                ));
+            }
+        }
+
+        match packet.packet_type() {
+            // signon packets use their own tick numbering
+            PacketType::Signon | PacketType::DataTables => {}
+            packet_type => {
+                if let Some(previous) = previous_tick.filter(|previous| *previous > tick) {
+                    issues.push(Issue::new(
+                        index,
+                        tick,
+                        start,
+                        Problem::NonMonotonicTick { previous, tick },
+                    ));
+                }
+                previous_tick = Some(tick);
+                match packet_type {
+                    PacketType::Message => frames += 1,
+                    PacketType::Stop => stopped = true,
+                    _ => {}
+                }
+            }
+        }
+
+        if let Err(e) = handler.handle_packet(packet) {
+            issues.push(Issue::new(index, tick, start, (&e).into()));
+        }
+    }
+
+    if packets.incomplete {
+        issues.push(Issue::demo(Problem::Truncated));
+    }
+    if !stopped {
+        issues.push(Issue::demo(Problem::MissingStop));
+    }
+    let ticks = last_tick.into();
+    if header.ticks != ticks {
+        issues.push(Issue::demo(Problem::HeaderTicks {
+            header: header.ticks,
+            actual: ticks,
+        }));
+    }
+    if header.frames != frames {
+        issues.push(Issue::demo(Problem::HeaderFrames {
+            header: header.frames,
+            actual: frames,
+        }));
+    }
+
+    Ok(ValidationReport {
+        header,
+        packets: packet_count,
+        ticks,
+        frames,
+        issues,
+    })
+}
+
+#[test]
+fn test_problem_from_error() {
+    let err = ParseError::DataRemaining(12).with_context(|context| {
+        context.packet_index = Some(3);
+        context.tick = Some(DemoTick::from(10));
+    });
+    let issue = Issue::from_error(&err);
+    assert_eq!(Some(3), issue.packet_index);
+    assert_eq!(Some(DemoTick::from(10)), issue.tick);
+    assert_eq!(Problem::DataRemaining { bits: 12 }, issue.problem);
+
+    assert_eq!(
+        Problem::UnknownStringTable { table_id: 4 },
+        Problem::from(&ParseError::StringTableNotFound(4))
+    );
+    assert_eq!(
+        Problem::UnknownServerClass {
+            class: ClassId::from(400u16)
+        },
+        Problem::from(&ParseError::UnknownServerClass(ClassId::from(400u16)))
+    );
+}
@@ -0,0 +1,203 @@
+use crate::demo::data::DemoTick;
+use crate::demo::header::Header;
+use crate::demo::message::Message;
+use crate::demo::packet::stop::StopPacket;
+use crate::demo::packet::{Packet, PacketType};
+use crate::demo::parser::{DemoHandler, Encode, NullHandler};
+use crate::{ParseError, Result};
+use bitbuffer::{BitWrite, BitWriteStream, LittleEndian};
+use std::io::{Seek, SeekFrom, Write};
+
+/// Size of the encoded demo header in bytes
+const HEADER_SIZE: usize = 1072;
+
+/// Tick interval used for the header duration when the demo contains no server info or update rate
+const DEFAULT_INTERVAL_PER_TICK: f32 = 0.015;
+
+/// Write packets into a new demo file
+///
+/// The writer keeps track of the state needed to encode the packets, like the data tables and
+/// string tables, so packets can be written as they are produced by a [`DemoParser`](crate::DemoParser).
+/// Once all packets are written, [`finish`](Self::finish) adds a stop packet if needed and fills in
+/// the tick count, frame count, duration and signon size in the header if they are zero, like in the
+/// header of a demo that wasn't closed properly. Use [`update_header`](Self::update_header) to
+/// recalculate them even if they are set, for example after removing packets.
+///
+/// ```no_run
+/// # use bitbuffer::BitRead;
+/// # use tf_demo_parser::Demo;
+/// # use tf_demo_parser::demo::header::Header;
+/// # use tf_demo_parser::demo::parser::{DemoHandler, RawPacketStream};
+/// # use tf_demo_parser::demo::writer::DemoWriter;
+/// # use std::fs::File;
+/// # fn main() -> tf_demo_parser::Result<()> {
+/// # let file = std::fs::read("in.dem")?;
+/// let demo = Demo::new(&file);
+/// let mut stream = demo.get_stream();
+/// let header = Header::read(&mut stream)?;
+/// let mut packets = RawPacketStream::new(stream);
+/// let mut handler = DemoHandler::default();
+///
+/// let mut writer = DemoWriter::new(File::create("out.dem")?, header)?;
+/// while let Some(packet) = packets.next(&handler.state_handler)? {
+///     writer.write_packet(packet.clone())?;
+///     handler.handle_packet(packet)?;
+/// }
+/// writer.finish()?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct DemoWriter<'a, W: Write + Seek> {
+    out: W,
+    start: u64,
+    header: Header,
+    handler: DemoHandler<'a, NullHandler>,
+    buffer: Vec<u8>,
+    last_tick: DemoTick,
+    frames: u32,
+    signon: u32,
+    stopped: bool,
+    min_update_rate: Option<f32>,
+    update_header: bool,
+}
+
+impl<'a, W: Write + Seek> DemoWriter<'a, W> {
+    /// Create a writer and write a placeholder header, the header is completed by [`finish`](Self::finish)
+    pub fn new(mut out: W, header: Header) -> Result<Self> {
+        let start = out.stream_position()?;
+        let mut buffer = Vec::with_capacity(HEADER_SIZE);
+        {
+            let mut stream = BitWriteStream::new(&mut buffer, LittleEndian);
+            header.write(&mut stream)?;
+        }
+        out.write_all(&buffer)?;
+
+        Ok(DemoWriter {
+            out,
+            start,
+            header,
+            handler: DemoHandler::default(),
+            buffer,
+            last_tick: DemoTick::default(),
+            frames: 0,
+            signon: 0,
+            stopped: false,
+            min_update_rate: None,
+            update_header: false,
+        })
+    }
+
+    /// Recalculate the tick count, frame count, duration and signon size of the header from the
+    /// written packets, instead of only filling in the fields that are zero
+    pub fn update_header(mut self, update_header: bool) -> Self {
+        self.update_header = update_header;
+        self
+    }
+
+    /// Encode a packet and write it to the demo
+    ///
+    /// Returns an error when a packet is written after a stop packet.
+    pub fn write_packet(&mut self, packet: Packet<'a>) -> Result<()> {
+        if self.stopped {
+            return Err(ParseError::PacketAfterStop);
+        }
+
+        self.buffer.clear();
+        {
+            let mut stream = BitWriteStream::new(&mut self.buffer, LittleEndian);
+            packet.encode(&mut stream, &self.handler.state_handler)?;
+        }
+        self.out.write_all(&self.buffer)?;
+
+        match packet.packet_type() {
+            PacketType::Message => self.frames += 1,
+            // the signon size in the header covers the signon and data table packets
+            PacketType::Signon | PacketType::DataTables => self.signon += self.buffer.len() as u32,
+            PacketType::Stop => self.stopped = true,
+            _ => {}
+        }
+        self.last_tick = packet.tick();
+
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &packet {
+            for message in message_packet.messages.iter() {
+                if let Message::SetConVar(convars) = message {
+                    if let Some(rate) = convars
+                        .vars
+                        .iter()
+                        .find(|var| var.key == "sv_minupdaterate")
+                        .and_then(|var| var.value.parse::<f32>().ok())
+                        .filter(|rate| *rate > 0.0)
+                    {
+                        self.min_update_rate = Some(rate);
+                    }
+                }
+            }
+        }
+
+        self.handler.handle_packet(packet)
+    }
+
+    /// The state used to encode the packets, containing everything written so far
+    pub fn state(&self) -> &DemoHandler<'a, NullHandler> {
+        &self.handler
+    }
+
+    /// The tick interval used for the duration in the header
+    ///
+    /// This is taken from the server info, with the `sv_minupdaterate` convar as fallback.
+    pub fn interval_per_tick(&self) -> f32 {
+        match (
+            self.handler.state_handler.demo_meta.interval_per_tick,
+            self.min_update_rate,
+        ) {
+            (interval, _) if interval > 0.0 => interval,
+            (_, Some(rate)) => 1.0 / rate,
+            _ => DEFAULT_INTERVAL_PER_TICK,
+        }
+    }
+
+    /// The header with the tick count, frame count, duration and signon size of the packets written so far
+    ///
+    /// Unless [`update_header`](Self::update_header) is enabled, only the fields that are zero in
+    /// the header passed to [`new`](Self::new) are replaced. The signon size is the size of the
+    /// signon and data table packets.
+    pub fn header(&self) -> Header {
+        let ticks: u32 = self.last_tick.into();
+        let mut header = self.header.clone();
+        if self.update_header || header.ticks == 0 {
+            header.ticks = ticks;
+        }
+        if self.update_header || header.frames == 0 {
+            header.frames = self.frames;
+        }
+        if self.update_header || header.duration == 0.0 {
+            header.duration = ticks as f32 * self.interval_per_tick();
+        }
+        if self.update_header || header.signon == 0 {
+            header.signon = self.signon;
+        }
+        header
+    }
+
+    /// Write the stop packet if needed, complete the header and return the output
+    pub fn finish(mut self) -> Result<W> {
+        if !self.stopped {
+            self.write_packet(Packet::Stop(StopPacket {
+                tick: self.last_tick,
+            }))?;
+        }
+
+        let header = self.header();
+        self.buffer.clear();
+        {
+            let mut stream = BitWriteStream::new(&mut self.buffer, LittleEndian);
+            header.write(&mut stream)?;
+        }
+        self.out.seek(SeekFrom::Start(self.start))?;
+        self.out.write_all(&self.buffer)?;
+        self.out.seek(SeekFrom::End(0))?;
+        self.out.flush()?;
+
+        Ok(self.out)
+    }
+}
@@ -0,0 +1,122 @@
+//! Helpers shared by the integration tests
+#![allow(dead_code)]
+
+use bitbuffer::BitRead;
+
+use tf_demo_parser::demo::data::UserInfo;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::message::packetentities::EntityId;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::message::PacketPadding;
+use tf_demo_parser::demo::packet::Packet;
+use tf_demo_parser::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use tf_demo_parser::demo::sendprop::SendProp;
+use tf_demo_parser::{Demo, MessageType};
+
+pub fn pvs_entities(handler: &DemoHandler<NullHandler>) -> Vec<(EntityId, u32, Vec<SendProp>)> {
+    let mut entities: Vec<_> = handler
+        .state_handler
+        .entities
+        .iter()
+        .filter(|entity| entity.in_pvs)
+        .map(|entity| {
+            let mut props = entity.props.clone();
+            props.sort_by_key(|prop| prop.index);
+            (entity.entity_index, entity.serial_number, props)
+        })
+        .collect();
+    entities.sort_by_key(|(id, _, _)| *id);
+    entities
+}
+
+/// The user info from `UpdateStringTable` messages for the `userinfo` table
+pub fn userinfo_updates(data: &[u8]) -> Vec<UserInfo> {
+    let demo = Demo::new(data);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    // table ids are assigned in the order tables are created by either a packet or a message
+    let mut table_names = Vec::new();
+    let mut updates = Vec::new();
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        match &packet {
+            Packet::StringTables(tables) => {
+                table_names.extend(tables.tables.iter().map(|table| table.name.to_string()))
+            }
+            Packet::Signon(message_packet) | Packet::Message(message_packet) => {
+                for message in message_packet.messages.iter() {
+                    match message {
+                        Message::CreateStringTable(create) => {
+                            table_names.push(create.table.name.to_string())
+                        }
+                        Message::UpdateStringTable(update)
+                            if table_names
+                                .get(update.table_id as usize)
+                                .map(String::as_str)
+                                == Some("userinfo") =>
+                        {
+                            updates.extend(update.entries.iter().filter_map(|(index, entry)| {
+                                UserInfo::parse_from_string_table(
+                                    *index,
+                                    entry.text.as_deref(),
+                                    entry.extra_data.as_ref().map(|data| data.data.clone()),
+                                )
+                                .unwrap()
+                            }))
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    updates
+}
+
+pub fn voice_messages(data: &[u8]) -> Vec<MessageType> {
+    let demo = Demo::new(data);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut voice = Vec::new();
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &packet {
+            voice.extend(
+                message_packet
+                    .messages
+                    .iter()
+                    .map(Message::get_message_type)
+                    .filter(|ty| matches!(ty, MessageType::VoiceInit | MessageType::VoiceData)),
+            );
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    voice
+}
+
+/// The bit positions and padding of all message packets in the demo
+pub fn message_packets(data: &[u8]) -> Vec<(usize, PacketPadding)> {
+    let demo = Demo::new(data);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::default();
+    let mut message_packets = Vec::new();
+    loop {
+        let start = packets.pos();
+        match packets.next(&handler.state_handler).unwrap() {
+            Some(packet) => {
+                if let Packet::Message(message_packet) = &packet {
+                    message_packets.push((start, message_packet.padding));
+                }
+                handler.handle_packet(packet).unwrap()
+            }
+            None => break,
+        }
+    }
+    message_packets
+}
@@ -0,0 +1,62 @@
+use bitbuffer::BitRead;
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::cut::cut_demo;
+use tf_demo_parser::demo::data::DemoTick;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::packet::PacketType;
+use tf_demo_parser::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use tf_demo_parser::Demo;
+
+mod common;
+use common::pvs_entities;
+
+#[test_case("small.dem", 40, 100; "small.dem")]
+#[test_case("short-2024.dem", 50, 150; "short-2024.dem")]
+fn cut_demo_test(input_file: &str, start: u32, end: u32) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let output = cut_demo(&demo, Cursor::new(Vec::new()), start.into(), end.into())
+        .unwrap()
+        .into_inner();
+
+    let config = ParserConfig::new().parse_all(true).track_entities(true);
+
+    // entity state of the original demo at the end tick
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, config.clone());
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        let in_signon = matches!(
+            packet.packet_type(),
+            PacketType::Signon | PacketType::DataTables | PacketType::SyncTick
+        );
+        if !in_signon && packet.tick() > end {
+            break;
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    let expected = pvs_entities(&handler);
+    assert!(!expected.is_empty());
+
+    let cut = Demo::new(&output);
+    let mut stream = cut.get_stream();
+    let header = Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut cut_handler = DemoHandler::with_config(NullHandler, config);
+    let mut first_tick = None;
+    while let Some(packet) = packets.next(&cut_handler.state_handler).unwrap() {
+        if packet.packet_type() == PacketType::Message {
+            first_tick.get_or_insert(packet.tick());
+        }
+        cut_handler.handle_packet(packet).unwrap();
+    }
+
+    assert_eq!(Some(DemoTick::from(0)), first_tick);
+    assert!(header.ticks > 0);
+    assert!(header.ticks <= end - start);
+    assert_eq!(expected, pvs_entities(&cut_handler));
+}
@@ -4,15 +4,19 @@ use std::fs;
 use test_case::test_case;
 
 use fnv::FnvHashMap;
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use tf_demo_parser::demo::data::DemoTick;
 use tf_demo_parser::demo::message::packetentities::{EntityId, PacketEntity, UpdateType};
 use tf_demo_parser::demo::message::Message;
 use tf_demo_parser::demo::packet::datatable::{
     ParseSendTable, SendTableName, ServerClass, ServerClassName,
 };
-use tf_demo_parser::demo::parser::MessageHandler;
-use tf_demo_parser::demo::sendprop::{SendPropIdentifier, SendPropName, SendPropValue};
+use tf_demo_parser::demo::parser::analyser::Analyser;
+use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
+use tf_demo_parser::demo::parser::handler::BorrowMessageHandler;
+use tf_demo_parser::demo::parser::player_summary_analyzer::PlayerSummaryAnalyzer;
+use tf_demo_parser::demo::parser::{MessageHandler, ParserConfig};
+use tf_demo_parser::demo::sendprop::{SendProp, SendPropIdentifier, SendPropName, SendPropValue};
 use tf_demo_parser::{Demo, DemoParser, MessageType, ParserState};
 
 /// Compatible serialization with the js parser entity dumps
@@ -60,7 +64,8 @@ impl EntityDump {
             id: entity.entity_index,
             pvs: entity.update_type.into(),
             props: entity
-                .props(state)
+                .try_props(state)
+                .unwrap()
                 .map(|prop| {
                     let (table_name, prop_name) = &prop_names[&prop.identifier];
                     (format!("{}.{}", table_name, prop_name), prop.value)
@@ -141,3 +146,334 @@ fn entity_test(input_file: &str) {
         insta::assert_json_snapshot!(entities);
     });
 }
+
+#[test]
+fn entity_store_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let config = ParserConfig::new().track_entities(true);
+    let (_, mut ticker) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .ticker()
+        .unwrap();
+    while ticker.tick().unwrap() {}
+    let state = ticker.parser_state();
+
+    assert!(!state.entities.is_empty());
+    let health = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    let players: Vec<_> = state.entities_by_class_name("CTFPlayer").collect();
+    assert!(!players.is_empty());
+    for player in players {
+        assert_eq!(Some(player), state.entities.get(player.entity_index));
+        // the stored entity contains the full prop set, not just the last update
+        assert!(player.props.iter().any(|prop| prop.identifier == health));
+    }
+    assert_eq!(0, state.entities_by_class_name("NotAClass").count());
+
+    // entity tracking is opt-in
+    let (_, mut ticker) = DemoParser::new(demo.get_stream()).ticker().unwrap();
+    while ticker.tick().unwrap() {}
+    assert!(ticker.parser_state().entities.is_empty());
+}
+
+/// The prop indexes of entering entities, and the indexes of their update and baseline props
+#[derive(Default)]
+struct EnterProps {
+    entities: Vec<(Vec<u32>, BTreeSet<u32>)>,
+    repeated_identifiers: usize,
+}
+
+impl MessageHandler for EnterProps {
+    type Output = Self;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        message_type == MessageType::PacketEntities
+    }
+
+    fn handle_message(&mut self, message: &Message, _tick: DemoTick, parser_state: &ParserState) {
+        let Message::PacketEntities(message) = message else {
+            return;
+        };
+        for entity in message.entities.iter() {
+            if entity.update_type != UpdateType::Enter {
+                continue;
+            }
+            let baseline = entity.get_baseline_props(parser_state);
+            let identifiers: HashSet<_> = baseline.iter().map(|prop| prop.identifier).collect();
+            if identifiers.len() < baseline.len() {
+                self.repeated_identifiers += 1;
+            }
+            let expected = entity
+                .props
+                .iter()
+                .chain(baseline.iter())
+                .map(|prop| prop.index)
+                .collect();
+            let props = entity
+                .try_props(parser_state)
+                .unwrap()
+                .map(|prop| prop.index)
+                .collect();
+            self.entities.push((props, expected));
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self
+    }
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn enter_props_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, enter_props) = DemoParser::new_with_analyser(demo.get_stream(), EnterProps::default())
+        .parse()
+        .unwrap();
+
+    // sub tables that are included multiple times, like the elements of an array of data tables,
+    // repeat the identifiers of their props
+    assert!(enter_props.repeated_identifiers > 0);
+    for (props, expected) in enter_props.entities {
+        // every prop is included once, baseline props are only left out when they are updated
+        assert_eq!(props.len(), expected.len());
+        assert_eq!(expected, props.into_iter().collect::<BTreeSet<_>>());
+    }
+}
+
+#[derive(Default)]
+struct EntityLifecycle {
+    live: HashMap<EntityId, u32>,
+    entered: usize,
+    left: usize,
+    deleted: usize,
+}
+
+impl MessageHandler for EntityLifecycle {
+    type Output = Self;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        message_type == MessageType::PacketEntities
+    }
+
+    fn handle_entity_enter(
+        &mut self,
+        entity: &PacketEntity,
+        created: bool,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        let previous = self.live.insert(entity.entity_index, entity.serial_number);
+        if created {
+            assert_eq!(None, previous);
+        } else {
+            assert_eq!(Some(entity.serial_number), previous);
+        }
+        self.entered += 1;
+    }
+
+    fn handle_entity_leave(
+        &mut self,
+        entity: &PacketEntity,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        assert_eq!(
+            Some(&entity.serial_number),
+            self.live.get(&entity.entity_index)
+        );
+        self.left += 1;
+    }
+
+    fn handle_entity_delete(
+        &mut self,
+        entity: &PacketEntity,
+        _tick: DemoTick,
+        _parser_state: &ParserState,
+    ) {
+        assert_eq!(
+            Some(entity.serial_number),
+            self.live.remove(&entity.entity_index)
+        );
+        self.deleted += 1;
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self
+    }
+}
+
+impl BorrowMessageHandler for EntityLifecycle {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        self
+    }
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn entity_lifecycle_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, mut ticker) =
+        DemoParser::new_with_analyser(demo.get_stream(), EntityLifecycle::default())
+            .ticker()
+            .unwrap();
+    while ticker.tick().unwrap() {}
+    let live: HashMap<EntityId, u32> = ticker
+        .parser_state()
+        .entity_serials
+        .iter()
+        .map(|(id, serial)| (*id, *serial))
+        .collect();
+    let lifecycle = ticker.state();
+
+    assert!(lifecycle.entered > 0);
+    assert_eq!(live, lifecycle.live);
+}
+
+#[derive(Default)]
+struct EntityProps {
+    props: Vec<(EntityId, Vec<SendProp>)>,
+    lazy: usize,
+}
+
+impl MessageHandler for EntityProps {
+    type Output = Self;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        message_type == MessageType::PacketEntities
+    }
+
+    fn handle_message(&mut self, message: &Message, _tick: DemoTick, parser_state: &ParserState) {
+        if let Message::PacketEntities(message) = message {
+            for entity in message.entities.iter() {
+                if entity.lazy_props.is_some() {
+                    self.lazy += 1;
+                }
+                // lazily decoded props of a valid demo always decode
+                let props = entity.try_props(parser_state).unwrap().collect();
+                self.props.push((entity.entity_index, props));
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self
+    }
+}
+
+impl BorrowMessageHandler for EntityProps {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        self
+    }
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn lazy_props_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let config = ParserConfig::new().lazy_props(true);
+
+    let (_, eager) = DemoParser::new_with_analyser(demo.get_stream(), EntityProps::default())
+        .parse()
+        .unwrap();
+    let (_, lazy) =
+        DemoParser::with_config(demo.get_stream(), EntityProps::default(), config.clone())
+            .parse()
+            .unwrap();
+    assert_eq!(0, eager.lazy);
+    assert!(lazy.lazy > 0);
+    assert_eq!(eager.props, lazy.props);
+
+    let (_, eager) = DemoParser::new_with_analyser(demo.get_stream(), PlayerSummaryAnalyzer::new())
+        .parse()
+        .unwrap();
+    let (_, lazy) = DemoParser::with_config(
+        demo.get_stream(),
+        PlayerSummaryAnalyzer::new(),
+        config.clone(),
+    )
+    .parse()
+    .unwrap();
+    assert_eq!(eager, lazy);
+
+    let (_, eager) = DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+        .parse()
+        .unwrap();
+    let (_, lazy) = DemoParser::with_config(demo.get_stream(), GameStateAnalyser::new(), config)
+        .parse()
+        .unwrap();
+    assert_eq!(eager, lazy);
+}
+
+struct PlayerProps(EntityProps);
+
+impl MessageHandler for PlayerProps {
+    type Output = EntityProps;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        <EntityProps as MessageHandler>::does_handle(message_type)
+    }
+
+    fn handle_message(&mut self, message: &Message, tick: DemoTick, parser_state: &ParserState) {
+        MessageHandler::handle_message(&mut self.0, message, tick, parser_state)
+    }
+
+    fn entity_classes(&self) -> Option<Vec<String>> {
+        Some(vec!["CTFPlayer".into()])
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.0
+    }
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn entity_class_filter_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+
+    let (_, mut ticker) = DemoParser::new_with_analyser(demo.get_stream(), EntityProps::default())
+        .ticker()
+        .unwrap();
+    while ticker.tick().unwrap() {}
+    let player_class = ticker
+        .parser_state()
+        .server_classes
+        .iter()
+        .find(|class| class.name.as_str() == "CTFPlayer")
+        .unwrap()
+        .id;
+    let entity_classes = ticker.parser_state().entity_classes.clone();
+    let all = ticker.state();
+
+    let (_, filtered) =
+        DemoParser::new_with_analyser(demo.get_stream(), PlayerProps(EntityProps::default()))
+            .parse()
+            .unwrap();
+    assert_eq!(all.props.len(), filtered.props.len());
+    for ((entity_id, all_props), (filtered_id, filtered_props)) in
+        all.props.iter().zip(filtered.props.iter())
+    {
+        assert_eq!(entity_id, filtered_id);
+        if entity_classes.get(entity_id) == Some(&player_class) {
+            assert_eq!(all_props, filtered_props);
+        }
+    }
+    assert!(filtered.props.iter().any(|(_, props)| props.is_empty()));
+
+    // combining with a handler without filter decodes all classes
+    let (_, (summary, _)) = DemoParser::new_with_analyser(
+        demo.get_stream(),
+        (PlayerSummaryAnalyzer::new(), EntityProps::default()),
+    )
+    .parse()
+    .unwrap();
+    let (_, filtered_summary) =
+        DemoParser::new_with_analyser(demo.get_stream(), PlayerSummaryAnalyzer::new())
+            .parse()
+            .unwrap();
+    assert_eq!(summary, filtered_summary);
+}
@@ -0,0 +1,72 @@
+use bitbuffer::BitRead;
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::json::{demo_to_json, json_to_demo};
+use tf_demo_parser::demo::message::usermessage::UserMessage;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::Packet;
+use tf_demo_parser::demo::parser::{DemoHandler, NullHandler, ParserConfig, RawPacketStream};
+use tf_demo_parser::Demo;
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn json_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let json = demo_to_json(&demo, Vec::new()).unwrap();
+
+    let unchanged = json_to_demo(json.as_slice(), Cursor::new(Vec::new()))
+        .unwrap()
+        .into_inner();
+    assert_eq!(file, unchanged);
+
+    // edit every text message and remove the first user command
+    let mut value: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    let packets = value["packets"].as_array_mut().unwrap();
+    let packet_count = packets.len();
+    let user_cmd = packets
+        .iter()
+        .position(|packet| packet["type"] == "UserCmd")
+        .unwrap();
+    packets.remove(user_cmd);
+    let mut text_count = 0;
+    for packet in packets.iter_mut() {
+        for message in packet["messages"].as_array_mut().into_iter().flatten() {
+            // user messages are nested in the message, since both are tagged by `type`
+            if let Some(user_message) = message.get_mut("user_message") {
+                if user_message["type"] == "Text" {
+                    user_message["text"] = "edited".into();
+                    text_count += 1;
+                }
+            }
+        }
+    }
+    let edited_json = serde_json::to_vec(&value).unwrap();
+    let edited = json_to_demo(edited_json.as_slice(), Cursor::new(Vec::new()))
+        .unwrap()
+        .into_inner();
+
+    let edited_demo = Demo::new(&edited);
+    let mut stream = edited_demo.get_stream();
+    let _ = Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut edited_count = 0;
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        edited_count += 1;
+        if let Packet::Message(message_packet) = &packet {
+            for message in message_packet.messages.iter() {
+                if let Message::UserMessage(UserMessage::Text(text)) = message {
+                    assert_eq!("edited", text.plain_text());
+                    text_count -= 1;
+                }
+            }
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    assert_eq!(packet_count - 1, edited_count);
+    assert_eq!(0, text_count);
+}
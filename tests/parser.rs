@@ -0,0 +1,409 @@
+use bitbuffer::{BitError, BitRead};
+use std::fs;
+use test_case::test_case;
+
+use tf_demo_parser::demo::data::DemoTick;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::packet::message::MessagePacketMeta;
+use tf_demo_parser::demo::packet::PacketType;
+use tf_demo_parser::demo::parser::analyser::Analyser;
+use tf_demo_parser::demo::parser::gamestateanalyser::{GameState, GameStateAnalyser};
+use tf_demo_parser::demo::parser::{
+    CancelToken, DemoHandler, DynMessageHandler, MessageHandler, ParserConfig, ParserLimits,
+    RawPacketStream, ReaderPacketStream,
+};
+use tf_demo_parser::{Demo, DemoParser, MatchState, MessageType, ParseError, ParserState};
+
+mod common;
+use common::message_packets;
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn reader_test(input_file: &str) {
+    let path = format!("test_data/{}", input_file);
+    let file = fs::read(&path).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (header, state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+
+    let reader = fs::File::open(&path).expect("Unable to open file");
+    let (reader_header, reader_state) = DemoParser::from_reader(std::io::BufReader::new(reader))
+        .parse()
+        .unwrap();
+
+    assert_eq!(header, reader_header);
+    assert_eq!(state, reader_state);
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn seek_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (header, mut ticker) =
+        DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+            .ticker()
+            .unwrap();
+    let start = ticker.current_tick();
+    ticker.build_index(header.ticks / 10).unwrap();
+    assert!(ticker.keyframe_index().len() > 1);
+    assert_eq!(start, ticker.current_tick());
+    // the data tables are shared between the keyframes instead of being copied
+    assert!(std::sync::Arc::strong_count(&ticker.parser_state().send_tables) > 1);
+
+    let reader = fs::File::open(format!("test_data/{}", input_file)).expect("Unable to open file");
+    let (_, mut reader_ticker) = DemoParser::from_reader_with_analyser(
+        std::io::BufReader::new(reader),
+        GameStateAnalyser::new(),
+    )
+    .ticker()
+    .unwrap();
+    reader_ticker.build_index(header.ticks / 10).unwrap();
+
+    for target in [header.ticks / 2, header.ticks / 5, header.ticks * 4 / 5] {
+        let target = DemoTick::from(target);
+        let (_, mut linear) =
+            DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+                .ticker()
+                .unwrap();
+        while linear.current_tick() < target {
+            assert!(linear.tick().unwrap());
+        }
+
+        assert!(ticker.seek(target).unwrap());
+        assert_eq!(linear.current_tick(), ticker.current_tick());
+        assert!(reader_ticker.seek(target).unwrap());
+        assert_eq!(linear.current_tick(), reader_ticker.current_tick());
+        assert_eq!(linear.state(), reader_ticker.state());
+        assert_eq!(linear.state(), ticker.state());
+        // positions stay relative to the start of the demo after seeking backwards
+        assert_eq!(linear.progress(), ticker.progress());
+        assert_eq!(Some(file.len()), ticker.progress().total_bytes);
+    }
+
+    while ticker.tick().unwrap() {}
+    assert_eq!(file.len(), ticker.progress().bytes);
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn combined_handler_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, match_state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+    let (_, game_state) =
+        DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+            .parse()
+            .unwrap();
+
+    let (_, (combined_match_state, combined_game_state)) = DemoParser::new_with_analyser(
+        demo.get_stream(),
+        (Analyser::new(), GameStateAnalyser::new()),
+    )
+    .parse()
+    .unwrap();
+    assert_eq!(match_state, combined_match_state);
+    assert_eq!(game_state, combined_game_state);
+
+    let (_, mut ticker) = DemoParser::new_with_analyser(
+        demo.get_stream(),
+        (Analyser::new(), GameStateAnalyser::new()),
+    )
+    .ticker()
+    .unwrap();
+    let (_, mut game_state_ticker) =
+        DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+            .ticker()
+            .unwrap();
+    while ticker.tick().unwrap() {}
+    while game_state_ticker.tick().unwrap() {}
+    let (borrowed_match_state, borrowed_game_state) = ticker.states();
+    assert_eq!(&match_state, borrowed_match_state);
+    assert_eq!(game_state_ticker.state(), borrowed_game_state);
+    assert_eq!(
+        game_state_ticker.parser_state().server_classes,
+        ticker.parser_state().server_classes
+    );
+
+    let handlers: Vec<Box<dyn DynMessageHandler>> = vec![
+        Box::new(Analyser::new()),
+        Box::new(GameStateAnalyser::new()),
+    ];
+    let (_, outputs) = DemoParser::new_with_analyser(demo.get_stream(), handlers)
+        .parse()
+        .unwrap();
+    let mut outputs = outputs.into_iter();
+    assert_eq!(
+        &match_state,
+        outputs
+            .next()
+            .unwrap()
+            .downcast_ref::<MatchState>()
+            .unwrap()
+    );
+    assert_eq!(
+        &game_state,
+        outputs.next().unwrap().downcast_ref::<GameState>().unwrap()
+    );
+}
+
+#[test]
+fn dyn_handler_message_types_test() {
+    let handlers: Vec<Box<dyn DynMessageHandler>> = vec![Box::new(Analyser::new())];
+    let handler = DemoHandler::with_analyser(handlers);
+    let state = &handler.state_handler;
+    assert!(state.should_parse_message(MessageType::GameEvent));
+    assert!(!state.should_parse_message(MessageType::VoiceData));
+    assert!(!state.should_parse_message(MessageType::PacketEntities));
+
+    let handlers: Vec<Box<dyn DynMessageHandler>> = vec![Box::new(GameStateAnalyser::new())];
+    let handler = DemoHandler::with_analyser((Analyser::new(), handlers));
+    let state = &handler.state_handler;
+    assert!(state.should_parse_message(MessageType::GameEvent));
+    assert!(state.should_parse_message(MessageType::PacketEntities));
+    assert!(!state.should_parse_message(MessageType::VoiceData));
+}
+
+#[test]
+fn error_context_test() {
+    let mut file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::default();
+    let mut starts = Vec::new();
+    loop {
+        let start = packets.pos();
+        match packets.next(&handler.state_handler).unwrap() {
+            Some(packet) => handler.handle_packet(packet).unwrap(),
+            None => break,
+        }
+        starts.push(start);
+    }
+
+    // corrupt the packet type of the 20th packet
+    let start = starts[20];
+    file[start / 8] = 0xff;
+
+    let demo = Demo::new(&file);
+    let err = DemoParser::new(demo.get_stream()).parse().unwrap_err();
+    let context = err.context().expect("no error context");
+    assert_eq!(Some(20), context.packet_index);
+    assert_eq!(Some(start), context.position);
+}
+
+#[test]
+fn lenient_test() {
+    let mut file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let packets = message_packets(&file);
+    let (_, expected) = DemoParser::new(demo.get_stream()).parse().unwrap();
+
+    // corrupt the first message of a message packet, after the tick, meta and length
+    let (start, _) = packets[packets.len() / 2];
+    file[start / 8 + 1 + 4 + 84 + 4] = 0xff;
+
+    let demo = Demo::new(&file);
+    assert!(DemoParser::new(demo.get_stream()).parse().is_err());
+
+    let (_, state, skipped) = DemoParser::new(demo.get_stream()).parse_lenient().unwrap();
+    assert_eq!(1, skipped.len());
+    assert_eq!(start, skipped[0].start);
+    assert!(skipped[0].end.unwrap() > start);
+    assert_eq!(expected.users, state.users);
+
+    let (_, reader_state, reader_skipped) = DemoParser::from_reader(file.as_slice())
+        .parse_lenient()
+        .unwrap();
+    assert_eq!(state, reader_state);
+    assert_eq!(skipped[0].start, reader_skipped[0].start);
+    assert_eq!(skipped[0].end, reader_skipped[0].end);
+
+    // with an invalid packet type, the rest of the demo is skipped
+    file[start / 8] = 0xff;
+    let demo = Demo::new(&file);
+    let (_, _, skipped) = DemoParser::new(demo.get_stream()).parse_lenient().unwrap();
+    assert_eq!(start, skipped[0].start);
+    assert_eq!(None, skipped[0].end);
+
+    // halve the data of the packet, the messages now read past the end of their packet
+    let mut file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let length_pos = start / 8 + 1 + 4 + 84;
+    let length = u32::from_le_bytes(file[length_pos..length_pos + 4].try_into().unwrap());
+    let data_end = length_pos + 4 + length as usize;
+    file.drain(data_end - length as usize / 2..data_end);
+    file[length_pos..length_pos + 4].copy_from_slice(&(length - length / 2).to_le_bytes());
+
+    let demo = Demo::new(&file);
+    let err = DemoParser::new(demo.get_stream()).parse().unwrap_err();
+    assert!(matches!(
+        err.inner(),
+        ParseError::ReadError(BitError::NotEnoughData { .. })
+    ));
+    let context = err.context().unwrap();
+    assert_eq!(Some(start), context.position);
+    assert_eq!(Some(PacketType::Message), context.packet_type);
+
+    let (_, state, skipped) = DemoParser::new(demo.get_stream()).parse_lenient().unwrap();
+    assert_eq!(1, skipped.len());
+    assert_eq!(start, skipped[0].start);
+    assert_eq!(Some(data_end * 8 - length as usize / 2 * 8), skipped[0].end);
+    assert_eq!(expected.users, state.users);
+
+    let (_, reader_state, reader_skipped) = DemoParser::from_reader(file.as_slice())
+        .parse_lenient()
+        .unwrap();
+    assert_eq!(state, reader_state);
+    assert_eq!(skipped[0].end, reader_skipped[0].end);
+
+    // a packet running past the end of the demo can't be skipped
+    let mut file = fs::read("test_data/small.dem").expect("Unable to read file");
+    file[length_pos..length_pos + 4].copy_from_slice(&u32::MAX.to_le_bytes());
+    let limits = ParserLimits {
+        max_packet_size: Some(1 << 20),
+        ..ParserLimits::default()
+    };
+    let demo = Demo::new(&file);
+    let config = ParserConfig::new().limits(limits).lenient(true);
+    let (_, _, skipped) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .parse_lenient()
+        .unwrap();
+    assert_eq!(1, skipped.len());
+    assert_eq!(None, skipped[0].end);
+}
+
+#[test]
+fn config_test() {
+    let file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, expected) = DemoParser::new(demo.get_stream()).parse().unwrap();
+
+    let config = ParserConfig::new().parse_all(true).decode_entities(false);
+    let (_, state) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .parse()
+        .unwrap();
+    assert_eq!(expected, state);
+
+    let config = ParserConfig::new().skip_messages([MessageType::UserMessage]);
+    let (_, state) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .parse()
+        .unwrap();
+    assert!(!expected.chat.is_empty());
+    assert!(state.chat.is_empty());
+    assert_eq!(expected.users, state.users);
+
+    let limits = ParserLimits {
+        max_packet_size: Some(1024),
+        ..ParserLimits::default()
+    };
+    let config = ParserConfig::new().limits(limits.clone());
+    let err = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .parse()
+        .unwrap_err();
+    assert!(matches!(err.inner(), ParseError::PacketTooLarge { .. }));
+
+    let config = ParserConfig::new().limits(limits.clone()).lenient(true);
+    let (_, _, skipped) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .parse_lenient()
+        .unwrap();
+    let config = ParserConfig::new().limits(limits).lenient(true);
+    let (_, _, reader_skipped) =
+        DemoParser::from_reader_with_config(file.as_slice(), Analyser::new(), config)
+            .parse_lenient()
+            .unwrap();
+    assert!(!skipped.is_empty());
+    assert_eq!(
+        skipped.iter().map(|packet| packet.end).collect::<Vec<_>>(),
+        reader_skipped
+            .iter()
+            .map(|packet| packet.end)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[derive(Default)]
+struct FirstPackets {
+    ticks: Vec<DemoTick>,
+}
+
+impl MessageHandler for FirstPackets {
+    type Output = Vec<DemoTick>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn handle_packet_meta(
+        &mut self,
+        tick: DemoTick,
+        _meta: &MessagePacketMeta,
+        _state: &ParserState,
+    ) {
+        self.ticks.push(tick);
+    }
+
+    fn is_done(&self) -> bool {
+        self.ticks.len() >= 10
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.ticks
+    }
+}
+
+#[test]
+fn early_termination_test() {
+    let file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, ticks) = DemoParser::new_with_analyser(demo.get_stream(), FirstPackets::default())
+        .parse()
+        .unwrap();
+    assert_eq!(10, ticks.len());
+
+    // combined handlers only stop once all of them are done
+    let (_, (ticks, match_state)) = DemoParser::new_with_analyser(
+        demo.get_stream(),
+        (FirstPackets::default(), Analyser::new()),
+    )
+    .parse()
+    .unwrap();
+    assert!(ticks.len() > 10);
+    assert!(!match_state.users.is_empty());
+
+    let token = CancelToken::new();
+    let config = ParserConfig::new().cancel_token(token.clone());
+    let (_, mut ticker) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .ticker()
+        .unwrap();
+    assert!(ticker.tick().unwrap());
+    token.cancel();
+    assert!(!ticker.tick().unwrap());
+    assert!(ticker.is_stopped());
+    ticker.into_state();
+}
+
+#[test]
+fn progress_test() {
+    let file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut progress = Vec::new();
+    let (header, _) = DemoParser::new(demo.get_stream())
+        .parse_with_progress(|p| progress.push(p))
+        .unwrap();
+
+    let last = progress.last().unwrap();
+    assert_eq!(Some(file.len()), last.total_bytes);
+    assert_eq!(file.len(), last.bytes);
+    assert_eq!(header.ticks, last.total_ticks);
+    assert_eq!(Some(1.0), last.fraction());
+    assert!(progress
+        .windows(2)
+        .all(|window| window[0].fraction() <= window[1].fraction()));
+
+    let packets = ReaderPacketStream::new(file.as_slice()).with_total_size(file.len());
+    let mut reader_progress = Vec::new();
+    DemoParser::from_source(packets, Analyser::new(), ParserConfig::new())
+        .parse_with_progress(|p| reader_progress.push(p))
+        .unwrap();
+    assert_eq!(progress, reader_progress);
+}
@@ -0,0 +1,112 @@
+use std::fs;
+use test_case::test_case;
+
+use bitbuffer::BitRead;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::datatable::ClassId;
+use tf_demo_parser::demo::packet::Packet;
+use tf_demo_parser::demo::parser::{
+    DemoHandler, NullHandler, ParseObserver, ParserConfig, RawPacketStream,
+};
+use tf_demo_parser::demo::profile::profile_demo;
+use tf_demo_parser::{Demo, MessageType, Stream};
+
+#[test_case("small.dem", true; "small.dem")]
+#[test_case("short-2024.dem", false; "short-2024.dem")]
+fn profile_test(input_file: &str, has_game_events: bool) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let profile = profile_demo(&Demo::new(&file)).unwrap();
+
+    // header is 1072 bytes, everything else is in a packet
+    let packet_bits: usize = profile.packets.values().map(|entry| entry.bits).sum();
+    assert_eq!(profile.total_bits, packet_bits + 1072 * 8);
+
+    let message_packet_bits = profile.packets["Message"].bits + profile.packets["Signon"].bits;
+    let message_bits: usize = profile.messages.values().map(|entry| entry.bits).sum();
+    assert!(message_bits < message_packet_bits);
+
+    let user_message_bits: usize = profile.user_messages.values().map(|entry| entry.bits).sum();
+    assert_eq!(profile.messages["UserMessage"].bits, user_message_bits);
+    assert_eq!(has_game_events, !profile.game_events.is_empty());
+    let game_event_bits: usize = profile.game_events.values().map(|entry| entry.bits).sum();
+    assert_eq!(
+        profile
+            .messages
+            .get("GameEvent")
+            .map_or(0, |entry| entry.bits),
+        game_event_bits
+    );
+
+    let class_bits: usize = profile
+        .server_classes
+        .values()
+        .map(|entry| entry.bits)
+        .sum();
+    let prop_bits: usize = profile.props.values().map(|entry| entry.bits).sum();
+    assert!(prop_bits < class_bits);
+    assert!(class_bits < profile.messages["PacketEntities"].bits);
+    assert!(profile.server_classes.contains_key("CTFPlayer"));
+    assert!(profile.props.contains_key("DT_BaseEntity.m_vecOrigin"));
+
+    assert!(profile.user_messages.contains_key("ResetHUD"));
+    assert!(profile.message_time.contains_key("PacketEntities"));
+    assert!(profile.packet_time.contains_key("DataTables"));
+}
+
+#[derive(Default)]
+struct CountObserver {
+    messages: usize,
+    entity_updates: usize,
+}
+
+impl ParseObserver for CountObserver {
+    fn end_message(
+        &mut self,
+        _message_type: MessageType,
+        message: Option<&Message>,
+        _data: &Stream,
+        _bits: usize,
+    ) {
+        self.messages += message.is_some() as usize;
+    }
+
+    fn entity_update(&mut self, _class: ClassId, _bits: usize) {
+        self.entity_updates += 1;
+    }
+}
+
+#[test]
+fn observer_test() {
+    let file = fs::read("test_data/small.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::with_config(NullHandler, ParserConfig::new().parse_all(true));
+    let mut observer = CountObserver::default();
+
+    // the observer sees the same messages and entity updates that end up in the packets
+    let mut messages = 0;
+    let mut entity_updates = 0;
+    while let Some(packet) = packets
+        .next_observed(&handler.state_handler, &mut observer)
+        .unwrap()
+    {
+        if let Packet::Signon(message_packet) | Packet::Message(message_packet) = &packet {
+            messages += message_packet.messages.len();
+            entity_updates += message_packet
+                .messages
+                .iter()
+                .map(|message| match message {
+                    Message::PacketEntities(message) => message.entities.len(),
+                    _ => 0,
+                })
+                .sum::<usize>();
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    assert_eq!(messages, observer.messages);
+    assert!(entity_updates > 0);
+    assert_eq!(entity_updates, observer.entity_updates);
+}
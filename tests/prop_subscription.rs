@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::fs;
+
+use bitbuffer::BitRead;
+use tf_demo_parser::demo::data::{DemoTick, ServerTick};
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::message::packetentities::{
+    EntityId, PacketEntitiesMessage, PacketEntity, UpdateType,
+};
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::parser::handler::BorrowMessageHandler;
+use tf_demo_parser::demo::parser::{
+    DemoHandler, DynMessageHandler, MessageHandler, PropChange, PropSubscription, RawPacketStream,
+};
+use tf_demo_parser::demo::sendprop::{SendProp, SendPropIdentifier, SendPropValue};
+use tf_demo_parser::{Demo, DemoParser, MessageType, ParserState};
+
+#[derive(Default)]
+struct TickBaseChanges {
+    changes: Vec<PropChange>,
+}
+
+impl MessageHandler for TickBaseChanges {
+    type Output = Vec<PropChange>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+        vec![PropSubscription::new(
+            "CTFPlayer",
+            SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nTickBase"),
+        )]
+    }
+
+    fn handle_prop_change(&mut self, change: &PropChange, _parser_state: &ParserState) {
+        self.changes.push(change.clone());
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.changes
+    }
+}
+
+impl BorrowMessageHandler for TickBaseChanges {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.changes
+    }
+}
+
+#[test]
+fn prop_subscription_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let (_, mut ticker) =
+        DemoParser::new_with_analyser(demo.get_stream(), TickBaseChanges::default())
+            .ticker()
+            .unwrap();
+    while ticker.tick().unwrap() {}
+    let state = ticker.parser_state();
+    let changes = ticker.state();
+
+    assert!(changes.iter().any(|change| change.old.is_some()));
+
+    let tick_base = SendPropIdentifier::new("DT_LocalPlayerExclusive", "m_nTickBase");
+    let mut last: HashMap<EntityId, SendPropValue> = HashMap::new();
+    for change in changes.iter() {
+        assert_eq!(tick_base, change.identifier);
+        assert_ne!(change.old.as_ref(), Some(&change.new));
+        if let Some(old) = &change.old {
+            assert_eq!(Some(old), last.get(&change.entity_id));
+        }
+        last.insert(change.entity_id, change.new.clone());
+    }
+
+    for (entity_id, value) in last {
+        if let Some(entity) = state.entities.get(entity_id) {
+            let prop = entity.get_prop_by_identifier(&tick_base, state).unwrap();
+            assert_eq!(value, prop.value);
+        }
+    }
+}
+
+struct PropChanges {
+    subscription: PropSubscription,
+    changes: Vec<PropChange>,
+}
+
+impl PropChanges {
+    fn new(class_name: &str, table: &str, prop: &str) -> Self {
+        PropChanges {
+            subscription: PropSubscription::new(class_name, SendPropIdentifier::new(table, prop)),
+            changes: Vec::new(),
+        }
+    }
+}
+
+impl MessageHandler for PropChanges {
+    type Output = Vec<PropChange>;
+
+    fn does_handle(_message_type: MessageType) -> bool {
+        false
+    }
+
+    fn prop_subscriptions(&self) -> Vec<PropSubscription> {
+        vec![self.subscription.clone()]
+    }
+
+    fn handle_prop_change(&mut self, change: &PropChange, _parser_state: &ParserState) {
+        self.changes.push(change.clone());
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.changes
+    }
+}
+
+impl BorrowMessageHandler for PropChanges {
+    fn borrow_output(&self, _state: &ParserState) -> &Self::Output {
+        &self.changes
+    }
+}
+
+#[test]
+fn combined_prop_subscription_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let tick_base = || PropChanges::new("CTFPlayer", "DT_LocalPlayerExclusive", "m_nTickBase");
+    let health = || PropChanges::new("CTFPlayer", "DT_BasePlayer", "m_iHealth");
+
+    let (_, tick_base_changes) = DemoParser::new_with_analyser(demo.get_stream(), tick_base())
+        .parse()
+        .unwrap();
+    let (_, health_changes) = DemoParser::new_with_analyser(demo.get_stream(), health())
+        .parse()
+        .unwrap();
+    assert!(!tick_base_changes.is_empty());
+    assert!(!health_changes.is_empty());
+
+    // each handler only receives the changes it subscribed to
+    let (_, (combined_tick_base, combined_health)) =
+        DemoParser::new_with_analyser(demo.get_stream(), (tick_base(), health()))
+            .parse()
+            .unwrap();
+    assert_eq!(tick_base_changes, combined_tick_base);
+    assert_eq!(health_changes, combined_health);
+
+    // nested handlers and handlers subscribing to the same prop all receive their changes
+    let (_, ((nested_tick_base, nested_health), other_health)) =
+        DemoParser::new_with_analyser(demo.get_stream(), ((tick_base(), health()), health()))
+            .parse()
+            .unwrap();
+    assert_eq!(tick_base_changes, nested_tick_base);
+    assert_eq!(health_changes, nested_health);
+    assert_eq!(health_changes, other_health);
+
+    let handlers: Vec<Box<dyn DynMessageHandler>> = vec![Box::new(tick_base()), Box::new(health())];
+    let (_, outputs) = DemoParser::new_with_analyser(demo.get_stream(), handlers)
+        .parse()
+        .unwrap();
+    let outputs: Vec<_> = outputs
+        .into_iter()
+        .map(|output| *output.downcast::<Vec<PropChange>>().unwrap())
+        .collect();
+    assert_eq!(vec![tick_base_changes, health_changes], outputs);
+}
+
+#[test]
+fn reused_entity_index_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream).unwrap();
+    let mut handler =
+        DemoHandler::with_analyser(PropChanges::new("CTFPlayer", "DT_BasePlayer", "m_iHealth"));
+    handler.handle_header(&header);
+    let mut packets = RawPacketStream::new(stream);
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        handler.handle_packet(packet).unwrap();
+    }
+
+    let health = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    let state = &handler.state_handler;
+    let player = state
+        .entities
+        .iter()
+        .find(|entity| {
+            entity.props.iter().any(|prop| prop.identifier == health)
+                && state
+                    .server_classes
+                    .get(usize::from(entity.server_class))
+                    .is_some_and(|class| class.name.as_str() == "CTFPlayer")
+        })
+        .expect("no player")
+        .clone();
+    let index = state
+        .index_for_prop(player.server_class, health)
+        .expect("no health prop");
+
+    // enter an entity of the same class at the index of the existing player
+    let mut enter = |serial_number: u32, value: i64| {
+        let entity = PacketEntity {
+            serial_number,
+            update_type: UpdateType::Enter,
+            props: vec![SendProp {
+                index,
+                identifier: health,
+                value: SendPropValue::Integer(value),
+            }],
+            lazy_props: None,
+            ..player.clone()
+        };
+        let message = PacketEntitiesMessage {
+            entities: vec![entity],
+            removed_entities: Vec::new(),
+            max_entries: 2048,
+            // a delta update, a full update would replace the existing entities
+            delta: Some(ServerTick::from(u32::MAX)),
+            base_line: player.baseline_index,
+            updated_base_line: false,
+        };
+        handler.handle_message(Message::PacketEntities(message), DemoTick::from(u32::MAX));
+        handler.borrow_output().last().cloned().expect("no change")
+    };
+
+    // the same entity re-entering keeps its old value
+    let change = enter(player.serial_number, 1001);
+    assert_eq!(player.entity_index, change.entity_id);
+    assert!(change.old.is_some());
+
+    // a new entity with a different serial number has no old value
+    let change = enter(player.serial_number + 1, 1002);
+    assert_eq!(player.entity_index, change.entity_id);
+    assert_eq!(SendPropValue::Integer(1002), change.new);
+    assert_eq!(None, change.old);
+}
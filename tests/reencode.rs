@@ -139,3 +139,31 @@ fn re_encode_test(input_file: &str) {
         handler.handle_packet(packet).unwrap();
     }
 }
+
+#[test_case("test_data/small.dem"; "small.dem")]
+#[test_case("test_data/short-2024.dem"; "short-2024.dem")]
+fn bit_exact_re_encode_test(input_file: &str) {
+    let file = fs::read(input_file).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::parse_all_with_analyser(NullHandler);
+
+    let mut out_buffer = Vec::with_capacity(file.len());
+    {
+        let mut out_stream = BitWriteStream::new(&mut out_buffer, LittleEndian);
+        header.write(&mut out_stream).unwrap();
+        while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+            packet
+                .encode(&mut out_stream, &handler.state_handler)
+                .unwrap();
+            handler.handle_packet(packet).unwrap();
+        }
+    }
+
+    // only report the first difference instead of dumping the whole demo
+    let first_difference = file.iter().zip(out_buffer.iter()).position(|(a, b)| a != b);
+    assert_eq!(None, first_difference);
+    assert_eq!(file.len(), out_buffer.len());
+}
@@ -0,0 +1,55 @@
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::repair::repair_demo;
+use tf_demo_parser::{Demo, DemoParser};
+
+mod common;
+use common::message_packets;
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn repair_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+
+    let (_, report) = repair_demo(&Demo::new(&file), Cursor::new(Vec::new())).unwrap();
+    assert!(!report.is_damaged());
+    assert_eq!(report.original_header.ticks, report.header.ticks);
+
+    // cut the demo off in the middle of the packets, most of the demo is signon data
+    let truncated = &file[..file.len() - 10_000];
+    let (output, report) = repair_demo(&Demo::new(truncated), Cursor::new(Vec::new())).unwrap();
+    let output = output.into_inner();
+    assert!(report.is_damaged());
+    assert!(report.truncated);
+    assert!(report.missing_stop);
+    assert_eq!(None, report.error);
+    assert!(report.dropped_bytes > 0);
+    assert!(report.header.ticks > 0);
+    assert!(report.header.ticks < report.original_header.ticks);
+    assert!(report.header.frames < report.original_header.frames);
+    assert_eq!(0.015, report.interval_per_tick);
+
+    let (header, _) = DemoParser::new(Demo::new(&output).get_stream())
+        .parse()
+        .unwrap();
+    assert_eq!(report.header, header);
+    assert!((header.ticks as f32 * 0.015 - header.duration).abs() < 0.001);
+
+    // corrupt the first message of a packet in the middle of the demo
+    let packets = message_packets(&file);
+    let mut damaged = file.clone();
+    damaged[packets[packets.len() / 2].0 / 8 + 1 + 4 + 84 + 4] = 0xff;
+    let (output, report) = repair_demo(&Demo::new(&damaged), Cursor::new(Vec::new())).unwrap();
+    let output = output.into_inner();
+    assert!(report.is_damaged());
+    assert!(!report.truncated);
+    assert!(!report.missing_stop);
+    assert_eq!(None, report.error);
+    assert_eq!(1, report.skipped.len());
+    assert_eq!(0, report.dropped_bytes);
+    assert_eq!(report.original_header.ticks, report.header.ticks);
+    assert_eq!(report.original_header.frames - 1, report.header.frames);
+    assert_eq!(packets.len() - 1, message_packets(&output).len());
+}
@@ -0,0 +1,286 @@
+use bitbuffer::BitRead;
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::message::stringtable::UpdateStringTableMessage;
+use tf_demo_parser::demo::message::usermessage::UserMessage;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::message::MessagePacketMeta;
+use tf_demo_parser::demo::packet::stringtable::StringTableEntry;
+use tf_demo_parser::demo::packet::{Packet, PacketType};
+use tf_demo_parser::demo::parser::analyser::Analyser;
+use tf_demo_parser::demo::parser::{DemoHandler, RawPacketStream};
+use tf_demo_parser::demo::transform::{
+    rewrite_demo, Anonymizer, DropPackets, PacketTransform, PovToStv, PropArraySizes, StripVoice,
+    TransformPipeline,
+};
+use tf_demo_parser::{Demo, DemoParser, MessageType, ParserState};
+
+mod common;
+use common::{userinfo_updates, voice_messages};
+
+/// Drops all chat messages and counts them
+#[derive(Default)]
+struct ChatFilter {
+    removed: usize,
+}
+
+impl PacketTransform for ChatFilter {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        if let Packet::Message(message_packet) = &mut packet {
+            let before = message_packet.messages.len();
+            message_packet.messages.retain(|message| {
+                !matches!(
+                    message,
+                    Message::UserMessage(UserMessage::SayText2(_) | UserMessage::Text(_))
+                )
+            });
+            self.removed += before - message_packet.messages.len();
+        }
+        Some(packet)
+    }
+}
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn transform_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+
+    let mut pipeline = TransformPipeline::new()
+        .with(PovToStv)
+        .with(DropPackets::new([PacketType::ConsoleCmd]))
+        .with(PropArraySizes::new().set("DT_Team", "player_array", 101));
+    let mut transform = (&mut pipeline, ChatFilter::default());
+    let output = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut transform)
+        .unwrap()
+        .into_inner();
+    let chat_messages = transform.1.removed;
+
+    let written = Demo::new(&output);
+    let mut stream = written.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::parse_all_with_analyser(Analyser::new());
+    let mut player_array = None;
+    let mut stv = false;
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        match &packet {
+            Packet::ConsoleCmd(_) => panic!("console commands should be dropped"),
+            Packet::DataTables(tables) => {
+                player_array = tables
+                    .tables
+                    .iter()
+                    .filter(|table| table.name.as_str() == "DT_Team")
+                    .flat_map(|table| table.props.iter())
+                    .find(|prop| prop.name.as_str() == "\"player_array\"")
+                    .and_then(|prop| prop.element_count);
+            }
+            Packet::Signon(message_packet) | Packet::Message(message_packet) => {
+                assert_eq!(
+                    MessagePacketMeta::default().view_angles,
+                    message_packet.meta.view_angles
+                );
+                for message in message_packet.messages.iter() {
+                    match message {
+                        Message::ServerInfo(info) => stv = info.stv,
+                        Message::UserMessage(UserMessage::SayText2(_) | UserMessage::Text(_)) => {
+                            panic!("chat messages should be dropped")
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    assert_eq!(Some(101), player_array);
+    assert!(stv);
+
+    let (_, state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+    let written_state = handler.into_output();
+    assert_eq!(state.chat.len(), chat_messages);
+    assert!(written_state.chat.is_empty());
+    assert_eq!(state.users, written_state.users);
+    assert_eq!(state.deaths, written_state.deaths);
+}
+
+/// Sends the user info of the first real player again through an `UpdateStringTable` message
+///
+/// Optionally drops the `CreateStringTable` messages, leaving the `StringTables` packet to define the tables.
+#[derive(Default)]
+struct ResendUserInfo {
+    drop_created: bool,
+    entry: Option<(u8, u16, StringTableEntry<'static>)>,
+    sent: bool,
+}
+
+impl PacketTransform for ResendUserInfo {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        match &mut packet {
+            Packet::StringTables(tables) => {
+                let userinfo = tables
+                    .tables
+                    .iter()
+                    .enumerate()
+                    .find(|(_, table)| table.name == "userinfo");
+                if let Some((table_id, table)) = userinfo {
+                    self.entry = table
+                        .entries
+                        .iter()
+                        .find(|(_, entry)| entry.extra_data.is_some())
+                        .map(|(index, entry)| (table_id as u8, *index, entry.to_owned()));
+                }
+            }
+            Packet::Signon(message_packet) if self.drop_created => {
+                message_packet
+                    .messages
+                    .retain(|message| !matches!(message, Message::CreateStringTable(_)));
+            }
+            Packet::Message(message_packet) if !self.sent => {
+                if let Some((table_id, index, entry)) = self.entry.clone() {
+                    message_packet.messages.push(Message::UpdateStringTable(
+                        UpdateStringTableMessage {
+                            entries: vec![(index, entry)],
+                            table_id,
+                        },
+                    ));
+                    self.sent = true;
+                }
+            }
+            _ => {}
+        }
+        Some(packet)
+    }
+}
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn anonymize_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut anonymizer = Anonymizer::new();
+    let output = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut anonymizer)
+        .unwrap()
+        .into_inner();
+
+    let (header, state) = DemoParser::new(demo.get_stream()).parse().unwrap();
+    let (written_header, written_state) = DemoParser::new(Demo::new(&output).get_stream())
+        .parse()
+        .unwrap();
+
+    assert!(anonymizer.player_count() > 0);
+    assert_eq!("Player 1", written_header.nick);
+    assert_eq!(state.users.len(), written_state.users.len());
+    for (user_id, user) in state.users.iter() {
+        let written = &written_state.users[user_id];
+        assert!(written.name.starts_with("Player "));
+        let number: usize = written.name.trim_start_matches("Player ").parse().unwrap();
+        assert!((1..=anonymizer.player_count()).contains(&number));
+        assert_eq!(
+            written.steam_id,
+            format!("[U:1:{}]", written.name.trim_start_matches("Player "))
+        );
+
+        // no trace of the original identity is left in the demo
+        for original in [&user.name, &user.steam_id, &header.nick] {
+            assert!(!output
+                .windows(original.len())
+                .any(|window| window == original.as_bytes()));
+        }
+    }
+
+    // user info that is updated later in the demo is anonymized too, also when the tables are
+    // only defined by the `StringTables` packet
+    for drop_created in [false, true] {
+        let mut resend = ResendUserInfo {
+            drop_created,
+            ..ResendUserInfo::default()
+        };
+        let resent = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut resend)
+            .unwrap()
+            .into_inner();
+        let original_updates = userinfo_updates(&resent);
+        assert!(original_updates
+            .iter()
+            .any(|update| update.player_info.steam_id != "BOT"));
+        let output = rewrite_demo(
+            &Demo::new(&resent),
+            Cursor::new(Vec::new()),
+            &mut Anonymizer::new(),
+        )
+        .unwrap()
+        .into_inner();
+        let updates = userinfo_updates(&output);
+        assert_eq!(original_updates.len(), updates.len());
+        for update in updates {
+            let info = update.player_info;
+            if info.steam_id != "BOT" {
+                assert!(info.name.starts_with("Player "));
+                assert_eq!(
+                    info.steam_id,
+                    format!("[U:1:{}]", info.name.trim_start_matches("Player "))
+                );
+            }
+        }
+    }
+}
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn strip_voice_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let original_voice = voice_messages(&file);
+    assert!(original_voice.contains(&MessageType::VoiceInit));
+
+    let plain = rewrite_demo(
+        &demo,
+        Cursor::new(Vec::new()),
+        &mut TransformPipeline::new(),
+    )
+    .unwrap()
+    .into_inner();
+
+    let mut strip_voice = StripVoice::new();
+    let stripped = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut strip_voice)
+        .unwrap()
+        .into_inner();
+    assert!(voice_messages(&stripped).is_empty());
+    assert_eq!(original_voice.len(), strip_voice.removed_messages());
+
+    assert_eq!(plain.len() - stripped.len(), strip_voice.removed_bytes());
+
+    // stripping other clients keeps everything
+    let mut strip_other = StripVoice::clients([200]);
+    let kept = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut strip_other)
+        .unwrap()
+        .into_inner();
+    assert_eq!(original_voice, voice_messages(&kept));
+    assert_eq!(0, strip_other.removed_messages());
+
+    // repeated stages are all applied in order
+    let mut strip_other = StripVoice::clients([200]);
+    let mut strip_all = StripVoice::new();
+    let mut pipeline = TransformPipeline::new()
+        .with(&mut strip_other)
+        .with(&mut strip_all);
+    let stripped_twice = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut pipeline)
+        .unwrap()
+        .into_inner();
+    drop(pipeline);
+    assert_eq!(stripped, stripped_twice);
+    assert_eq!(0, strip_other.removed_messages());
+    assert_eq!(original_voice.len(), strip_all.removed_messages());
+}
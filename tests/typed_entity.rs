@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::fs;
+
+use tf_demo_parser::demo::data::game_state::Handle;
+use tf_demo_parser::demo::data::DemoTick;
+use tf_demo_parser::demo::entity_gen::TFPlayer;
+use tf_demo_parser::demo::message::packetentities::EntityId;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::parser::analyser::Analyser;
+use tf_demo_parser::demo::parser::{MessageHandler, ParserConfig};
+use tf_demo_parser::demo::sendprop::SendPropIdentifier;
+use tf_demo_parser::demo::vector::VectorXY;
+use tf_demo_parser::{Demo, DemoParser, MessageType, ParserState};
+
+#[test]
+fn typed_entity_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let config = ParserConfig::new().track_entities(true);
+    let (_, mut ticker) = DemoParser::with_config(demo.get_stream(), Analyser::new(), config)
+        .ticker()
+        .unwrap();
+    while ticker.tick().unwrap() {}
+    let state = ticker.parser_state();
+
+    let health = SendPropIdentifier::new("DT_BasePlayer", "m_iHealth");
+    let origin = SendPropIdentifier::new("DT_TFNonLocalPlayerExclusive", "m_vecOrigin");
+    let active_weapon = SendPropIdentifier::new("DT_BaseCombatCharacter", "m_hActiveWeapon");
+    let attribute =
+        SendPropIdentifier::new("DT_ScriptCreatedAttribute", "m_iAttributeDefinitionIndex");
+    let mut players = state
+        .entities_by_class_name(TFPlayer::CLASS_NAME)
+        .peekable();
+    assert!(players.peek().is_some());
+    for entity in players {
+        let player = TFPlayer::from_entity(entity, state).unwrap();
+        let expected_health: i64 = (&entity.get_prop_by_identifier(&health, state).unwrap().value)
+            .try_into()
+            .unwrap();
+        assert_eq!(expected_health, i64::from(player.health));
+        let expected_weapon: i64 = (&entity
+            .get_prop_by_identifier(&active_weapon, state)
+            .unwrap()
+            .value)
+            .try_into()
+            .unwrap();
+        assert_eq!(Handle(expected_weapon), player.active_weapon);
+        // every entry of the repeated attribute table ends up at its element in the array
+        for prop in entity
+            .try_props(state)
+            .unwrap()
+            .filter(|prop| prop.identifier == attribute)
+        {
+            let expected_attribute: i64 = (&prop.value).try_into().unwrap();
+            let element = state
+                .prop_location(entity.server_class, prop.index)
+                .and_then(|location| location.element)
+                .unwrap();
+            assert_eq!(
+                u16::try_from(expected_attribute).unwrap(),
+                player.attribute_definition_index[usize::from(element)]
+            );
+        }
+        // the props of array tables, named by their index, end up at their index in the array
+        for (index, weapon) in player.my_weapons.iter().enumerate() {
+            let identifier = SendPropIdentifier::new("m_hMyWeapons", &format!("{:03}", index));
+            let prop = entity.get_prop_by_identifier(&identifier, state).unwrap();
+            let expected_weapon: i64 = (&prop.value).try_into().unwrap();
+            assert_eq!(Handle(expected_weapon), *weapon);
+        }
+        assert!(player
+            .my_weapons
+            .iter()
+            .any(|weapon| *weapon != Handle::default()));
+        if let Some(prop) = entity.get_prop_by_identifier(&origin, state) {
+            let expected_origin: VectorXY = (&prop.value).try_into().unwrap();
+            assert_eq!(expected_origin, player.tf_non_local_player_exclusive_origin);
+        }
+    }
+}
+
+/// Typed players built from the entity updates of every packet
+#[derive(Default)]
+struct TypedPlayers {
+    players: HashMap<EntityId, TFPlayer>,
+}
+
+impl MessageHandler for TypedPlayers {
+    type Output = HashMap<EntityId, TFPlayer>;
+
+    fn does_handle(message_type: MessageType) -> bool {
+        message_type == MessageType::PacketEntities
+    }
+
+    fn handle_message(&mut self, message: &Message, _tick: DemoTick, state: &ParserState) {
+        let Message::PacketEntities(message) = message else {
+            return;
+        };
+        for entity in message.entities.iter() {
+            let class = &state.server_classes[usize::from(entity.server_class)];
+            if class.name == TFPlayer::CLASS_NAME {
+                self.players
+                    .entry(entity.entity_index)
+                    .or_default()
+                    .apply(entity, state)
+                    .unwrap();
+            }
+        }
+    }
+
+    fn into_output(self, _state: &ParserState) -> Self::Output {
+        self.players
+    }
+}
+
+#[test]
+fn typed_entity_lazy_test() {
+    let file = fs::read("test_data/short-2024.dem").expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let parse = |lazy_props| {
+        let config = ParserConfig::new().lazy_props(lazy_props);
+        DemoParser::with_config(demo.get_stream(), TypedPlayers::default(), config)
+            .parse()
+            .unwrap()
+            .1
+    };
+    let players = parse(false);
+    assert!(!players.is_empty());
+    // the baseline props of entering players are included
+    assert!(players.values().all(|player| player.health > 0));
+    assert_eq!(players, parse(true));
+}
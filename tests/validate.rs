@@ -0,0 +1,270 @@
+use bitbuffer::{BitRead, BitWrite, BitWriteSized, BitWriteStream, LittleEndian};
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::data::DemoTick;
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::message::Message;
+use tf_demo_parser::demo::packet::{Packet, PacketType};
+use tf_demo_parser::demo::parser::{DemoHandler, RawPacketStream};
+use tf_demo_parser::demo::transform::{rewrite_demo, PacketTransform};
+use tf_demo_parser::demo::validate::{validate_demo, Problem};
+use tf_demo_parser::{Demo, MessageType, ParserState};
+
+mod common;
+use common::message_packets;
+
+/// Damage a demo by moving a packet back in time and sending a game event of an unknown type
+#[derive(Default)]
+struct Damage {
+    message_packets: usize,
+    event_damaged: bool,
+}
+
+impl PacketTransform for Damage {
+    fn transform<'a>(
+        &mut self,
+        mut packet: Packet<'a>,
+        _state: &ParserState,
+    ) -> Option<Packet<'a>> {
+        if let Packet::Message(message_packet) = &mut packet {
+            self.message_packets += 1;
+            if self.message_packets == 50 {
+                message_packet.tick = DemoTick::from(1);
+            }
+            for message in message_packet.messages.iter_mut() {
+                if let Message::GameEvent(event) = message {
+                    if !self.event_damaged {
+                        event.event_type_id = serde_json::from_str("511").unwrap();
+                        self.event_damaged = true;
+                    }
+                }
+            }
+        }
+        Some(packet)
+    }
+}
+
+#[test_case("small.dem", true; "small.dem")]
+#[test_case("short-2024.dem", false; "short-2024.dem")]
+fn validate_test(input_file: &str, has_game_events: bool) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let report = validate_demo(&demo).unwrap();
+    assert!(report.is_valid(), "{:?}", report.issues);
+    assert_eq!(report.header.ticks, report.ticks);
+
+    let truncated = validate_demo(&Demo::new(&file[..file.len() - 10_000])).unwrap();
+    let problems: Vec<_> = truncated
+        .issues
+        .iter()
+        .map(|issue| &issue.problem)
+        .collect();
+    assert!(problems.contains(&&Problem::Truncated));
+    assert!(problems.contains(&&Problem::MissingStop));
+    assert!(problems.contains(&&Problem::HeaderTicks {
+        header: report.ticks,
+        actual: truncated.ticks
+    }));
+
+    let mut damage = Damage::default();
+    let damaged = rewrite_demo(&demo, Cursor::new(Vec::new()), &mut damage)
+        .unwrap()
+        .into_inner();
+    assert_eq!(has_game_events, damage.event_damaged);
+    let report = validate_demo(&Demo::new(&damaged)).unwrap();
+    assert!(report.issues.iter().any(|issue| matches!(
+        issue.problem,
+        Problem::NonMonotonicTick { tick, .. } if tick == 1
+    )));
+    let event_issue = report
+        .issues
+        .iter()
+        .find(|issue| matches!(issue.problem, Problem::GameEventMismatch { .. }));
+    assert_eq!(has_game_events, event_issue.is_some());
+    assert!(event_issue.map_or(true, |issue| issue.packet_index.is_some()));
+
+    // add 16 empty bytes to a message packet whose messages end on a whole byte, everything after
+    // the first of the extra bytes is more than the padding to a whole byte
+    let packets = message_packets(&file);
+    let (start, _) = packets
+        .iter()
+        .find(|(_, padding)| padding.bits == 0)
+        .copied()
+        .unwrap();
+    let length_pos = start / 8 + 1 + 4 + 84;
+    let length = u32::from_le_bytes(file[length_pos..length_pos + 4].try_into().unwrap());
+    let mut padded = file.clone();
+    padded[length_pos..length_pos + 4].copy_from_slice(&(length + 16).to_le_bytes());
+    let data_end = length_pos + 4 + length as usize;
+    padded.splice(data_end..data_end, [0; 16]);
+    let padded_report = validate_demo(&Demo::new(&padded)).unwrap();
+    let issue = padded_report
+        .issues
+        .iter()
+        .find(|issue| matches!(issue.problem, Problem::DataRemaining { .. }))
+        .unwrap();
+    assert_eq!(Some(start), issue.position);
+    assert!(matches!(issue.problem, Problem::DataRemaining { bits } if bits == 120));
+
+    // a corrupt packet is reported and the rest of the demo is still validated
+    let mut corrupt = file.clone();
+    corrupt[length_pos + 4] = 0xff;
+    let corrupt_report = validate_demo(&Demo::new(&corrupt)).unwrap();
+    let problems: Vec<_> = corrupt_report
+        .issues
+        .iter()
+        .map(|issue| &issue.problem)
+        .collect();
+    assert!(matches!(problems[0], Problem::InvalidPacket { .. }));
+    assert_eq!(Some(start), corrupt_report.issues[0].position);
+    assert!(!problems.contains(&&Problem::Truncated));
+    assert!(!problems.contains(&&Problem::MissingStop));
+    assert_eq!(report.packets, corrupt_report.packets);
+    assert_eq!(report.ticks, corrupt_report.ticks);
+
+    // the report is attached to uploads as json
+    let json = serde_json::to_value(&report).unwrap();
+    assert!(json["issues"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .any(|issue| issue["type"] == "NonMonotonicTick"));
+}
+
+/// The bit position, type and tick of every packet in the demo
+fn packet_starts(data: &[u8]) -> Vec<(usize, PacketType, DemoTick)> {
+    let demo = Demo::new(data);
+    let mut stream = demo.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::default();
+    let mut starts = Vec::new();
+    loop {
+        let start = packets.pos();
+        match packets.next(&handler.state_handler).unwrap() {
+            Some(packet) => {
+                starts.push((start, packet.packet_type(), packet.tick()));
+                handler.handle_packet(packet).unwrap()
+            }
+            None => break,
+        }
+    }
+    starts
+}
+
+/// Insert a copy of the message or signon packet at the bit position `source` with the message
+/// data replaced by `messages` at the bit position `target`
+fn insert_packet(data: &mut Vec<u8>, source: usize, target: usize, messages: &[u8]) {
+    let mut packet = data[source / 8..source / 8 + 1 + 4 + 84].to_vec();
+    packet.extend_from_slice(&(messages.len() as u32).to_le_bytes());
+    packet.extend_from_slice(messages);
+    data.splice(target / 8..target / 8, packet);
+}
+
+fn write_messages(write: impl FnOnce(&mut BitWriteStream<LittleEndian>)) -> Vec<u8> {
+    let mut data = Vec::new();
+    let mut stream = BitWriteStream::new(&mut data, LittleEndian);
+    write(&mut stream);
+    data
+}
+
+#[test_case("small.dem"; "small.dem")]
+#[test_case("short-2024.dem"; "short-2024.dem")]
+fn validate_unknown_references_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let header = Header::read(&mut Demo::new(&file).get_stream()).unwrap();
+    let packets = packet_starts(&file);
+
+    // an update for the last string table id in a signon packet,
+    // only the tables created by the first signon packets exist at this point
+    let (signon_start, _, signon_tick) = *packets
+        .iter()
+        .take_while(|(_, packet_type, _)| *packet_type != PacketType::StringTables)
+        .filter(|(_, packet_type, _)| *packet_type == PacketType::Signon)
+        .last()
+        .unwrap();
+    let (string_table_target, _, _) = *packets
+        .iter()
+        .find(|(start, _, _)| *start > signon_start)
+        .unwrap();
+    let string_table_update = write_messages(|stream| {
+        (MessageType::UpdateStringTable as u8)
+            .write_sized(stream, 6)
+            .unwrap();
+        31u8.write_sized(stream, 5).unwrap();
+        // a single changed entry without data
+        false.write(stream).unwrap();
+        0u32.write_sized(stream, 20).unwrap();
+    });
+
+    // a temp entity with the highest class id that fits in the class id bits
+    let (server_class_target, _, server_class_tick) = *packets
+        .iter()
+        .filter(|(_, packet_type, _)| *packet_type == PacketType::Message)
+        .nth(20)
+        .unwrap();
+    let temp_entity = write_messages(|stream| {
+        (MessageType::TempEntities as u8)
+            .write_sized(stream, 6)
+            .unwrap();
+        1u8.write_sized(stream, 8).unwrap();
+        // no fire delay, a new class id and the class id bits
+        let length = 2u32 + 16;
+        if header.protocol > 23 {
+            length.write_sized(stream, 8).unwrap();
+        } else {
+            length.write_sized(stream, 17).unwrap();
+        }
+        false.write(stream).unwrap();
+        true.write(stream).unwrap();
+        u16::MAX.write_sized(stream, 16).unwrap();
+    });
+
+    // insert the later packet first to keep the position of the earlier one
+    let mut corrupt = file.clone();
+    insert_packet(
+        &mut corrupt,
+        server_class_target,
+        server_class_target,
+        &temp_entity,
+    );
+    insert_packet(
+        &mut corrupt,
+        signon_start,
+        string_table_target,
+        &string_table_update,
+    );
+    let server_class_target =
+        server_class_target + (1 + 4 + 84 + 4 + string_table_update.len()) * 8;
+
+    let report = validate_demo(&Demo::new(&corrupt)).unwrap();
+    let string_table_issue = report
+        .issues
+        .iter()
+        .find(|issue| matches!(issue.problem, Problem::UnknownStringTable { .. }))
+        .expect("no unknown string table reported");
+    assert_eq!(
+        Problem::UnknownStringTable { table_id: 31 },
+        string_table_issue.problem
+    );
+    assert_eq!(Some(string_table_target), string_table_issue.position);
+    assert_eq!(Some(signon_tick), string_table_issue.tick);
+
+    let server_class_issue = report
+        .issues
+        .iter()
+        .find(|issue| matches!(issue.problem, Problem::UnknownServerClass { .. }))
+        .expect("no unknown server class reported");
+    assert_eq!(Some(server_class_target), server_class_issue.position);
+    assert_eq!(Some(server_class_tick), server_class_issue.tick);
+    assert!(string_table_issue.packet_index < server_class_issue.packet_index);
+
+    // the inserted packets are skipped and the rest of the demo is still validated
+    assert_eq!(packets.len() + 2, report.packets);
+    assert!(!report
+        .issues
+        .iter()
+        .any(|issue| matches!(issue.problem, Problem::Truncated | Problem::MissingStop)));
+}
@@ -0,0 +1,118 @@
+use bitbuffer::BitRead;
+use std::fs;
+use std::io::Cursor;
+use test_case::test_case;
+
+use tf_demo_parser::demo::header::Header;
+use tf_demo_parser::demo::packet::PacketType;
+use tf_demo_parser::demo::parser::gamestateanalyser::GameStateAnalyser;
+use tf_demo_parser::demo::parser::{DemoHandler, RawPacketStream};
+use tf_demo_parser::demo::writer::DemoWriter;
+use tf_demo_parser::{Demo, DemoParser};
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn demo_writer_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::default();
+
+    // start from a header without any length info, like from a demo that wasn't closed properly
+    let mut empty_header = header.clone();
+    empty_header.ticks = 0;
+    empty_header.frames = 0;
+    empty_header.duration = 0.0;
+    empty_header.signon = 0;
+
+    let mut writer = DemoWriter::new(Cursor::new(Vec::new()), empty_header).unwrap();
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        // leave out the stop packet, the writer should add it
+        if packet.packet_type() != PacketType::Stop {
+            writer.write_packet(packet.clone()).unwrap();
+        }
+        handler.handle_packet(packet).unwrap();
+    }
+    let output = writer.finish().unwrap().into_inner();
+
+    let written = Demo::new(&output);
+    let (written_header, written_state) =
+        DemoParser::new_with_analyser(written.get_stream(), GameStateAnalyser::new())
+            .parse()
+            .unwrap();
+    let (_, state) = DemoParser::new_with_analyser(demo.get_stream(), GameStateAnalyser::new())
+        .parse()
+        .unwrap();
+    assert_eq!(state, written_state);
+    assert_eq!(header.ticks, written_header.ticks);
+    assert_eq!(header.frames, written_header.frames);
+    assert!((header.duration - written_header.duration).abs() < 0.01);
+
+    // the signon size is the size of all signon and data table packets
+    let mut stream = written.get_stream();
+    Header::read(&mut stream).unwrap();
+    let mut packets = RawPacketStream::new(stream);
+    let mut handler = DemoHandler::default();
+    let mut signon = 0;
+    let mut start = packets.pos();
+    while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+        if matches!(
+            packet.packet_type(),
+            PacketType::Signon | PacketType::DataTables
+        ) {
+            signon += (packets.pos() - start) / 8;
+        }
+        start = packets.pos();
+        handler.handle_packet(packet).unwrap();
+    }
+    assert_eq!(signon as u32, written_header.signon);
+}
+
+#[test_case("small.dem")]
+#[test_case("short-2024.dem")]
+fn writer_header_test(input_file: &str) {
+    let file = fs::read(format!("test_data/{}", input_file)).expect("Unable to read file");
+    let demo = Demo::new(&file);
+    let mut stream = demo.get_stream();
+    let header = Header::read(&mut stream).unwrap();
+
+    // write the first half of the demo
+    let write_half = |update_header: bool| {
+        let mut packets = RawPacketStream::new(stream.clone());
+        let mut handler = DemoHandler::default();
+        let mut writer = DemoWriter::new(Cursor::new(Vec::new()), header.clone())
+            .unwrap()
+            .update_header(update_header);
+        let mut start = None;
+        while let Some(packet) = packets.next(&handler.state_handler).unwrap() {
+            let in_signon = matches!(
+                packet.packet_type(),
+                PacketType::Signon | PacketType::DataTables | PacketType::SyncTick
+            );
+            if !in_signon {
+                let start = *start.get_or_insert(u32::from(packet.tick()));
+                if u32::from(packet.tick()) - start > header.ticks / 2 {
+                    break;
+                }
+            }
+            writer.write_packet(packet.clone()).unwrap();
+            handler.handle_packet(packet).unwrap();
+        }
+        let output = writer.finish().unwrap().into_inner();
+        Header::read(&mut Demo::new(&output).get_stream()).unwrap()
+    };
+
+    // by default the length info that is already set is kept
+    assert_eq!(header, write_half(false));
+
+    let updated = write_half(true);
+    assert!(updated.ticks > 0);
+    assert!(updated.ticks <= header.ticks / 2 + 1);
+    assert!(updated.frames > 0);
+    assert!(updated.frames < header.frames);
+    assert!(updated.duration < header.duration);
+    assert_eq!(header.signon, updated.signon);
+    assert_eq!(header.map, updated.map);
+}